@@ -0,0 +1,216 @@
+#![cfg_attr(docsrs, feature(doc_auto_cfg))]
+//! A [`Json`](crate::Json) extractor variant that awaits
+//! [`serde_valid::AsyncValidate`] before calling the handler.
+//!
+//! # Features
+//!
+//! - aide: support for [aide](https://docs.rs/aide/latest/aide/)
+
+use std::ops::Deref;
+
+use async_trait::async_trait;
+use axum::http::Request;
+use axum::{extract::FromRequest, response::IntoResponse, BoxError};
+use serde::Serialize;
+
+/// Wrapper type over [`axum::Json`] that validates requests
+/// with [`serde_valid::AsyncValidate`], running the synchronous validators
+/// and then awaiting the asynchronous ones.
+pub struct AsyncJson<T>(pub T);
+
+impl<T> Deref for AsyncJson<T> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<T> From<T> for AsyncJson<T> {
+    fn from(data: T) -> Self {
+        AsyncJson(data)
+    }
+}
+
+#[async_trait]
+impl<T, S, B> FromRequest<S, B> for AsyncJson<T>
+where
+    T: crate::validated::Deserialize + serde_valid::AsyncValidate + Send + 'static,
+    B: http_body::Body + Send + 'static,
+    B::Data: Send,
+    B::Error: Into<BoxError>,
+    S: Send + Sync,
+{
+    type Rejection = crate::rejection::Rejection;
+
+    async fn from_request(req: Request<B>, state: &S) -> Result<Self, Self::Rejection> {
+        crate::request::from_request_async::<_, _, T>(req, state)
+            .await
+            .map(AsyncJson)
+    }
+}
+
+impl<T> IntoResponse for AsyncJson<T>
+where
+    T: Serialize,
+{
+    fn into_response(self) -> axum::response::Response {
+        axum::Json(self.0).into_response()
+    }
+}
+
+#[cfg(feature = "aide")]
+mod impl_aide {
+    use super::*;
+
+    impl<T> aide::OperationInput for AsyncJson<T>
+    where
+        T: schemars::JsonSchema,
+    {
+        fn operation_input(
+            ctx: &mut aide::gen::GenContext,
+            operation: &mut aide::openapi::Operation,
+        ) {
+            axum::Json::<T>::operation_input(ctx, operation);
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::AsyncJson;
+    use axum::http::StatusCode;
+    use axum::{
+        body::Body,
+        http::{self, Request},
+    };
+    use serde::Deserialize;
+    use serde_json::json;
+    use serde_valid::{AsyncValidate, Validate};
+    use tower::ServiceExt;
+
+    type TestResult = Result<(), Box<dyn std::error::Error>>;
+
+    /// In-memory stand-in for a user store.
+    async fn unique_name(name: &str) -> Result<(), serde_valid::validation::Error> {
+        tokio::task::yield_now().await;
+        if ["taro", "hanako"].contains(&name) {
+            Err(serde_valid::validation::Error::Custom(format!(
+                "`{name}` is already taken."
+            )))
+        } else {
+            Ok(())
+        }
+    }
+
+    #[cfg(all(not(feature = "jsonschema"), not(feature = "aide")))]
+    #[tokio::test]
+    async fn test_async_json() -> TestResult {
+        use axum::{routing::post, Router};
+
+        #[derive(Deserialize, Validate, AsyncValidate)]
+        struct User {
+            #[validate(max_length = 3)]
+            #[validate(custom_async(unique_name))]
+            name: String,
+        }
+
+        let app = Router::new().route(
+            "/json",
+            post(|_user: AsyncJson<User>| async move { "hello" }),
+        );
+
+        let response = app
+            .oneshot(
+                Request::builder()
+                    .method(http::Method::POST)
+                    .uri("/json")
+                    .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
+                    .body(Body::from(serde_json::to_vec(&json!({"name": "taro"}))?))?,
+            )
+            .await?;
+
+        assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+        assert_eq!(
+            serde_json::from_slice::<serde_json::Value>(
+                &hyper::body::to_bytes(response.into_body()).await?[..],
+            )?,
+            json!({"errors": [
+                {
+                    "error": "The length of the value must be `<= 3`.",
+                    "instance_location": "/name",
+                    "keyword_location": null
+                },
+                {
+                    "error": "`taro` is already taken.",
+                    "instance_location": "/name",
+                    "keyword_location": null
+                }
+            ]})
+        );
+
+        Ok(())
+    }
+
+    #[cfg(feature = "jsonschema")]
+    #[tokio::test]
+    async fn test_async_json_with_jsonschema() -> TestResult {
+        use axum::{routing::post, Router};
+
+        #[derive(Deserialize, Validate, AsyncValidate, schemars::JsonSchema)]
+        struct User {
+            #[validate(custom_async(unique_name))]
+            name: String,
+        }
+
+        let app = Router::new().route(
+            "/json",
+            post(|_user: AsyncJson<User>| async move { "hello" }),
+        );
+
+        let response = app
+            .oneshot(
+                Request::builder()
+                    .method(http::Method::POST)
+                    .uri("/json")
+                    .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
+                    .body(Body::from(serde_json::to_vec(&json!({"name": "jiro"}))?))?,
+            )
+            .await?;
+
+        assert_eq!(response.status(), StatusCode::OK);
+
+        Ok(())
+    }
+
+    #[cfg(feature = "aide")]
+    #[tokio::test]
+    async fn test_async_json_with_aide() -> TestResult {
+        use aide::axum::{routing::post, ApiRouter};
+
+        #[derive(Deserialize, Validate, AsyncValidate, schemars::JsonSchema)]
+        struct User {
+            #[validate(custom_async(unique_name))]
+            name: String,
+        }
+
+        let app = ApiRouter::new().route(
+            "/json",
+            post(|_user: AsyncJson<User>| async move { "hello" }),
+        );
+
+        let response = app
+            .oneshot(
+                Request::builder()
+                    .method(http::Method::POST)
+                    .uri("/json")
+                    .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
+                    .body(Body::from(serde_json::to_vec(&json!({"name": "hanako"}))?))?,
+            )
+            .await?;
+
+        assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+
+        Ok(())
+    }
+}
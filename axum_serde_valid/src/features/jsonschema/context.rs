@@ -15,8 +15,13 @@ thread_local! {
     static CONTEXT: RefCell<SchemaContext> = RefCell::new(SchemaContext::new());
 }
 
+/// Compiled `schemars` schemas of the request types.
+///
+/// The schemas are not merged with `serde_valid::ValidateSchema`,
+/// whose keywords are checked by `Validate` after deserialization instead.
 pub(crate) struct SchemaContext {
     pub generator: SchemaGenerator,
+    pub root_schemas: HashMap<TypeId, Value>,
     pub schemas: HashMap<TypeId, JSONSchema>,
 }
 
@@ -26,36 +31,185 @@ impl SchemaContext {
             generator: SchemaSettings::draft07()
                 .with(|settings| settings.inline_subschemas = true)
                 .into_generator(),
+            root_schemas: HashMap::default(),
             schemas: HashMap::default(),
         }
     }
 
     pub fn validate<T>(value: &Value) -> Result<(), VecDeque<OutputUnit<ErrorDescription>>>
+    where
+        T: crate::validated::Deserialize + schemars::JsonSchema + 'static,
+    {
+        CONTEXT.with(|ctx| ctx.borrow_mut().apply::<T>(value))
+    }
+
+    /// Validate the query string against the schema of `T`, and return the validated value.
+    ///
+    /// Every query parameter is a string, so the values are coerced to
+    /// the boolean or numeric types the schema allows before validation.
+    /// The returned value is what `T` is deserialized from.
+    pub fn validate_query<T>(query: &str) -> Result<Value, VecDeque<OutputUnit<ErrorDescription>>>
     where
         T: crate::validated::Deserialize + schemars::JsonSchema + 'static,
     {
         CONTEXT.with(|ctx| {
             let ctx = &mut *ctx.borrow_mut();
-            let schema = ctx.schemas.entry(TypeId::of::<T>()).or_insert_with(|| {
-                match jsonschema::JSONSchema::compile(
-                    &serde_json::to_value(ctx.generator.root_schema_for::<T>()).unwrap(),
-                ) {
-                    Ok(s) => s,
-                    Err(error) => {
-                        tracing::error!(
-                            %error,
-                            type_name = type_name::<T>(),
-                            "invalid JSON schema for type"
-                        );
-                        JSONSchema::compile(&Value::Object(Map::default())).unwrap()
-                    }
+            let value = query_to_value(query, ctx.root_schema::<T>());
+
+            ctx.apply::<T>(&value).map(|()| value)
+        })
+    }
+
+    fn root_schema<T>(&mut self) -> &Value
+    where
+        T: schemars::JsonSchema + 'static,
+    {
+        let generator = &mut self.generator;
+        self.root_schemas
+            .entry(TypeId::of::<T>())
+            .or_insert_with(|| serde_json::to_value(generator.root_schema_for::<T>()).unwrap())
+    }
+
+    fn apply<T>(&mut self, value: &Value) -> Result<(), VecDeque<OutputUnit<ErrorDescription>>>
+    where
+        T: schemars::JsonSchema + 'static,
+    {
+        if !self.schemas.contains_key(&TypeId::of::<T>()) {
+            let schema = match jsonschema::JSONSchema::compile(self.root_schema::<T>()) {
+                Ok(s) => s,
+                Err(error) => {
+                    tracing::error!(
+                        %error,
+                        type_name = type_name::<T>(),
+                        "invalid JSON schema for type"
+                    );
+                    JSONSchema::compile(&Value::Object(Map::default())).unwrap()
                 }
-            });
+            };
+            self.schemas.insert(TypeId::of::<T>(), schema);
+        }
+
+        match self.schemas[&TypeId::of::<T>()].apply(value).basic() {
+            BasicOutput::Valid(_) => Ok(()),
+            BasicOutput::Invalid(v) => Err(v),
+        }
+    }
+}
+
+/// Repeated keys, such as `?tag=a&tag=b`, and the keys of array properties are collected into arrays.
+fn query_to_value(query: &str, schema: &Value) -> Value {
+    let mut object = Map::new();
 
-            match schema.apply(value).basic() {
-                BasicOutput::Valid(_) => Ok(()),
-                BasicOutput::Invalid(v) => Err(v),
+    for (key, value) in form_urlencoded::parse(query.as_bytes()) {
+        let property = schema
+            .get("properties")
+            .and_then(|properties| properties.get(key.as_ref()));
+        let is_array =
+            schema_types(property.and_then(|property| property.get("type"))).contains(&"array");
+        let types = match is_array {
+            true => property
+                .and_then(|property| property.get("items"))
+                .and_then(|items| items.get("type")),
+            false => property.and_then(|property| property.get("type")),
+        };
+        let value = coerce_query_value(&value, types);
+
+        match object.get_mut(key.as_ref()) {
+            Some(Value::Array(values)) => values.push(value),
+            Some(previous) => *previous = Value::Array(vec![previous.take(), value]),
+            None if is_array => {
+                object.insert(key.into_owned(), Value::Array(vec![value]));
             }
-        })
+            None => {
+                object.insert(key.into_owned(), value);
+            }
+        }
+    }
+
+    Value::Object(object)
+}
+
+fn schema_types(types: Option<&Value>) -> Vec<&str> {
+    match types {
+        Some(Value::String(t)) => vec![t.as_str()],
+        Some(Value::Array(ts)) => ts.iter().filter_map(Value::as_str).collect(),
+        _ => vec![],
+    }
+}
+
+fn coerce_query_value(value: &str, types: Option<&Value>) -> Value {
+    let types = schema_types(types);
+
+    if !types.contains(&"string") {
+        if types.contains(&"boolean") {
+            if let Ok(v) = value.parse::<bool>() {
+                return Value::Bool(v);
+            }
+        }
+        if types.contains(&"integer") || types.contains(&"number") {
+            if let Ok(v) = value.parse::<i64>() {
+                return Value::from(v);
+            }
+            if let Ok(v) = value.parse::<u64>() {
+                return Value::from(v);
+            }
+        }
+        if types.contains(&"number") {
+            if let Some(v) = value
+                .parse::<f64>()
+                .ok()
+                .and_then(serde_json::Number::from_f64)
+            {
+                return Value::Number(v);
+            }
+        }
+    }
+
+    Value::String(value.to_owned())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn query_to_value_coerces_by_schema_type() {
+        let schema = json!({
+            "properties": {
+                "name": {"type": "string"},
+                "page": {"type": "integer"},
+                "ratio": {"type": ["number", "null"]},
+                "active": {"type": "boolean"}
+            }
+        });
+
+        assert_eq!(
+            query_to_value("name=10&page=2&ratio=0.5&active=true&other=1", &schema),
+            json!({"name": "10", "page": 2, "ratio": 0.5, "active": true, "other": "1"})
+        );
+    }
+
+    #[test]
+    fn query_to_value_keeps_unparsable_value_as_string() {
+        let schema = json!({"properties": {"page": {"type": "integer"}}});
+
+        assert_eq!(query_to_value("page=1.5", &schema), json!({"page": "1.5"}));
+    }
+
+    #[test]
+    fn query_to_value_collects_repeated_keys() {
+        let schema = json!({
+            "properties": {
+                "ids": {"type": "array", "items": {"type": "integer"}},
+                "tags": {"type": ["array", "null"], "items": {"type": "string"}},
+                "page": {"type": "integer"}
+            }
+        });
+
+        assert_eq!(
+            query_to_value("ids=1&ids=2&tags=a&page=1&page=2&other=x&other=y", &schema),
+            json!({"ids": [1, 2], "tags": ["a"], "page": [1, 2], "other": ["x", "y"]})
+        );
     }
 }
@@ -42,7 +42,7 @@ impl<T> From<T> for Json<T> {
 #[async_trait]
 impl<T, S, B> FromRequest<S, B> for Json<T>
 where
-    T: crate::validated::Deserialize + 'static,
+    T: crate::validated::Deserialize + serde_valid::Validate + 'static,
     B: http_body::Body + Send + 'static,
     B::Data: Send,
     B::Error: Into<BoxError>,
@@ -0,0 +1,214 @@
+#![cfg_attr(docsrs, feature(doc_auto_cfg))]
+//! A [`Json`](crate::Json) extractor variant that validates the request
+//! with the router state as the validation context.
+//!
+//! # Features
+//!
+//! - aide: support for [aide](https://docs.rs/aide/latest/aide/)
+
+use std::ops::Deref;
+
+use async_trait::async_trait;
+use axum::http::Request;
+use axum::{extract::FromRequest, response::IntoResponse, BoxError};
+use serde::Serialize;
+
+/// Wrapper type over [`axum::Json`] that validates requests
+/// with [`serde_valid::ValidateWith`], passing the router state as the context.
+///
+/// The request type is derived with `#[validate(context = S)]`,
+/// where `S` is the router state type.
+pub struct JsonWith<T>(pub T);
+
+impl<T> Deref for JsonWith<T> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<T> From<T> for JsonWith<T> {
+    fn from(data: T) -> Self {
+        JsonWith(data)
+    }
+}
+
+#[async_trait]
+impl<T, S, B> FromRequest<S, B> for JsonWith<T>
+where
+    T: crate::validated::Deserialize + serde_valid::ValidateWith<S> + 'static,
+    B: http_body::Body + Send + 'static,
+    B::Data: Send,
+    B::Error: Into<BoxError>,
+    S: Send + Sync,
+{
+    type Rejection = crate::rejection::Rejection;
+
+    async fn from_request(req: Request<B>, state: &S) -> Result<Self, Self::Rejection> {
+        crate::request::from_request_with::<_, _, T>(req, state)
+            .await
+            .map(JsonWith)
+    }
+}
+
+impl<T> IntoResponse for JsonWith<T>
+where
+    T: Serialize,
+{
+    fn into_response(self) -> axum::response::Response {
+        axum::Json(self.0).into_response()
+    }
+}
+
+#[cfg(feature = "aide")]
+mod impl_aide {
+    use super::*;
+
+    impl<T> aide::OperationInput for JsonWith<T>
+    where
+        T: schemars::JsonSchema,
+    {
+        fn operation_input(
+            ctx: &mut aide::gen::GenContext,
+            operation: &mut aide::openapi::Operation,
+        ) {
+            axum::Json::<T>::operation_input(ctx, operation);
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::JsonWith;
+    use axum::http::StatusCode;
+    use axum::{
+        body::Body,
+        http::{self, Request},
+    };
+    use serde::Deserialize;
+    use serde_json::json;
+    use serde_valid::Validate;
+    use tower::ServiceExt;
+
+    type TestResult = Result<(), Box<dyn std::error::Error>>;
+
+    #[derive(Clone)]
+    struct AppConfig {
+        max_name_length: usize,
+    }
+
+    #[cfg(all(not(feature = "jsonschema"), not(feature = "aide")))]
+    #[tokio::test]
+    async fn test_json_with() -> TestResult {
+        use axum::{routing::post, Router};
+
+        #[derive(Deserialize, Validate)]
+        #[validate(context = AppConfig)]
+        struct User {
+            #[validate(max_length = ctx.max_name_length)]
+            name: String,
+        }
+
+        let app = Router::new()
+            .route(
+                "/json",
+                post(|_user: JsonWith<User>| async move { "hello" }),
+            )
+            .with_state(AppConfig { max_name_length: 3 });
+
+        let response = app
+            .oneshot(
+                Request::builder()
+                    .method(http::Method::POST)
+                    .uri("/json")
+                    .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
+                    .body(Body::from(serde_json::to_vec(&json!({"name": "taro"}))?))?,
+            )
+            .await?;
+
+        assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+        assert_eq!(
+            serde_json::from_slice::<serde_json::Value>(
+                &hyper::body::to_bytes(response.into_body()).await?[..],
+            )?,
+            json!({"errors": [
+                {
+                    "error": "The length of the value must be `<= 3`.",
+                    "instance_location": "/name",
+                    "keyword_location": null
+                }
+            ]})
+        );
+
+        Ok(())
+    }
+
+    #[cfg(feature = "jsonschema")]
+    #[tokio::test]
+    async fn test_json_with_jsonschema() -> TestResult {
+        use axum::{routing::post, Router};
+
+        #[derive(Deserialize, Validate, schemars::JsonSchema)]
+        #[validate(context = AppConfig)]
+        struct User {
+            #[validate(max_length = ctx.max_name_length)]
+            name: String,
+        }
+
+        let app = Router::new()
+            .route(
+                "/json",
+                post(|_user: JsonWith<User>| async move { "hello" }),
+            )
+            .with_state(AppConfig { max_name_length: 5 });
+
+        let response = app
+            .oneshot(
+                Request::builder()
+                    .method(http::Method::POST)
+                    .uri("/json")
+                    .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
+                    .body(Body::from(serde_json::to_vec(&json!({"name": "taro"}))?))?,
+            )
+            .await?;
+
+        assert_eq!(response.status(), StatusCode::OK);
+
+        Ok(())
+    }
+
+    #[cfg(feature = "aide")]
+    #[tokio::test]
+    async fn test_json_with_aide() -> TestResult {
+        use aide::axum::{routing::post, ApiRouter};
+
+        #[derive(Deserialize, Validate, schemars::JsonSchema)]
+        #[validate(context = AppConfig)]
+        struct User {
+            #[validate(max_length = ctx.max_name_length)]
+            name: String,
+        }
+
+        let app = ApiRouter::new()
+            .route(
+                "/json",
+                post(|_user: JsonWith<User>| async move { "hello" }),
+            )
+            .with_state(AppConfig { max_name_length: 3 });
+
+        let response = app
+            .oneshot(
+                Request::builder()
+                    .method(http::Method::POST)
+                    .uri("/json")
+                    .header(http::header::CONTENT_TYPE, mime::APPLICATION_JSON.as_ref())
+                    .body(Body::from(serde_json::to_vec(&json!({"name": "taro"}))?))?,
+            )
+            .await?;
+
+        assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+
+        Ok(())
+    }
+}
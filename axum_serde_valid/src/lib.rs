@@ -1,11 +1,16 @@
+mod async_json;
 mod features;
 mod json;
 pub mod json_pointer;
+mod json_with;
 mod query;
 pub mod rejection;
 mod request;
 mod validated;
 
+pub use async_json::AsyncJson;
+#[cfg(feature = "jsonschema")]
 pub use features::*;
 pub use json::Json;
+pub use json_with::JsonWith;
 pub use query::Query;
@@ -16,12 +16,15 @@
 use std::ops::Deref;
 
 use async_trait::async_trait;
-use axum::http::Request;
-use axum::{extract::FromRequest, BoxError};
+use axum::extract::FromRequestParts;
+use axum::http::request::Parts;
 
-/// Wrapper type over [`axum::extract::Query`] that validates
-/// requests with a more helpful validation
+/// Wrapper type over [`axum::extract::Query`] that deserializes
+/// the URI query string and validates it with a more helpful validation
 /// message.
+///
+/// With the `jsonschema` feature, `T` is deserialized from the query value that passed the schema,
+/// so repeated keys such as `?tag=a&tag=b` fill a `Vec` field.
 pub struct Query<T>(pub T);
 
 impl<T> Deref for Query<T> {
@@ -39,20 +42,15 @@ impl<T> From<T> for Query<T> {
 }
 
 #[async_trait]
-impl<S, B, T> FromRequest<S, B> for Query<T>
+impl<S, T> FromRequestParts<S> for Query<T>
 where
-    B: http_body::Body + Send + 'static,
-    B::Data: Send,
-    B::Error: Into<BoxError>,
     S: Send + Sync,
-    T: crate::validated::Deserialize + 'static,
+    T: crate::validated::Deserialize + serde_valid::Validate + 'static,
 {
     type Rejection = crate::rejection::Rejection;
 
-    async fn from_request(req: Request<B>, state: &S) -> Result<Self, Self::Rejection> {
-        crate::request::from_request::<_, _, T>(req, state)
-            .await
-            .map(Query)
+    async fn from_request_parts(parts: &mut Parts, _state: &S) -> Result<Self, Self::Rejection> {
+        crate::request::from_query::<T>(&parts.uri).map(Query)
     }
 }
 
@@ -72,3 +70,263 @@ mod impl_aide {
         }
     }
 }
+
+#[cfg(test)]
+mod test {
+    use crate::Query;
+    use axum::http::StatusCode;
+    use axum::{
+        body::Body,
+        http::{self, Request},
+    };
+    use serde::Deserialize;
+    use serde_json::json;
+    use serde_valid::Validate;
+    use tower::ServiceExt;
+
+    type TestResult = Result<(), Box<dyn std::error::Error>>;
+
+    #[cfg(all(not(feature = "jsonschema"), not(feature = "aide")))]
+    #[tokio::test]
+    async fn test_query() -> TestResult {
+        use axum::{routing::get, Router};
+
+        #[derive(Deserialize, Validate)]
+        struct User {
+            #[validate(max_length = 3)]
+            name: String,
+        }
+
+        let app = Router::new().route("/query", get(|_user: Query<User>| async move { "hello" }));
+
+        let response = app
+            .clone()
+            .oneshot(
+                Request::builder()
+                    .method(http::Method::GET)
+                    .uri("/query?name=ken")
+                    .body(Body::empty())?,
+            )
+            .await?;
+
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let response = app
+            .oneshot(
+                Request::builder()
+                    .method(http::Method::GET)
+                    .uri("/query?name=taro")
+                    .body(Body::empty())?,
+            )
+            .await?;
+
+        assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+        assert_eq!(
+            serde_json::from_slice::<serde_json::Value>(
+                &hyper::body::to_bytes(response.into_body()).await?[..],
+            )?,
+            json!({"errors": [
+                {
+                    "error": "The length of the value must be `<= 3`.",
+                    "instance_location": "/name",
+                    "keyword_location": null
+                }
+            ]})
+        );
+
+        Ok(())
+    }
+
+    #[cfg(all(not(feature = "jsonschema"), not(feature = "aide")))]
+    #[tokio::test]
+    async fn test_query_format_error() -> TestResult {
+        use axum::{routing::get, Router};
+
+        #[derive(Deserialize, Validate)]
+        struct Page {
+            #[validate(maximum = 100)]
+            page: u32,
+        }
+
+        let app = Router::new().route("/query", get(|_page: Query<Page>| async move { "hello" }));
+
+        let response = app
+            .oneshot(
+                Request::builder()
+                    .method(http::Method::GET)
+                    .uri("/query?page=abc")
+                    .body(Body::empty())?,
+            )
+            .await?;
+
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+
+        Ok(())
+    }
+
+    #[cfg(feature = "jsonschema")]
+    #[tokio::test]
+    async fn test_query_with_jsonschema() -> TestResult {
+        use axum::{routing::get, Router};
+
+        #[derive(Deserialize, Validate, schemars::JsonSchema)]
+        struct Page {
+            #[validate(maximum = 100)]
+            page: u32,
+            active: Option<bool>,
+        }
+
+        let app = Router::new().route(
+            "/query",
+            get(|page: Query<Page>| async move { format!("{} {:?}", page.page, page.active) }),
+        );
+
+        let response = app
+            .clone()
+            .oneshot(
+                Request::builder()
+                    .method(http::Method::GET)
+                    .uri("/query?page=10&active=true")
+                    .body(Body::empty())?,
+            )
+            .await?;
+
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let response = app
+            .clone()
+            .oneshot(
+                Request::builder()
+                    .method(http::Method::GET)
+                    .uri("/query?page=abc")
+                    .body(Body::empty())?,
+            )
+            .await?;
+
+        assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+        assert_eq!(
+            serde_json::from_slice::<serde_json::Value>(
+                &hyper::body::to_bytes(response.into_body()).await?[..],
+            )?["errors"][0]["instance_location"],
+            json!("/page")
+        );
+
+        let response = app
+            .oneshot(
+                Request::builder()
+                    .method(http::Method::GET)
+                    .uri("/query?page=200")
+                    .body(Body::empty())?,
+            )
+            .await?;
+
+        assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+        assert_eq!(
+            serde_json::from_slice::<serde_json::Value>(
+                &hyper::body::to_bytes(response.into_body()).await?[..],
+            )?,
+            json!({"errors": [
+                {
+                    "error": "The number must be `<= 100`.",
+                    "instance_location": "/page",
+                    "keyword_location": null
+                }
+            ]})
+        );
+
+        Ok(())
+    }
+
+    #[cfg(feature = "jsonschema")]
+    #[tokio::test]
+    async fn test_query_with_jsonschema_array() -> TestResult {
+        use axum::{routing::get, Router};
+
+        #[derive(Deserialize, Validate, schemars::JsonSchema)]
+        struct Filter {
+            #[validate(max_items = 2)]
+            tag: Vec<String>,
+            id: Vec<u32>,
+        }
+
+        let app = Router::new().route(
+            "/query",
+            get(|filter: Query<Filter>| async move { format!("{:?} {:?}", filter.tag, filter.id) }),
+        );
+
+        let response = app
+            .clone()
+            .oneshot(
+                Request::builder()
+                    .method(http::Method::GET)
+                    .uri("/query?tag=a&tag=b&id=1")
+                    .body(Body::empty())?,
+            )
+            .await?;
+
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(
+            &hyper::body::to_bytes(response.into_body()).await?[..],
+            br#"["a", "b"] [1]"#
+        );
+
+        let response = app
+            .oneshot(
+                Request::builder()
+                    .method(http::Method::GET)
+                    .uri("/query?tag=a&tag=b&tag=c&id=1")
+                    .body(Body::empty())?,
+            )
+            .await?;
+
+        assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+        assert_eq!(
+            serde_json::from_slice::<serde_json::Value>(
+                &hyper::body::to_bytes(response.into_body()).await?[..],
+            )?["errors"][0]["instance_location"],
+            json!("/tag")
+        );
+
+        Ok(())
+    }
+
+    #[cfg(feature = "aide")]
+    #[tokio::test]
+    async fn test_query_with_aide() -> TestResult {
+        use aide::axum::{routing::get, ApiRouter};
+
+        #[derive(Deserialize, Validate, schemars::JsonSchema)]
+        struct User {
+            #[validate(max_length = 3)]
+            name: String,
+        }
+
+        let app =
+            ApiRouter::new().route("/query", get(|_user: Query<User>| async move { "hello" }));
+
+        let response = app
+            .oneshot(
+                Request::builder()
+                    .method(http::Method::GET)
+                    .uri("/query?name=taro")
+                    .body(Body::empty())?,
+            )
+            .await?;
+
+        assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+        assert_eq!(
+            serde_json::from_slice::<serde_json::Value>(
+                &hyper::body::to_bytes(response.into_body()).await?[..],
+            )?,
+            json!({"errors": [
+                {
+                    "error": "The length of the value must be `<= 3`.",
+                    "instance_location": "/name",
+                    "keyword_location": null
+                }
+            ]})
+        );
+
+        Ok(())
+    }
+}
@@ -1,14 +1,20 @@
-use axum::{extract::rejection::JsonRejection, http::StatusCode, response::IntoResponse};
+use axum::{
+    extract::rejection::{JsonRejection, QueryRejection},
+    http::StatusCode,
+    response::IntoResponse,
+};
 use serde::Serialize;
 use serde_valid::flatten::IntoFlat;
 
 use crate::json_pointer::JsonPointer;
 
-/// Rejection for [`Json`].
+/// Rejection for [`Json`] and [`Query`].
 #[derive(Debug)]
 pub enum Rejection {
     /// A rejection returned by [`axum::Json`].
     Json(JsonRejection),
+    /// A rejection returned by [`axum::extract::Query`].
+    Query(QueryRejection),
     /// A serde error.
     Serde(serde_json::Error),
     /// A serde_valid validation error.
@@ -68,6 +74,7 @@ impl From<Rejection> for JsonErrorResponse {
     fn from(rejection: Rejection) -> Self {
         match rejection {
             Rejection::Json(v) => Self::FormatError(v.to_string()),
+            Rejection::Query(v) => Self::FormatError(v.to_string()),
             Rejection::Serde(_) => Self::FormatError("invalid request".to_string()),
             Rejection::SerdeValid(errors) => Self::ValidationError(JsonSchemaErrorResponse {
                 errors: errors
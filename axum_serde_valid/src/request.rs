@@ -7,6 +7,61 @@ pub async fn from_request<S, B, T>(
     req: axum::http::Request<B>,
     state: &S,
 ) -> Result<T, crate::rejection::Rejection>
+where
+    B: http_body::Body + Send + 'static,
+    B::Data: Send,
+    B::Error: Into<BoxError>,
+    S: Send + Sync,
+    T: crate::validated::Deserialize + serde_valid::Validate + 'static,
+{
+    let v = deserialize_request::<S, B, T>(req, state).await?;
+    v.validate()
+        .map_err(crate::rejection::Rejection::SerdeValid)?;
+
+    Ok(v)
+}
+
+pub async fn from_request_with<S, B, T>(
+    req: axum::http::Request<B>,
+    state: &S,
+) -> Result<T, crate::rejection::Rejection>
+where
+    B: http_body::Body + Send + 'static,
+    B::Data: Send,
+    B::Error: Into<BoxError>,
+    S: Send + Sync,
+    T: crate::validated::Deserialize + serde_valid::ValidateWith<S> + 'static,
+{
+    let v = deserialize_request::<S, B, T>(req, state).await?;
+    v.validate_with(state)
+        .map_err(crate::rejection::Rejection::SerdeValid)?;
+
+    Ok(v)
+}
+
+pub async fn from_request_async<S, B, T>(
+    req: axum::http::Request<B>,
+    state: &S,
+) -> Result<T, crate::rejection::Rejection>
+where
+    B: http_body::Body + Send + 'static,
+    B::Data: Send,
+    B::Error: Into<BoxError>,
+    S: Send + Sync,
+    T: crate::validated::Deserialize + serde_valid::AsyncValidate + Send + 'static,
+{
+    let v = deserialize_request::<S, B, T>(req, state).await?;
+    v.validate_async()
+        .await
+        .map_err(crate::rejection::Rejection::SerdeValid)?;
+
+    Ok(v)
+}
+
+async fn deserialize_request<S, B, T>(
+    req: axum::http::Request<B>,
+    state: &S,
+) -> Result<T, crate::rejection::Rejection>
 where
     B: http_body::Body + Send + 'static,
     B::Data: Send,
@@ -25,20 +80,54 @@ where
             .map_err(crate::rejection::Rejection::Jsonschema)?;
     }
 
-    match serde_json::from_value::<T>(value) {
-        Ok(v) => {
-            v.validate()
-                .map_err(crate::rejection::Rejection::SerdeValid)?;
-
-            Ok(v)
-        }
-        Err(error) => {
-            tracing::error!(
-                %error,
-                type_name = type_name::<T>(),
-                "schema validation passed but serde failed"
-            );
-            Err(crate::rejection::Rejection::Serde(error))
-        }
-    }
+    serde_json::from_value::<T>(value).map_err(|error| {
+        tracing::error!(
+            %error,
+            type_name = type_name::<T>(),
+            "schema validation passed but serde failed"
+        );
+        crate::rejection::Rejection::Serde(error)
+    })
+}
+
+pub fn from_query<T>(uri: &axum::http::Uri) -> Result<T, crate::rejection::Rejection>
+where
+    T: crate::validated::Deserialize + serde_valid::Validate + 'static,
+{
+    let v = deserialize_query::<T>(uri)?;
+    v.validate()
+        .map_err(crate::rejection::Rejection::SerdeValid)?;
+
+    Ok(v)
+}
+
+/// Deserialize the value that passed the schema, so that the arrays of repeated keys reach `T`.
+#[cfg(feature = "jsonschema")]
+fn deserialize_query<T>(uri: &axum::http::Uri) -> Result<T, crate::rejection::Rejection>
+where
+    T: crate::validated::Deserialize + 'static,
+{
+    let value = crate::jsonschema::context::SchemaContext::validate_query::<T>(
+        uri.query().unwrap_or_default(),
+    )
+    .map_err(crate::rejection::Rejection::Jsonschema)?;
+
+    serde_json::from_value::<T>(value).map_err(|error| {
+        tracing::error!(
+            %error,
+            type_name = type_name::<T>(),
+            "schema validation passed but serde failed"
+        );
+        crate::rejection::Rejection::Serde(error)
+    })
+}
+
+#[cfg(not(feature = "jsonschema"))]
+fn deserialize_query<T>(uri: &axum::http::Uri) -> Result<T, crate::rejection::Rejection>
+where
+    T: crate::validated::Deserialize + 'static,
+{
+    axum::extract::Query::<T>::try_from_uri(uri)
+        .map(|axum::extract::Query(v)| v)
+        .map_err(crate::rejection::Rejection::Query)
 }
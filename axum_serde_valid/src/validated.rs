@@ -1,21 +1,15 @@
 #[cfg(not(any(feature = "jsonschema", feature = "aide")))]
 mod deserialize {
-    pub trait Deserialize: serde::de::DeserializeOwned + serde_valid::Validate {}
+    pub trait Deserialize: serde::de::DeserializeOwned {}
 
-    impl<T> Deserialize for T where T: serde::de::DeserializeOwned + serde_valid::Validate {}
+    impl<T> Deserialize for T where T: serde::de::DeserializeOwned {}
 }
 
 #[cfg(any(feature = "jsonschema", feature = "aide"))]
 mod deserialize {
-    pub trait Deserialize:
-        serde::de::DeserializeOwned + serde_valid::Validate + schemars::JsonSchema
-    {
-    }
+    pub trait Deserialize: serde::de::DeserializeOwned + schemars::JsonSchema {}
 
-    impl<T> Deserialize for T where
-        T: serde::de::DeserializeOwned + serde_valid::Validate + schemars::JsonSchema
-    {
-    }
+    impl<T> Deserialize for T where T: serde::de::DeserializeOwned + schemars::JsonSchema {}
 }
 
 pub use deserialize::Deserialize;
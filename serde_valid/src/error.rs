@@ -1,8 +1,9 @@
 use itertools::Itertools;
 use serde_valid_literal::Literal;
 
-use crate::validation::ToDefaultMessage;
-use crate::validation::{Number, Pattern};
+use crate::message_catalog::{with_default_messages, BuiltinMessage};
+use crate::validation::{ErrorCode, ToDefaultMessage};
+use crate::validation::{Number, Pattern, VecErrors};
 
 #[derive(Debug, thiserror::Error)]
 pub enum Error<E>
@@ -53,18 +54,33 @@ macro_rules! struct_error_params {
     (
         #[derive(Debug, Clone)]
         #[default_message=$default_message:literal]
+        #[code=$code:ident]
         pub struct $Error:ident {
             pub $limit:ident: Vec<$type:ty>,
         }
     ) => {
-        #[derive(Debug, Clone)]
+        #[derive(Debug, Clone, serde::Serialize)]
         pub struct $Error {
             pub $limit: Vec<$type>,
         }
 
+        impl ErrorCode for $Error {
+            #[inline]
+            fn code(&self) -> &'static str {
+                stringify!($code)
+            }
+        }
+
         impl ToDefaultMessage for $Error {
             #[inline]
             fn to_default_message(&self) -> String {
+                with_default_messages(|catalog| catalog.$code(self))
+            }
+        }
+
+        impl BuiltinMessage for $Error {
+            #[inline]
+            fn builtin_message(&self) -> String {
                 format!(
                     $default_message,
                     self.enumerate.iter().map(|v| format!("{}", v)).join(", ")
@@ -76,11 +92,12 @@ macro_rules! struct_error_params {
     (
         #[derive(Debug, Clone)]
         #[default_message=$default_message:literal]
+        #[code=$code:ident]
         pub struct $Error:ident {
             pub $limit:ident: $type:ty,
         }
     ) => {
-        #[derive(Debug, Clone)]
+        #[derive(Debug, Clone, serde::Serialize)]
         pub struct $Error {
             pub $limit: $type,
         }
@@ -93,9 +110,23 @@ macro_rules! struct_error_params {
             }
         }
 
+        impl ErrorCode for $Error {
+            #[inline]
+            fn code(&self) -> &'static str {
+                stringify!($code)
+            }
+        }
+
         impl ToDefaultMessage for $Error {
             #[inline]
             fn to_default_message(&self) -> String {
+                with_default_messages(|catalog| catalog.$code(self))
+            }
+        }
+
+        impl BuiltinMessage for $Error {
+            #[inline]
+            fn builtin_message(&self) -> String {
                 format!($default_message, self.$limit)
             }
         }
@@ -104,15 +135,30 @@ macro_rules! struct_error_params {
     (
         #[derive(Debug, Clone)]
         #[default_message=$default_message:literal]
+        #[code=$code:ident]
         pub struct $Error:ident {
         }
     ) => {
-        #[derive(Debug, Clone)]
+        #[derive(Debug, Clone, serde::Serialize)]
         pub struct $Error {}
 
+        impl ErrorCode for $Error {
+            #[inline]
+            fn code(&self) -> &'static str {
+                stringify!($code)
+            }
+        }
+
         impl ToDefaultMessage for $Error {
             #[inline]
             fn to_default_message(&self) -> String {
+                with_default_messages(|catalog| catalog.$code(self))
+            }
+        }
+
+        impl BuiltinMessage for $Error {
+            #[inline]
+            fn builtin_message(&self) -> String {
                 format!($default_message)
             }
         }
@@ -123,6 +169,7 @@ macro_rules! struct_error_params {
 struct_error_params!(
     #[derive(Debug, Clone)]
     #[default_message = "The number must be `>= {}`."]
+    #[code = minimum]
     pub struct MinimumError {
         pub minimum: Number,
     }
@@ -131,6 +178,7 @@ struct_error_params!(
 struct_error_params!(
     #[derive(Debug, Clone)]
     #[default_message = "The number must be `<= {}`."]
+    #[code = maximum]
     pub struct MaximumError {
         pub maximum: Number,
     }
@@ -139,6 +187,7 @@ struct_error_params!(
 struct_error_params!(
     #[derive(Debug, Clone)]
     #[default_message = "The number must be `> {}`."]
+    #[code = exclusive_minimum]
     pub struct ExclusiveMinimumError {
         pub exclusive_minimum: Number,
     }
@@ -147,6 +196,7 @@ struct_error_params!(
 struct_error_params!(
     #[derive(Debug, Clone)]
     #[default_message = "The number must be `< {}`."]
+    #[code = exclusive_maximum]
     pub struct ExclusiveMaximumError {
         pub exclusive_maximum: Number,
     }
@@ -155,6 +205,7 @@ struct_error_params!(
 struct_error_params!(
     #[derive(Debug, Clone)]
     #[default_message = "The value must be multiple of `{}`."]
+    #[code = multiple_of]
     pub struct MultipleOfError {
         pub multiple_of: Number,
     }
@@ -164,6 +215,7 @@ struct_error_params!(
 struct_error_params!(
     #[derive(Debug, Clone)]
     #[default_message = "The length of the value must be `>= {}`."]
+    #[code = min_length]
     pub struct MinLengthError {
         pub min_length: usize,
     }
@@ -172,6 +224,7 @@ struct_error_params!(
 struct_error_params!(
     #[derive(Debug, Clone)]
     #[default_message = "The length of the value must be `<= {}`."]
+    #[code = max_length]
     pub struct MaxLengthError {
         pub max_length: usize,
     }
@@ -180,15 +233,26 @@ struct_error_params!(
 struct_error_params!(
     #[derive(Debug, Clone)]
     #[default_message = "The value must match the pattern of \"{}\"."]
+    #[code = pattern]
     pub struct PatternError {
         pub pattern: Pattern,
     }
 );
 
+struct_error_params!(
+    #[derive(Debug, Clone)]
+    #[default_message = "The value must match the format of \"{}\"."]
+    #[code = format]
+    pub struct FormatError {
+        pub format: String,
+    }
+);
+
 // Array
 struct_error_params!(
     #[derive(Debug, Clone)]
     #[default_message = "The length of the items must be `<= {}`."]
+    #[code = max_items]
     pub struct MaxItemsError {
         pub max_items: usize,
     }
@@ -197,21 +261,76 @@ struct_error_params!(
 struct_error_params!(
     #[derive(Debug, Clone)]
     #[default_message = "The length of the items must be `>= {}`."]
+    #[code = min_items]
     pub struct MinItemsError {
         pub min_items: usize,
     }
 );
 
+/// The indices of the duplicate items are error params,
+/// but not a part of the default message.
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct UniqueItemsError {
+    pub duplicates: Vec<usize>,
+}
+
+impl UniqueItemsError {
+    pub fn new(duplicates: Vec<usize>) -> Self {
+        Self { duplicates }
+    }
+}
+
+impl ErrorCode for UniqueItemsError {
+    #[inline]
+    fn code(&self) -> &'static str {
+        "unique_items"
+    }
+}
+
+impl ToDefaultMessage for UniqueItemsError {
+    #[inline]
+    fn to_default_message(&self) -> String {
+        with_default_messages(|catalog| catalog.unique_items(self))
+    }
+}
+
+impl BuiltinMessage for UniqueItemsError {
+    #[inline]
+    fn builtin_message(&self) -> String {
+        "The items must be unique.".to_string()
+    }
+}
+
+struct_error_params!(
+    #[derive(Debug, Clone)]
+    #[default_message = "The items must contain at least one matching item."]
+    #[code = contains]
+    pub struct ContainsError {}
+);
+
 struct_error_params!(
     #[derive(Debug, Clone)]
-    #[default_message = "The items must be unique."]
-    pub struct UniqueItemsError {}
+    #[default_message = "The number of matching items must be `>= {}`."]
+    #[code = min_contains]
+    pub struct MinContainsError {
+        pub min_contains: usize,
+    }
+);
+
+struct_error_params!(
+    #[derive(Debug, Clone)]
+    #[default_message = "The number of matching items must be `<= {}`."]
+    #[code = max_contains]
+    pub struct MaxContainsError {
+        pub max_contains: usize,
+    }
 );
 
 // Object
 struct_error_params!(
     #[derive(Debug, Clone)]
     #[default_message = "The size of the properties must be `<= {}`."]
+    #[code = max_properties]
     pub struct MaxPropertiesError {
         pub max_properties: usize,
     }
@@ -220,15 +339,188 @@ struct_error_params!(
 struct_error_params!(
     #[derive(Debug, Clone)]
     #[default_message = "The size of the properties must be `>= {}`."]
+    #[code = min_properties]
     pub struct MinPropertiesError {
         pub min_properties: usize,
     }
 );
 
+// Field comparison
+struct_error_params!(
+    #[derive(Debug, Clone)]
+    #[default_message = "The value must be `> {}`."]
+    #[code = greater_than]
+    pub struct GreaterThanError {
+        pub greater_than: String,
+    }
+);
+
+struct_error_params!(
+    #[derive(Debug, Clone)]
+    #[default_message = "The value must be `>= {}`."]
+    #[code = greater_than_or_equal]
+    pub struct GreaterThanOrEqualError {
+        pub greater_than_or_equal: String,
+    }
+);
+
+struct_error_params!(
+    #[derive(Debug, Clone)]
+    #[default_message = "The value must be `< {}`."]
+    #[code = less_than]
+    pub struct LessThanError {
+        pub less_than: String,
+    }
+);
+
+struct_error_params!(
+    #[derive(Debug, Clone)]
+    #[default_message = "The value must be `<= {}`."]
+    #[code = less_than_or_equal]
+    pub struct LessThanOrEqualError {
+        pub less_than_or_equal: String,
+    }
+);
+
+struct_error_params!(
+    #[derive(Debug, Clone)]
+    #[default_message = "The value must be `== {}`."]
+    #[code = equals]
+    pub struct EqualsError {
+        pub equals: String,
+    }
+);
+
+struct_error_params!(
+    #[derive(Debug, Clone)]
+    #[default_message = "The value must be `!= {}`."]
+    #[code = not_equals]
+    pub struct NotEqualsError {
+        pub not_equals: String,
+    }
+);
+
+// Composition
+struct_error_params!(
+    #[derive(Debug, Clone)]
+    #[default_message = "The value must match any of the validations: {}"]
+    #[code = any_of]
+    pub struct AnyOfError {
+        pub any_of: BranchErrors,
+    }
+);
+
+struct_error_params!(
+    #[derive(Debug, Clone)]
+    #[default_message = "The value must match all of the validations: {}"]
+    #[code = all_of]
+    pub struct AllOfError {
+        pub all_of: BranchErrors,
+    }
+);
+
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct OneOfError {
+    pub one_of: BranchErrors,
+}
+
+impl OneOfError {
+    pub fn new<N: Into<BranchErrors>>(one_of: N) -> Self {
+        Self {
+            one_of: one_of.into(),
+        }
+    }
+}
+
+impl ErrorCode for OneOfError {
+    #[inline]
+    fn code(&self) -> &'static str {
+        "one_of"
+    }
+}
+
+impl ToDefaultMessage for OneOfError {
+    #[inline]
+    fn to_default_message(&self) -> String {
+        with_default_messages(|catalog| catalog.one_of(self))
+    }
+}
+
+impl BuiltinMessage for OneOfError {
+    #[inline]
+    fn builtin_message(&self) -> String {
+        match self.one_of.matched_count() {
+            0 => format!(
+                "The value must match exactly one of the validations: {}",
+                self.one_of
+            ),
+            matched => format!(
+                "The value must match exactly one of the validations, but {matched} matched."
+            ),
+        }
+    }
+}
+
+struct_error_params!(
+    #[derive(Debug, Clone)]
+    #[default_message = "The value must not match the validation."]
+    #[code = not]
+    pub struct NotError {}
+);
+
+/// Errors of each branch of `any_of`, `all_of` and `one_of`,
+/// which are empty for the branches that matched.
+///
+/// Displayed as the messages of the failed branches, separated by ` / `.
+#[derive(Debug, Clone, Default)]
+pub struct BranchErrors(pub Vec<VecErrors>);
+
+impl BranchErrors {
+    pub fn matched_count(&self) -> usize {
+        self.0.iter().filter(|errors| errors.is_empty()).count()
+    }
+}
+
+impl From<Vec<VecErrors>> for BranchErrors {
+    fn from(branches: Vec<VecErrors>) -> Self {
+        Self(branches)
+    }
+}
+
+impl std::fmt::Display for BranchErrors {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            self.0
+                .iter()
+                .filter(|errors| !errors.is_empty())
+                .map(|errors| errors.iter().join(" "))
+                .join(" / ")
+        )
+    }
+}
+
+/// Each branch as a list of [`StructuredError`](crate::validation::StructuredError)s.
+impl serde::Serialize for BranchErrors {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_seq(self.0.iter().map(|errors| {
+            errors
+                .iter()
+                .map(crate::validation::Error::to_structured)
+                .collect::<Vec<_>>()
+        }))
+    }
+}
+
 // Generic
 struct_error_params!(
     #[derive(Debug, Clone)]
     #[default_message = "The value must be in [{:}]."]
+    #[code = enumerate]
     pub struct EnumerateError {
         pub enumerate: Vec<Literal>,
     }
@@ -244,3 +536,60 @@ impl EnumerateError {
         }
     }
 }
+
+// Schema
+/// The JSON types allowed by the `type` keyword of a runtime [`Schema`](crate::schema::Schema).
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct TypeError {
+    pub types: Vec<&'static str>,
+}
+
+impl TypeError {
+    pub fn new(types: Vec<&'static str>) -> Self {
+        Self { types }
+    }
+}
+
+impl ErrorCode for TypeError {
+    #[inline]
+    fn code(&self) -> &'static str {
+        "type"
+    }
+}
+
+impl ToDefaultMessage for TypeError {
+    #[inline]
+    fn to_default_message(&self) -> String {
+        with_default_messages(|catalog| catalog.type_(self))
+    }
+}
+
+impl BuiltinMessage for TypeError {
+    #[inline]
+    fn builtin_message(&self) -> String {
+        format!("The value must be of type `{}`.", self.types.join("` or `"))
+    }
+}
+
+struct_error_params!(
+    #[derive(Debug, Clone)]
+    #[default_message = "The property is required."]
+    #[code = required]
+    pub struct RequiredError {}
+);
+
+struct_error_params!(
+    #[derive(Debug, Clone)]
+    #[default_message = "The property `{}` is not allowed."]
+    #[code = additional_properties]
+    pub struct AdditionalPropertiesError {
+        pub additional_properties: String,
+    }
+);
+
+struct_error_params!(
+    #[derive(Debug, Clone)]
+    #[default_message = "The value is not allowed."]
+    #[code = false_schema]
+    pub struct FalseSchemaError {}
+);
@@ -1,30 +1,47 @@
 use jsonschema::paths::{JSONPointer, PathChunk};
 use serde::Serialize;
+use serde_json::{Map, Value};
+
+use crate::validation::StructuredError;
 
 #[derive(Debug, PartialEq, Eq, Serialize)]
 pub struct FlatError {
     pub error: String,
+    pub code: &'static str,
+    pub params: Map<String, Value>,
     pub instance_location: JSONPointer,
 }
 
 impl FlatError {
+    /// A flat error with only a message, which has the `custom` code like
+    /// [`Error::Custom`](crate::validation::Error::Custom).
     pub fn new(instance_location: impl Into<JSONPointer>, error: String) -> Self {
+        Self::structured(
+            instance_location,
+            StructuredError::new("custom", Map::new(), error),
+        )
+    }
+
+    /// A flat error with the code and params of `error`.
+    pub fn structured(instance_location: impl Into<JSONPointer>, error: StructuredError) -> Self {
         Self {
-            error,
+            error: error.message,
+            code: error.code,
+            params: error.params,
             instance_location: instance_location.into(),
         }
     }
 
     pub fn merge_childs(self, instance_location: impl IntoIterator<Item = PathChunk>) -> Self {
-        Self::new(
-            JSONPointer::from(
+        Self {
+            instance_location: JSONPointer::from(
                 instance_location
                     .into_iter()
-                    .chain(self.instance_location.into_iter())
+                    .chain(self.instance_location)
                     .collect::<Vec<_>>()
                     .as_slice(),
             ),
-            self.error,
-        )
+            ..self
+        }
     }
 }
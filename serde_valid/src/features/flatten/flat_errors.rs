@@ -73,6 +73,8 @@ mod tests {
                 "errors": [
                     {
                         "error": "The length of the items must be `>= 1`.",
+                        "code": "min_items",
+                        "params": {"min_items": 1},
                         "instance_location": "",
                     }
                 ]
@@ -1,8 +1,9 @@
 use jsonschema::paths::{JSONPointer, PathChunk};
 
 use crate::validation::{
-    ArrayErrors, ItemErrorsMap, Message, ObjectErrors, PropertyErrorsMap, ToDefaultMessage,
+    ArrayErrors, ErrorCode, ItemErrorsMap, Message, ObjectErrors, PropertyErrorsMap,
 };
+use crate::UniqueItemsError;
 
 use super::{FlatError, FlatErrors};
 
@@ -41,25 +42,73 @@ impl IntoFlat for crate::validation::Error {
             crate::validation::Error::MinLength(inner) => inner.into_flat_at(path),
             crate::validation::Error::MaxLength(inner) => inner.into_flat_at(path),
             crate::validation::Error::Pattern(inner) => inner.into_flat_at(path),
+            crate::validation::Error::Format(inner) => inner.into_flat_at(path),
             crate::validation::Error::MinItems(inner) => inner.into_flat_at(path),
             crate::validation::Error::MaxItems(inner) => inner.into_flat_at(path),
-            crate::validation::Error::UniqueItems(inner) => inner.into_flat_at(path),
+            crate::validation::Error::UniqueItems(inner) => unique_items_into_flat_at(inner, path),
+            crate::validation::Error::Contains(inner) => inner.into_flat_at(path),
+            crate::validation::Error::MinContains(inner) => inner.into_flat_at(path),
+            crate::validation::Error::MaxContains(inner) => inner.into_flat_at(path),
             crate::validation::Error::MinProperties(inner) => inner.into_flat_at(path),
             crate::validation::Error::MaxProperties(inner) => inner.into_flat_at(path),
             crate::validation::Error::Enumerate(inner) => inner.into_flat_at(path),
+            crate::validation::Error::GreaterThan(inner) => inner.into_flat_at(path),
+            crate::validation::Error::GreaterThanOrEqual(inner) => inner.into_flat_at(path),
+            crate::validation::Error::LessThan(inner) => inner.into_flat_at(path),
+            crate::validation::Error::LessThanOrEqual(inner) => inner.into_flat_at(path),
+            crate::validation::Error::Equals(inner) => inner.into_flat_at(path),
+            crate::validation::Error::NotEquals(inner) => inner.into_flat_at(path),
+            crate::validation::Error::AnyOf(inner) => inner.into_flat_at(path),
+            crate::validation::Error::AllOf(inner) => inner.into_flat_at(path),
+            crate::validation::Error::OneOf(inner) => inner.into_flat_at(path),
+            crate::validation::Error::Not(inner) => inner.into_flat_at(path),
+            crate::validation::Error::Type(inner) => inner.into_flat_at(path),
+            crate::validation::Error::Required(inner) => inner.into_flat_at(path),
+            crate::validation::Error::AdditionalProperties(inner) => inner.into_flat_at(path),
+            crate::validation::Error::FalseSchema(inner) => inner.into_flat_at(path),
             crate::validation::Error::Items(inner) => inner.into_flat_at(path),
             crate::validation::Error::Properties(inner) => inner.into_flat_at(path),
-            crate::validation::Error::Custom(inner) => {
-                FlatErrors::new(vec![FlatError::new(path.to_owned(), inner)])
-            }
+            crate::validation::Error::Custom(_) => FlatErrors::new(vec![FlatError::structured(
+                path.to_owned(),
+                self.to_structured(),
+            )]),
             #[cfg(feature = "fluent")]
-            crate::validation::Error::Fluent(inner) => {
-                FlatErrors::new(vec![FlatError::new(path.to_owned(), inner.id.to_string())])
-            }
+            crate::validation::Error::Fluent(_) => FlatErrors::new(vec![FlatError::structured(
+                path.to_owned(),
+                self.to_structured(),
+            )]),
         }
     }
 }
 
+/// Point at each duplicate item, or at the array when no duplicates are listed.
+fn unique_items_into_flat_at(inner: Message<UniqueItemsError>, path: &JSONPointer) -> FlatErrors {
+    if inner.inner().duplicates.is_empty() {
+        return inner.into_flat_at(path);
+    }
+
+    let error = inner.to_structured();
+    FlatErrors::new(
+        inner
+            .inner()
+            .duplicates
+            .iter()
+            .map(|index| {
+                FlatError::structured(
+                    JSONPointer::from(
+                        path.clone()
+                            .into_iter()
+                            .chain([PathChunk::Index(*index)])
+                            .collect::<Vec<_>>()
+                            .as_slice(),
+                    ),
+                    error.clone(),
+                )
+            })
+            .collect::<Vec<_>>(),
+    )
+}
+
 impl<E> IntoFlat for Vec<E>
 where
     E: IntoFlat,
@@ -109,10 +158,13 @@ where
 
 impl<T> IntoFlat for Message<T>
 where
-    T: ToDefaultMessage,
+    T: ErrorCode + serde::Serialize,
 {
     fn into_flat_at(self, path: &JSONPointer) -> FlatErrors {
-        FlatErrors::new(vec![FlatError::new(path.to_owned(), self.error())])
+        FlatErrors::new(vec![FlatError::structured(
+            path.to_owned(),
+            self.to_structured(),
+        )])
     }
 }
 
@@ -160,7 +212,7 @@ mod tests {
     use super::*;
 
     use crate::{
-        validation::{Error, Errors},
+        validation::{Error, Errors, ToDefaultMessage},
         MaximumError, MinItemsError,
     };
 
@@ -193,7 +245,7 @@ mod tests {
                         ObjectErrors {
                             errors: vec![Error::Maximum(maximum.clone())],
                             properties: indexmap! {
-                                "name" => Errors::NewType(vec![Error::Maximum(maximum.clone())]),
+                                "name".into() => Errors::NewType(vec![Error::Maximum(maximum.clone())]),
                             }
                         }
                     ),
@@ -202,33 +254,80 @@ mod tests {
             })
             .into_flat(),
             FlatErrors::new(vec![
-                FlatError::new(
+                FlatError::structured(
                     JSONPointer::default(),
-                    min_items.error().to_default_message(),
+                    min_items.to_structured(),
                 ),
-                FlatError::new(
+                FlatError::structured(
                     JSONPointer::from([PathChunk::from(0)].as_ref()),
-                    maximum.error().to_default_message(),
+                    maximum.to_structured(),
                 ),
-                FlatError::new(
+                FlatError::structured(
                     JSONPointer::from([PathChunk::from(0), PathChunk::from(2)].as_ref()),
-                    maximum.error().to_default_message(),
+                    maximum.to_structured(),
                 ),
-                FlatError::new(
+                FlatError::structured(
                     JSONPointer::from([PathChunk::from(3)].as_ref()),
-                    maximum.error().to_default_message(),
+                    maximum.to_structured(),
                 ),
-                FlatError::new(
+                FlatError::structured(
                     JSONPointer::from([PathChunk::from(5)].as_ref()),
-                    maximum.error().to_default_message(),
+                    maximum.to_structured(),
                 ),
-                FlatError::new(
+                FlatError::structured(
                     JSONPointer::from(
                         [PathChunk::from(5), PathChunk::from("name".to_owned())].as_ref()
                     ),
-                    maximum.error().to_default_message(),
+                    maximum.to_structured(),
                 )
             ])
         );
     }
+
+    #[test]
+    fn object_errors_flatten_with_owned_keys() {
+        let maximum = Message::new(
+            MaximumError {
+                maximum: Number::I32(60),
+            },
+            MaximumError::to_default_message,
+        );
+        let key = String::from("timeout");
+
+        assert_eq!(
+            Errors::Object(ObjectErrors {
+                errors: vec![],
+                properties: indexmap! {
+                    "settings".into() => Errors::Object(ObjectErrors {
+                        errors: vec![],
+                        properties: indexmap! {
+                            key.into() => Errors::NewType(vec![Error::Maximum(maximum.clone())]),
+                        },
+                    }),
+                },
+            })
+            .into_flat(),
+            FlatErrors::new(vec![FlatError::structured(
+                JSONPointer::from(
+                    [
+                        PathChunk::from("settings".to_owned()),
+                        PathChunk::from("timeout".to_owned())
+                    ]
+                    .as_ref()
+                ),
+                maximum.to_structured(),
+            )])
+        );
+    }
+
+    #[test]
+    fn custom_error_flattens_to_message_only_flat_error() {
+        assert_eq!(
+            Errors::NewType(vec![Error::Custom("invalid value.".to_owned())]).into_flat(),
+            FlatErrors::new(vec![FlatError::new(
+                JSONPointer::default(),
+                "invalid value.".to_owned()
+            )])
+        );
+    }
 }
@@ -1,5 +1,5 @@
-mod message;
 mod into_localization;
+mod message;
 
-pub use message::Message;
 pub use into_localization::IntoLocalization;
+pub use message::Message;
@@ -87,12 +87,30 @@ impl IntoLocalization for crate::validation::Error {
             Self::MinLength(message) => localize_or_default(&message, bundle),
             Self::MaxLength(message) => localize_or_default(&message, bundle),
             Self::Pattern(message) => localize_or_default(&message, bundle),
+            Self::Format(message) => localize_or_default(&message, bundle),
             Self::MinItems(message) => localize_or_default(&message, bundle),
             Self::MaxItems(message) => localize_or_default(&message, bundle),
             Self::UniqueItems(message) => localize_or_default(&message, bundle),
+            Self::Contains(message) => localize_or_default(&message, bundle),
+            Self::MinContains(message) => localize_or_default(&message, bundle),
+            Self::MaxContains(message) => localize_or_default(&message, bundle),
             Self::MinProperties(message) => localize_or_default(&message, bundle),
             Self::MaxProperties(message) => localize_or_default(&message, bundle),
             Self::Enumerate(message) => localize_or_default(&message, bundle),
+            Self::GreaterThan(message) => localize_or_default(&message, bundle),
+            Self::GreaterThanOrEqual(message) => localize_or_default(&message, bundle),
+            Self::LessThan(message) => localize_or_default(&message, bundle),
+            Self::LessThanOrEqual(message) => localize_or_default(&message, bundle),
+            Self::Equals(message) => localize_or_default(&message, bundle),
+            Self::NotEquals(message) => localize_or_default(&message, bundle),
+            Self::AnyOf(message) => localize_or_default(&message, bundle),
+            Self::AllOf(message) => localize_or_default(&message, bundle),
+            Self::OneOf(message) => localize_or_default(&message, bundle),
+            Self::Not(message) => localize_or_default(&message, bundle),
+            Self::Type(message) => localize_or_default(&message, bundle),
+            Self::Required(message) => localize_or_default(&message, bundle),
+            Self::AdditionalProperties(message) => localize_or_default(&message, bundle),
+            Self::FalseSchema(message) => localize_or_default(&message, bundle),
             Self::Custom(message) => message,
             Self::Items(message) => format!("{message}"),
             Self::Properties(message) => format!("{message}"),
@@ -209,4 +227,34 @@ mod test {
             "Welcome, \u{2068}John\u{2069}."
         );
     }
+
+    #[test]
+    fn into_localization_keeps_owned_property_keys() {
+        let ftl_string = "too-long = Too long.".to_string();
+        let res = FluentResource::try_new(ftl_string).expect("Failed to parse an FTL string.");
+
+        let langid_en: LanguageIdentifier = "en-US".parse().expect("Parsing failed");
+        let mut bundle = FluentBundle::new(vec![langid_en]);
+        bundle.add_resource(res).unwrap();
+
+        let errors = crate::validation::Errors::Object(crate::validation::ObjectErrors::new(
+            vec![],
+            indexmap::indexmap! {
+                String::from("timeout").into() => crate::validation::Errors::NewType(vec![
+                    crate::validation::Error::Fluent(Message {
+                        id: "too-long",
+                        args: vec![],
+                    }),
+                ]),
+            },
+        ));
+
+        assert_eq!(
+            serde_json::to_value(errors.into_localization(&bundle)).unwrap(),
+            serde_json::json!({
+                "errors": [],
+                "properties": {"timeout": {"errors": ["Too long."]}}
+            })
+        );
+    }
 }
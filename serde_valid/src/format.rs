@@ -0,0 +1,366 @@
+//! String formats for the `format` validation.
+//!
+//! The built-in formats follow the
+//! [JSON Schema defined formats](https://json-schema.org/understanding-json-schema/reference/string.html#built-in-formats):
+//!
+//! | Format      | Description                                  |
+//! | :---------- | :------------------------------------------- |
+//! | `email`     | Internet email address.                      |
+//! | `uri`       | Absolute URI (RFC 3986).                     |
+//! | `uuid`      | Hyphenated UUID (RFC 4122).                  |
+//! | `ipv4`      | IPv4 address in dotted-quad notation.        |
+//! | `ipv6`      | IPv6 address (RFC 4291).                     |
+//! | `hostname`  | Internet host name (RFC 1123).               |
+//! | `date`      | Full date, e.g. `2020-09-10` (RFC 3339).     |
+//! | `time`      | Time with offset, e.g. `12:00:00Z`.          |
+//! | `date-time` | Date and time, e.g. `2020-09-10T12:00:00Z`.  |
+//!
+//! Other format names can be registered with [`register_format`],
+//! and are given to `#[validate(format = ...)]` by a constant,
+//! because a literal format name must be built in.
+//! Names that are neither built in nor registered never match.
+//!
+//! ```rust
+//! use serde_json::json;
+//! use serde_valid::Validate;
+//!
+//! const POSTAL_CODE: &str = "postal-code";
+//!
+//! fn is_postal_code(value: &str) -> bool {
+//!     value.len() == 7 && value.chars().all(|c| c.is_ascii_digit())
+//! }
+//!
+//! serde_valid::format::register_format(POSTAL_CODE, is_postal_code);
+//!
+//! #[derive(Validate)]
+//! struct TestStruct {
+//!     #[validate(format = POSTAL_CODE)]
+//!     val: String,
+//! }
+//!
+//! let s = TestStruct {
+//!     val: String::from("100-0001"),
+//! };
+//!
+//! assert_eq!(
+//!     s.validate().unwrap_err().to_string(),
+//!     json!({
+//!         "errors": [],
+//!         "properties": {
+//!             "val": {
+//!                 "errors": ["The value must match the format of \"postal-code\"."]
+//!             }
+//!         }
+//!     })
+//!     .to_string()
+//! );
+//! ```
+
+use std::{
+    collections::HashMap,
+    net::{Ipv4Addr, Ipv6Addr},
+    str::FromStr,
+    sync::RwLock,
+};
+
+use once_cell::sync::Lazy;
+
+/// Function checking whether a string matches a format.
+pub type FormatFn = fn(&str) -> bool;
+
+static FORMATS: Lazy<RwLock<HashMap<String, FormatFn>>> = Lazy::new(Default::default);
+
+/// Register a user-defined format.
+///
+/// Registering a built-in format name replaces the built-in check.
+pub fn register_format(name: impl Into<String>, format_fn: FormatFn) {
+    FORMATS
+        .write()
+        .unwrap_or_else(|error| error.into_inner())
+        .insert(name.into(), format_fn);
+}
+
+/// Check whether `value` matches `format`.
+///
+/// Formats that are neither built in nor registered never match.
+pub fn is_valid_format(format: &str, value: &str) -> bool {
+    let registered = FORMATS
+        .read()
+        .unwrap_or_else(|error| error.into_inner())
+        .get(format)
+        .copied();
+
+    if let Some(format_fn) = registered {
+        return format_fn(value);
+    }
+
+    match format {
+        "email" => is_email(value),
+        "uri" => is_uri(value),
+        "uuid" => is_uuid(value),
+        "ipv4" => Ipv4Addr::from_str(value).is_ok(),
+        "ipv6" => Ipv6Addr::from_str(value).is_ok(),
+        "hostname" => is_hostname(value),
+        "date" => is_date(value),
+        "time" => is_time(value),
+        "date-time" => is_date_time(value),
+        _ => false,
+    }
+}
+
+fn is_email(value: &str) -> bool {
+    let (local, domain) = match value.rsplit_once('@') {
+        Some(parts) => parts,
+        None => return false,
+    };
+
+    let is_valid_local = if local.len() >= 2 && local.starts_with('"') && local.ends_with('"') {
+        local[1..local.len() - 1]
+            .chars()
+            .all(|c| c != '"' && c != '\\' && !c.is_control())
+    } else {
+        !local.is_empty()
+            && local.len() <= 64
+            && local.split('.').all(|atom| {
+                !atom.is_empty()
+                    && atom
+                        .chars()
+                        .all(|c| c.is_ascii_alphanumeric() || "!#$%&'*+/=?^_`{|}~-".contains(c))
+            })
+    };
+
+    let is_valid_domain = match domain
+        .strip_prefix('[')
+        .and_then(|domain| domain.strip_suffix(']'))
+    {
+        Some(literal) => match literal.strip_prefix("IPv6:") {
+            Some(ipv6) => Ipv6Addr::from_str(ipv6).is_ok(),
+            None => Ipv4Addr::from_str(literal).is_ok(),
+        },
+        None => is_hostname(domain),
+    };
+
+    is_valid_local && is_valid_domain
+}
+
+fn is_uri(value: &str) -> bool {
+    let (scheme, rest) = match value.split_once(':') {
+        Some(parts) => parts,
+        None => return false,
+    };
+
+    let mut scheme_chars = scheme.chars();
+    let is_valid_scheme = scheme_chars.next().is_some_and(|c| c.is_ascii_alphabetic())
+        && scheme_chars.all(|c| c.is_ascii_alphanumeric() || "+-.".contains(c));
+
+    let bytes = rest.as_bytes();
+    let is_valid_rest = bytes.iter().enumerate().all(|(index, &b)| match b {
+        b'%' => {
+            bytes.get(index + 1).is_some_and(u8::is_ascii_hexdigit)
+                && bytes.get(index + 2).is_some_and(u8::is_ascii_hexdigit)
+        }
+        _ => b.is_ascii_alphanumeric() || b"-._~:/?#[]@!$&'()*+,;=".contains(&b),
+    });
+
+    is_valid_scheme && is_valid_rest
+}
+
+fn is_uuid(value: &str) -> bool {
+    value.len() == 36
+        && value.char_indices().all(|(index, c)| match index {
+            8 | 13 | 18 | 23 => c == '-',
+            _ => c.is_ascii_hexdigit(),
+        })
+}
+
+fn is_hostname(value: &str) -> bool {
+    let value = value.strip_suffix('.').unwrap_or(value);
+
+    !value.is_empty()
+        && value.len() <= 253
+        && value.split('.').all(|label| {
+            !label.is_empty()
+                && label.len() <= 63
+                && !label.starts_with('-')
+                && !label.ends_with('-')
+                && label.chars().all(|c| c.is_ascii_alphanumeric() || c == '-')
+        })
+}
+
+fn parse_digits(value: &str, len: usize) -> Option<u32> {
+    if value.len() == len && value.chars().all(|c| c.is_ascii_digit()) {
+        value.parse().ok()
+    } else {
+        None
+    }
+}
+
+fn is_date(value: &str) -> bool {
+    let mut parts = value.splitn(3, '-');
+    let (year, month, day) = match (
+        parts.next().and_then(|v| parse_digits(v, 4)),
+        parts.next().and_then(|v| parse_digits(v, 2)),
+        parts.next().and_then(|v| parse_digits(v, 2)),
+    ) {
+        (Some(year), Some(month), Some(day)) => (year, month, day),
+        _ => return false,
+    };
+
+    let is_leap_year = year % 4 == 0 && (year % 100 != 0 || year % 400 == 0);
+    let days_in_month = match month {
+        1 | 3 | 5 | 7 | 8 | 10 | 12 => 31,
+        4 | 6 | 9 | 11 => 30,
+        2 if is_leap_year => 29,
+        2 => 28,
+        _ => return false,
+    };
+
+    (1..=days_in_month).contains(&day)
+}
+
+fn is_partial_time(value: &str) -> bool {
+    let (value, fraction) = match value.split_once('.') {
+        Some((value, fraction)) => (value, Some(fraction)),
+        None => (value, None),
+    };
+    if let Some(fraction) = fraction {
+        if fraction.is_empty() || !fraction.chars().all(|c| c.is_ascii_digit()) {
+            return false;
+        }
+    }
+
+    let mut parts = value.splitn(3, ':');
+    matches!(
+        (
+            parts.next().and_then(|v| parse_digits(v, 2)),
+            parts.next().and_then(|v| parse_digits(v, 2)),
+            parts.next().and_then(|v| parse_digits(v, 2)),
+        ),
+        (Some(hour), Some(minute), Some(second)) if hour < 24 && minute < 60 && second <= 60
+    )
+}
+
+fn is_time(value: &str) -> bool {
+    if let Some(time) = value.strip_suffix('Z').or_else(|| value.strip_suffix('z')) {
+        return is_partial_time(time);
+    }
+
+    match value.rfind(['+', '-']) {
+        Some(index) => {
+            let (time, offset) = (&value[..index], &value[index + 1..]);
+            let mut parts = offset.splitn(2, ':');
+            is_partial_time(time)
+                && matches!(
+                    (
+                        parts.next().and_then(|v| parse_digits(v, 2)),
+                        parts.next().and_then(|v| parse_digits(v, 2)),
+                    ),
+                    (Some(hour), Some(minute)) if hour < 24 && minute < 60
+                )
+        }
+        None => false,
+    }
+}
+
+fn is_date_time(value: &str) -> bool {
+    match value.split_once(['T', 't']) {
+        Some((date, time)) => is_date(date) && is_time(time),
+        None => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_email_format() {
+        assert!(is_valid_format("email", "taro@example.com"));
+        assert!(is_valid_format(
+            "email",
+            "taro.yamada+tag@mail.example.co.jp"
+        ));
+        assert!(is_valid_format("email", "taro@[127.0.0.1]"));
+        assert!(!is_valid_format("email", "taro.example.com"));
+        assert!(!is_valid_format("email", "taro..yamada@example.com"));
+        assert!(!is_valid_format("email", "taro@-example.com"));
+    }
+
+    #[test]
+    fn test_uri_format() {
+        assert!(is_valid_format(
+            "uri",
+            "https://example.com/path?query=1#top"
+        ));
+        assert!(is_valid_format("uri", "urn:isbn:0451450523"));
+        assert!(!is_valid_format("uri", "//example.com/path"));
+        assert!(!is_valid_format("uri", "https://example.com/a b"));
+        assert!(!is_valid_format("uri", "https://example.com/%zz"));
+    }
+
+    #[test]
+    fn test_uuid_format() {
+        assert!(is_valid_format(
+            "uuid",
+            "f81d4fae-7dec-11d0-a765-00a0c91e6bf6"
+        ));
+        assert!(!is_valid_format("uuid", "f81d4fae7dec11d0a76500a0c91e6bf6"));
+        assert!(!is_valid_format(
+            "uuid",
+            "g81d4fae-7dec-11d0-a765-00a0c91e6bf6"
+        ));
+    }
+
+    #[test]
+    fn test_ip_format() {
+        assert!(is_valid_format("ipv4", "192.168.0.1"));
+        assert!(!is_valid_format("ipv4", "192.168.0.256"));
+        assert!(is_valid_format("ipv6", "::1"));
+        assert!(is_valid_format("ipv6", "2001:db8::8a2e:370:7334"));
+        assert!(!is_valid_format("ipv6", "192.168.0.1"));
+    }
+
+    #[test]
+    fn test_hostname_format() {
+        assert!(is_valid_format("hostname", "example.com"));
+        assert!(is_valid_format("hostname", "localhost"));
+        assert!(!is_valid_format("hostname", "-example.com"));
+        assert!(!is_valid_format("hostname", "exa_mple.com"));
+        assert!(!is_valid_format("hostname", &"a".repeat(64)));
+    }
+
+    #[test]
+    fn test_date_format() {
+        assert!(is_valid_format("date", "2020-09-10"));
+        assert!(is_valid_format("date", "2020-02-29"));
+        assert!(!is_valid_format("date", "2021-02-29"));
+        assert!(!is_valid_format("date", "2020/09/10"));
+        assert!(!is_valid_format("date", "2020-13-01"));
+    }
+
+    #[test]
+    fn test_date_time_format() {
+        assert!(is_valid_format("time", "12:34:56Z"));
+        assert!(is_valid_format("time", "12:34:56.789+09:00"));
+        assert!(!is_valid_format("time", "12:34:56"));
+        assert!(is_valid_format("date-time", "2020-09-10T12:34:56Z"));
+        assert!(is_valid_format("date-time", "2020-09-10t12:34:56-05:00"));
+        assert!(!is_valid_format("date-time", "2020-09-10 12:34:56Z"));
+        assert!(!is_valid_format("date-time", "2020-09-10T25:00:00Z"));
+    }
+
+    #[test]
+    fn test_unknown_format() {
+        assert!(!is_valid_format("unknown-format", "value"));
+    }
+
+    #[test]
+    fn test_registered_format() {
+        register_format("lowercase", |value| {
+            value.chars().all(|c| c.is_ascii_lowercase())
+        });
+
+        assert!(is_valid_format("lowercase", "serde"));
+        assert!(!is_valid_format("lowercase", "Serde"));
+    }
+}
@@ -0,0 +1,535 @@
+//! Built-in implementations of the validation traits for std containers.
+//!
+//! - Sequences report [`Errors::Array`] keyed by the item position.
+//! - Sets have no stable item order, so they report the item errors at the set itself.
+//! - Maps report [`Errors::Object`] keyed by the map key.
+//! - Smart pointers and `Option` delegate to the inner value.
+//! - Tuples report [`Errors::Array`] keyed by the element position.
+
+use std::borrow::Cow;
+use std::cell::RefCell;
+use std::collections::{BTreeMap, HashMap, HashSet, VecDeque};
+use std::rc::Rc;
+use std::sync::Arc;
+
+use indexmap::IndexMap;
+
+use crate::validation::{ArrayErrors, Error, Errors, ObjectErrors};
+use crate::{AsyncValidate, Validate, ValidateSchema, ValidateWith};
+
+fn validate_items<'a, T: 'a>(
+    items: impl IntoIterator<Item = &'a T>,
+    mut validate: impl FnMut(&'a T) -> Result<(), Errors>,
+) -> Result<(), Errors> {
+    let items = items
+        .into_iter()
+        .enumerate()
+        .filter_map(|(index, item)| validate(item).err().map(|errors| (index, errors)))
+        .collect::<IndexMap<_, _>>();
+
+    if items.is_empty() {
+        Ok(())
+    } else {
+        Err(Errors::Array(ArrayErrors::new(vec![], items)))
+    }
+}
+
+async fn validate_items_async<'a, T: AsyncValidate + 'a>(
+    items: impl IntoIterator<Item = &'a T>,
+) -> Result<(), Errors> {
+    let mut item_errors = IndexMap::new();
+
+    for (index, item) in items.into_iter().enumerate() {
+        if let Err(errors) = item.validate_async_only().await {
+            item_errors.insert(index, errors);
+        }
+    }
+
+    if item_errors.is_empty() {
+        Ok(())
+    } else {
+        Err(Errors::Array(ArrayErrors::new(vec![], item_errors)))
+    }
+}
+
+/// Item errors without the item positions, for containers whose iteration order is not stable.
+fn unindexed_item_errors(errors: Errors) -> Errors {
+    match errors {
+        Errors::Array(ArrayErrors { mut errors, items }) => {
+            for item_errors in items.into_values() {
+                match item_errors {
+                    Errors::Array(array) => errors.push(Error::Items(array)),
+                    Errors::Object(object) => errors.push(Error::Properties(object)),
+                    Errors::NewType(item_errors) => errors.extend(item_errors),
+                }
+            }
+            Errors::NewType(errors)
+        }
+        errors => errors,
+    }
+}
+
+fn validate_properties<'a, K: std::fmt::Display + 'a, V: 'a>(
+    properties: impl IntoIterator<Item = (&'a K, &'a V)>,
+    mut validate: impl FnMut(&'a V) -> Result<(), Errors>,
+) -> Result<(), Errors> {
+    let properties = properties
+        .into_iter()
+        .filter_map(|(key, value)| {
+            validate(value)
+                .err()
+                .map(|errors| (Cow::Owned(key.to_string()), errors))
+        })
+        .collect::<IndexMap<_, _>>();
+
+    if properties.is_empty() {
+        Ok(())
+    } else {
+        Err(Errors::Object(ObjectErrors::new(vec![], properties)))
+    }
+}
+
+async fn validate_properties_async<K: std::fmt::Display, V: AsyncValidate>(
+    properties: Vec<(&K, &V)>,
+) -> Result<(), Errors> {
+    let mut property_errors = IndexMap::new();
+
+    for (key, value) in properties {
+        if let Err(errors) = value.validate_async_only().await {
+            property_errors.insert(Cow::Owned(key.to_string()), errors);
+        }
+    }
+
+    if property_errors.is_empty() {
+        Ok(())
+    } else {
+        Err(Errors::Object(ObjectErrors::new(vec![], property_errors)))
+    }
+}
+
+macro_rules! impl_for_items {
+    ($($map_err:path =>)? [$($generics:tt)*] $ty:ty $(, $($sync_bounds:tt)*)?) => {
+        impl<$($generics)*> Validate for $ty
+        where
+            T: Validate,
+        {
+            fn validate(&self) -> Result<(), Errors> {
+                validate_items(self.iter(), T::validate)$(.map_err($map_err))?
+            }
+
+            fn validate_group(&self, group: &str) -> Result<(), Errors> {
+                validate_items(self.iter(), |item| item.validate_group(group))$(.map_err($map_err))?
+            }
+        }
+
+        impl<$($generics)*, Ctx> ValidateWith<Ctx> for $ty
+        where
+            T: ValidateWith<Ctx>,
+            Ctx: ?Sized,
+        {
+            fn validate_with(&self, context: &Ctx) -> Result<(), Errors> {
+                validate_items(self.iter(), |item| item.validate_with(context))$(.map_err($map_err))?
+            }
+
+            fn validate_group_with(&self, group: &str, context: &Ctx) -> Result<(), Errors> {
+                validate_items(self.iter(), |item| item.validate_group_with(group, context))
+                    $(.map_err($map_err))?
+            }
+        }
+
+        impl<$($generics)*> AsyncValidate for $ty
+        where
+            T: AsyncValidate,
+            $($($sync_bounds)*)?
+        {
+            async fn validate_async_only(&self) -> Result<(), Errors> {
+                validate_items_async(self.iter()).await$(.map_err($map_err))?
+            }
+        }
+    };
+}
+
+impl_for_items!([T] Vec<T>);
+impl_for_items!([T, const N: usize] [T; N]);
+impl_for_items!([T] VecDeque<T>);
+impl_for_items!(unindexed_item_errors => [T, S] HashSet<T, S>, S: Sync);
+
+macro_rules! impl_for_properties {
+    ([$($generics:tt)*] $ty:ty $(, $($sync_bounds:tt)*)?) => {
+        impl<$($generics)*> Validate for $ty
+        where
+            K: std::fmt::Display,
+            V: Validate,
+        {
+            fn validate(&self) -> Result<(), Errors> {
+                validate_properties(self.iter(), V::validate)
+            }
+
+            fn validate_group(&self, group: &str) -> Result<(), Errors> {
+                validate_properties(self.iter(), |value| value.validate_group(group))
+            }
+        }
+
+        impl<$($generics)*, Ctx> ValidateWith<Ctx> for $ty
+        where
+            K: std::fmt::Display,
+            V: ValidateWith<Ctx>,
+            Ctx: ?Sized,
+        {
+            fn validate_with(&self, context: &Ctx) -> Result<(), Errors> {
+                validate_properties(self.iter(), |value| value.validate_with(context))
+            }
+
+            fn validate_group_with(&self, group: &str, context: &Ctx) -> Result<(), Errors> {
+                validate_properties(self.iter(), |value| value.validate_group_with(group, context))
+            }
+        }
+
+        impl<$($generics)*> AsyncValidate for $ty
+        where
+            K: std::fmt::Display + Sync,
+            V: AsyncValidate,
+            $($($sync_bounds)*)?
+        {
+            async fn validate_async_only(&self) -> Result<(), Errors> {
+                // Collected first, as the `Send` check of the future rejects the `BTreeMap` iterator.
+                validate_properties_async(self.iter().collect::<Vec<_>>()).await
+            }
+        }
+
+        impl<$($generics)*> ValidateSchema for $ty
+        where
+            V: ValidateSchema,
+        {
+            fn json_schema() -> serde_json::Value {
+                serde_json::json!({
+                    "type": "object",
+                    "additionalProperties": V::json_schema(),
+                })
+            }
+        }
+    };
+}
+
+impl_for_properties!([K, V, S] HashMap<K, V, S>, S: Sync);
+impl_for_properties!([K, V] BTreeMap<K, V>);
+impl_for_properties!([K, V, S] IndexMap<K, V, S>, S: Sync);
+
+impl<T> ValidateSchema for Vec<T>
+where
+    T: ValidateSchema,
+{
+    fn json_schema() -> serde_json::Value {
+        serde_json::json!({
+            "type": "array",
+            "items": T::json_schema(),
+        })
+    }
+}
+
+impl<T, const N: usize> ValidateSchema for [T; N]
+where
+    T: ValidateSchema,
+{
+    fn json_schema() -> serde_json::Value {
+        serde_json::json!({
+            "type": "array",
+            "items": T::json_schema(),
+        })
+    }
+}
+
+impl<T> ValidateSchema for VecDeque<T>
+where
+    T: ValidateSchema,
+{
+    fn json_schema() -> serde_json::Value {
+        Vec::<T>::json_schema()
+    }
+}
+
+impl<T, S> ValidateSchema for HashSet<T, S>
+where
+    T: ValidateSchema,
+{
+    fn json_schema() -> serde_json::Value {
+        serde_json::json!({
+            "type": "array",
+            "items": T::json_schema(),
+            "uniqueItems": true,
+        })
+    }
+}
+
+impl<T> Validate for Option<T>
+where
+    T: Validate,
+{
+    fn validate(&self) -> Result<(), Errors> {
+        match self {
+            Some(value) => value.validate(),
+            None => Ok(()),
+        }
+    }
+
+    fn validate_group(&self, group: &str) -> Result<(), Errors> {
+        match self {
+            Some(value) => value.validate_group(group),
+            None => Ok(()),
+        }
+    }
+}
+
+impl<T, Ctx> ValidateWith<Ctx> for Option<T>
+where
+    T: ValidateWith<Ctx>,
+    Ctx: ?Sized,
+{
+    fn validate_with(&self, context: &Ctx) -> Result<(), Errors> {
+        match self {
+            Some(value) => value.validate_with(context),
+            None => Ok(()),
+        }
+    }
+
+    fn validate_group_with(&self, group: &str, context: &Ctx) -> Result<(), Errors> {
+        match self {
+            Some(value) => value.validate_group_with(group, context),
+            None => Ok(()),
+        }
+    }
+}
+
+impl<T> AsyncValidate for Option<T>
+where
+    T: AsyncValidate,
+{
+    async fn validate_async_only(&self) -> Result<(), Errors> {
+        match self {
+            Some(value) => value.validate_async_only().await,
+            None => Ok(()),
+        }
+    }
+}
+
+impl<T> ValidateSchema for Option<T>
+where
+    T: ValidateSchema,
+{
+    fn json_schema() -> serde_json::Value {
+        serde_json::json!({
+            "anyOf": [T::json_schema(), {"type": "null"}],
+        })
+    }
+}
+
+macro_rules! impl_for_pointer {
+    ([$($generics:tt)*] $ty:ty $(, $($bounds:tt)*)?) => {
+        impl<$($generics)*> Validate for $ty
+        where
+            T: Validate + ?Sized,
+            $($($bounds)*)?
+        {
+            fn validate(&self) -> Result<(), Errors> {
+                T::validate(self)
+            }
+
+            fn validate_group(&self, group: &str) -> Result<(), Errors> {
+                T::validate_group(self, group)
+            }
+        }
+
+        impl<$($generics)*, Ctx> ValidateWith<Ctx> for $ty
+        where
+            T: ValidateWith<Ctx> + ?Sized,
+            Ctx: ?Sized,
+            $($($bounds)*)?
+        {
+            fn validate_with(&self, context: &Ctx) -> Result<(), Errors> {
+                T::validate_with(self, context)
+            }
+
+            fn validate_group_with(&self, group: &str, context: &Ctx) -> Result<(), Errors> {
+                T::validate_group_with(self, group, context)
+            }
+        }
+
+        impl<$($generics)*> ValidateSchema for $ty
+        where
+            T: ValidateSchema + ?Sized,
+            $($($bounds)*)?
+        {
+            fn json_schema() -> serde_json::Value {
+                T::json_schema()
+            }
+        }
+    };
+}
+
+impl_for_pointer!([T] Box<T>);
+impl_for_pointer!([T] Rc<T>);
+impl_for_pointer!([T] Arc<T>);
+impl_for_pointer!(['a, T] Cow<'a, T>, T: ToOwned);
+
+/// The future is boxed so that recursive types such as `Box<Self>` have a finite future type.
+impl<T> AsyncValidate for Box<T>
+where
+    T: AsyncValidate + ?Sized,
+{
+    fn validate_async_only(&self) -> impl std::future::Future<Output = Result<(), Errors>> + Send {
+        let future: std::pin::Pin<Box<dyn std::future::Future<Output = _> + Send + '_>> =
+            Box::pin(T::validate_async_only(self));
+        future
+    }
+}
+
+impl<T> AsyncValidate for Arc<T>
+where
+    T: AsyncValidate + ?Sized + Send,
+{
+    async fn validate_async_only(&self) -> Result<(), Errors> {
+        T::validate_async_only(self).await
+    }
+}
+
+impl<T> AsyncValidate for Cow<'_, T>
+where
+    T: AsyncValidate + ToOwned + ?Sized,
+    T::Owned: Sync,
+{
+    async fn validate_async_only(&self) -> Result<(), Errors> {
+        T::validate_async_only(self).await
+    }
+}
+
+macro_rules! impl_for_tuple {
+    ($($T:ident $index:tt),+) => {
+        impl<$($T),+> Validate for ($($T,)+)
+        where
+            $($T: Validate,)+
+        {
+            fn validate(&self) -> Result<(), Errors> {
+                let mut items = IndexMap::new();
+                $(
+                    if let Err(errors) = self.$index.validate() {
+                        items.insert($index, errors);
+                    }
+                )+
+                into_tuple_result(items)
+            }
+
+            fn validate_group(&self, group: &str) -> Result<(), Errors> {
+                let mut items = IndexMap::new();
+                $(
+                    if let Err(errors) = self.$index.validate_group(group) {
+                        items.insert($index, errors);
+                    }
+                )+
+                into_tuple_result(items)
+            }
+        }
+
+        impl<$($T,)+ Ctx> ValidateWith<Ctx> for ($($T,)+)
+        where
+            $($T: ValidateWith<Ctx>,)+
+            Ctx: ?Sized,
+        {
+            fn validate_with(&self, context: &Ctx) -> Result<(), Errors> {
+                let mut items = IndexMap::new();
+                $(
+                    if let Err(errors) = self.$index.validate_with(context) {
+                        items.insert($index, errors);
+                    }
+                )+
+                into_tuple_result(items)
+            }
+
+            fn validate_group_with(&self, group: &str, context: &Ctx) -> Result<(), Errors> {
+                let mut items = IndexMap::new();
+                $(
+                    if let Err(errors) = self.$index.validate_group_with(group, context) {
+                        items.insert($index, errors);
+                    }
+                )+
+                into_tuple_result(items)
+            }
+        }
+
+        impl<$($T),+> AsyncValidate for ($($T,)+)
+        where
+            $($T: AsyncValidate,)+
+        {
+            async fn validate_async_only(&self) -> Result<(), Errors> {
+                let mut items = IndexMap::new();
+                $(
+                    if let Err(errors) = self.$index.validate_async_only().await {
+                        items.insert($index, errors);
+                    }
+                )+
+                into_tuple_result(items)
+            }
+        }
+
+        impl<$($T),+> ValidateSchema for ($($T,)+)
+        where
+            $($T: ValidateSchema,)+
+        {
+            fn json_schema() -> serde_json::Value {
+                serde_json::json!({
+                    "type": "array",
+                    "items": [$($T::json_schema()),+],
+                })
+            }
+        }
+    };
+}
+
+fn into_tuple_result(items: IndexMap<usize, Errors>) -> Result<(), Errors> {
+    if items.is_empty() {
+        Ok(())
+    } else {
+        Err(Errors::Array(ArrayErrors::new(vec![], items)))
+    }
+}
+
+impl_for_tuple!(T0 0);
+impl_for_tuple!(T0 0, T1 1);
+impl_for_tuple!(T0 0, T1 1, T2 2);
+impl_for_tuple!(T0 0, T1 1, T2 2, T3 3);
+impl_for_tuple!(T0 0, T1 1, T2 2, T3 3, T4 4);
+impl_for_tuple!(T0 0, T1 1, T2 2, T3 3, T4 4, T5 5);
+impl_for_tuple!(T0 0, T1 1, T2 2, T3 3, T4 4, T5 5, T6 6);
+impl_for_tuple!(T0 0, T1 1, T2 2, T3 3, T4 4, T5 5, T6 6, T7 7);
+impl_for_tuple!(T0 0, T1 1, T2 2, T3 3, T4 4, T5 5, T6 6, T7 7, T8 8);
+impl_for_tuple!(T0 0, T1 1, T2 2, T3 3, T4 4, T5 5, T6 6, T7 7, T8 8, T9 9);
+impl_for_tuple!(T0 0, T1 1, T2 2, T3 3, T4 4, T5 5, T6 6, T7 7, T8 8, T9 9, T10 10);
+impl_for_tuple!(T0 0, T1 1, T2 2, T3 3, T4 4, T5 5, T6 6, T7 7, T8 8, T9 9, T10 10, T11 11);
+
+/// JSON Schema of `T`, or an empty schema when `T` is already being built.
+///
+/// Used by `#[derive(ValidateSchema)]` so that recursive types such as trees terminate.
+#[doc(hidden)]
+pub fn json_schema_of<T: ?Sized>(
+    json_schema: impl FnOnce() -> serde_json::Value,
+) -> serde_json::Value {
+    thread_local! {
+        static IN_PROGRESS: RefCell<Vec<&'static str>> = const { RefCell::new(Vec::new()) };
+    }
+
+    struct Guard;
+
+    impl Drop for Guard {
+        fn drop(&mut self) {
+            IN_PROGRESS.with(|in_progress| in_progress.borrow_mut().pop());
+        }
+    }
+
+    let type_name = std::any::type_name::<T>();
+    if IN_PROGRESS.with(|in_progress| in_progress.borrow().contains(&type_name)) {
+        return serde_json::json!({});
+    }
+
+    IN_PROGRESS.with(|in_progress| in_progress.borrow_mut().push(type_name));
+    let _guard = Guard;
+    json_schema()
+}
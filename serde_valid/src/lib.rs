@@ -43,6 +43,7 @@
 //! | String  | `#[validate(max_length = 5)]`        | [`ValidateMaxLength`](ValidateMaxLength)               | [maxLength](https://json-schema.org/understanding-json-schema/reference/string.html#length)            |
 //! | String  | `#[validate(min_length = 5)]`        | [`ValidateMinLength`](ValidateMinLength)               | [minLength](https://json-schema.org/understanding-json-schema/reference/string.html#length)            |
 //! | String  | `#[validate(pattern = r"^\d{5}$")]`  | [`ValidatePattern`](ValidatePattern)                   | [pattern](https://json-schema.org/understanding-json-schema/reference/string.html#regular-expressions) |
+//! | String  | `#[validate(format = "email")]`      | [`ValidateFormat`](ValidateFormat)                     | [format](https://json-schema.org/understanding-json-schema/reference/string.html#format)               |
 //! | Numeric | `#[validate(maximum = 5)]`           | [`ValidateMaximum`](ValidateMaximum)                   | [maximum](https://json-schema.org/understanding-json-schema/reference/numeric.html#range)              |
 //! | Numeric | `#[validate(minimum = 5)]`           | [`ValidateMinimum`](ValidateMinimum)                   | [minimum](https://json-schema.org/understanding-json-schema/reference/numeric.html#range)              |
 //! | Numeric | `#[validate(exclusive_maximum = 5)]` | [`ValidateExclusiveMaximum`](ValidateExclusiveMaximum) | [exclusiveMaximum](https://json-schema.org/understanding-json-schema/reference/numeric.html#range)     |
@@ -50,11 +51,25 @@
 //! | Numeric | `#[validate(multiple_of = 5)]`       | [`ValidateMultipleOf`](ValidateMultipleOf)             | [multipleOf](https://json-schema.org/understanding-json-schema/reference/numeric.html#multiples)       |
 //! | Object  | `#[validate(max_properties = 5)]`    | [`ValidateMaxProperties`](ValidateMaxProperties)       | [maxProperties](https://json-schema.org/understanding-json-schema/reference/object.html#size)          |
 //! | Object  | `#[validate(min_properties = 5)]`    | [`ValidateMinProperties`](ValidateMinProperties)       | [minProperties](https://json-schema.org/understanding-json-schema/reference/object.html#size)          |
+//! | Object  | `#[validate(property_names(pattern = "^[a-z_]+$"))]` | [`PropertyEntries`](validation::PropertyEntries)       | [propertyNames](https://json-schema.org/understanding-json-schema/reference/object.html#property-names) |
+//! | Object  | `#[validate(pattern_properties("^x-", max_length = 100))]` | [`PropertyEntries`](validation::PropertyEntries)       | [patternProperties](https://json-schema.org/understanding-json-schema/reference/object.html#pattern-properties) |
 //! | Array   | `#[validate(max_items = 5)]`         | [`ValidateMaxItems`](ValidateMaxItems)                 | [maxItems](https://json-schema.org/understanding-json-schema/reference/array.html#length)              |
 //! | Array   | `#[validate(min_items = 5)]`         | [`ValidateMinItems`](ValidateMinItems)                 | [minItems](https://json-schema.org/understanding-json-schema/reference/array.html#length)              |
 //! | Array   | `#[validate(unique_items)]`          | [`ValidateUniqueItems`](ValidateUniqueItems)           | [uniqueItems](https://json-schema.org/understanding-json-schema/reference/array.html#unique_items)     |
+//! | Array   | `#[validate(unique_items_by = id)]`  | [`ValidateUniqueItemsBy`](ValidateUniqueItemsBy)       | [uniqueItems](https://json-schema.org/understanding-json-schema/reference/array.html#unique_items)     |
+//! | Array   | `#[validate(contains(custom(is_admin)))]` | [`ValidateContains`](ValidateContains)                 | [contains](https://json-schema.org/understanding-json-schema/reference/array.html#contains)            |
+//! | Array   | `#[validate(min_contains(2, "x"))]`  | [`ValidateMinContains`](ValidateMinContains)           | [minContains](https://json-schema.org/understanding-json-schema/reference/array.html#mincontains-maxcontains) |
+//! | Array   | `#[validate(max_contains(2, "x"))]`  | [`ValidateMaxContains`](ValidateMaxContains)           | [maxContains](https://json-schema.org/understanding-json-schema/reference/array.html#mincontains-maxcontains) |
 //! | Generic | `#[validate(enumerate(5, 10, 15))]`  | [`ValidateEnumerate`](ValidateEnumerate)               | [enum](https://json-schema.org/understanding-json-schema/reference/generic.html#enumerated-values)     |
 //!
+//! The String, Numeric and Generic validations also apply to each item of `Vec`, `VecDeque`, arrays and slices,
+//! and to each value of `HashMap`, `BTreeMap` and `IndexMap`, reporting the errors per index or key.
+//! They apply to each item of `HashSet` too, but report the errors at the set itself, because sets have no stable order.
+//! `max_properties` and `min_properties` still count the map itself.
+//!
+//! `unique_items` only needs `PartialEq` of the items, but hashes them when they are `Eq + Hash`, and `unique_items_by` compares them by the key the function returns.
+//! The `duplicates` param of `UniqueItemsError` lists the indices of the repeated items.
+//!
 //! ## Complete Constructor (Deserialization)
 //!
 //! Serde Valid support complete constructor method using by [`serde_valid::json::FromJsonValue`](json::FromJsonValue) trait.
@@ -114,6 +129,9 @@
 //!
 //! For user custom message, Serde Valid provides `message_fn` or `message`.
 //!
+//! `message` can refer to the error params, such as `{max_items}` of `MaxItemsError`,
+//! and `{{` or `}}` are literal braces.
+//!
 //! ```rust
 //! use serde_json::json;
 //! use serde_valid::Validate;
@@ -126,23 +144,62 @@
 //! #[derive(Validate)]
 //! struct SampleStruct {
 //!     #[validate(min_items = 4, message_fn(min_error_message))]
-//!     #[validate(max_items = 2, message = "this is max custom message.")]
-//!     val: Vec<i32>,
+//!     val1: Vec<i32>,
+//!     #[validate(max_items = 2, message = "the length must be `<= {max_items}`.")]
+//!     val2: Vec<i32>,
 //! }
 //!
-//! let s = SampleStruct { val: vec![1, 2, 3] };
+//! let s = SampleStruct {
+//!     val1: vec![1, 2, 3],
+//!     val2: vec![1, 2, 3],
+//! };
 //!
 //! assert_eq!(
 //!     s.validate().unwrap_err().to_string(),
 //!     json!({
 //!         "errors": [],
 //!         "properties": {
-//!             "val": {
-//!                 "errors": [
-//!                     "this is min custom message_fn.",
-//!                     "this is max custom message."
-//!                 ]
-//!             }
+//!             "val1": {"errors": ["this is min custom message_fn."]},
+//!             "val2": {"errors": ["the length must be `<= 2`."]}
+//!         }
+//!     })
+//!     .to_string()
+//! );
+//! ```
+//!
+//! ## Default Messages
+//!
+//! The default messages of the built-in errors can be replaced for the whole process with `set_default_messages`.
+//! Fields with `message` or `message_fn` keep their own message.
+//!
+//! ```rust
+//! use serde_json::json;
+//! use serde_valid::{MaximumError, MessageCatalog, Validate};
+//!
+//! struct Catalog;
+//!
+//! impl MessageCatalog for Catalog {
+//!     fn maximum(&self, error: &MaximumError) -> String {
+//!         format!("must be at most {}", error.maximum)
+//!     }
+//! }
+//!
+//! serde_valid::set_default_messages(Catalog);
+//!
+//! #[derive(Validate)]
+//! struct SampleStruct {
+//!     #[validate(maximum = 10)]
+//!     val: i32,
+//! }
+//!
+//! let s = SampleStruct { val: 11 };
+//!
+//! assert_eq!(
+//!     s.validate().unwrap_err().to_string(),
+//!     json!({
+//!         "errors": [],
+//!         "properties": {
+//!             "val": {"errors": ["must be at most 10"]}
 //!         }
 //!     })
 //!     .to_string()
@@ -228,6 +285,384 @@
 //! assert!(s.validate().is_ok());
 //! ```
 //!
+//! To report the error on a field instead of the struct, return a `RuleError`.
+//! `RuleError::at` takes a field name, `RuleError::at_index` an index of unnamed fields,
+//! and a rule can also return `Vec<RuleError>` or a partial `Errors` tree.
+//!
+//! ```rust
+//! use serde_json::json;
+//! use serde_valid::validation::{Error, RuleError};
+//! use serde_valid::Validate;
+//!
+//! fn date_rule(start_date: &u32, end_date: &u32) -> Result<(), RuleError> {
+//!     if start_date <= end_date {
+//!         Ok(())
+//!     } else {
+//!         Err(RuleError::at(
+//!             "end_date",
+//!             Error::Custom("The end date must be after the start date.".to_owned()),
+//!         ))
+//!     }
+//! }
+//!
+//! #[derive(Validate)]
+//! #[rule(date_rule(start_date, end_date))]
+//! struct SampleStruct {
+//!     start_date: u32,
+//!     end_date: u32,
+//! }
+//!
+//! let s = SampleStruct {
+//!     start_date: 2,
+//!     end_date: 1,
+//! };
+//!
+//! assert_eq!(
+//!     s.validate().unwrap_err().to_string(),
+//!     json!({
+//!         "errors": [],
+//!         "properties": {
+//!             "end_date": {"errors": ["The end date must be after the start date."]}
+//!         }
+//!     })
+//!     .to_string()
+//! );
+//! ```
+//!
+//! ## Field Comparison
+//!
+//! `greater_than`, `greater_than_or_equal`, `less_than`, `less_than_or_equal`, `equals` and `not_equals`
+//! compare a field with a sibling field by `PartialOrd` or `PartialEq`, and report the error under the field.
+//!
+//! ```rust
+//! use serde_json::json;
+//! use serde_valid::Validate;
+//!
+//! #[derive(Validate)]
+//! struct SampleStruct {
+//!     start: i32,
+//!     #[validate(greater_than = start)]
+//!     end: i32,
+//! }
+//!
+//! let s = SampleStruct { start: 5, end: 1 };
+//!
+//! assert_eq!(
+//!     s.validate().unwrap_err().to_string(),
+//!     json!({
+//!         "errors": [],
+//!         "properties": {
+//!             "end": {"errors": ["The value must be `> start`."]}
+//!         }
+//!     })
+//!     .to_string()
+//! );
+//! ```
+//!
+//! ## Composition
+//!
+//! `any_of`, `all_of`, `one_of` and `not` combine validations like `anyOf`, `allOf`, `oneOf` and `not` of JSON Schema.
+//! The error keeps the errors of each branch, and the message lists the failed ones.
+//!
+//! ```rust
+//! use serde_json::json;
+//! use serde_valid::Validate;
+//!
+//! #[derive(Validate)]
+//! struct SampleStruct {
+//!     #[validate(any_of(pattern = r"^\d+$", enumerate("auto", "none")))]
+//!     width: String,
+//!     #[validate(not(enumerate("admin", "root")))]
+//!     name: String,
+//! }
+//!
+//! let s = SampleStruct {
+//!     width: "wide".to_owned(),
+//!     name: "taro".to_owned(),
+//! };
+//!
+//! assert_eq!(
+//!     s.validate().unwrap_err().to_string(),
+//!     json!({
+//!         "errors": [],
+//!         "properties": {
+//!             "width": {
+//!                 "errors": [
+//!                     "The value must match any of the validations: The value must match the pattern of \"^\\d+$\". / The value must be in [auto, none]."
+//!                 ]
+//!             }
+//!         }
+//!     })
+//!     .to_string()
+//! );
+//! ```
+//!
+//! ## Conditional Validation
+//!
+//! `when = predicate` runs a validator only when the predicate holds,
+//! and `#[validate(skip_if = predicate)]` skips all validators of the field.
+//! The predicate receives `&Self`.
+//!
+//! ```rust
+//! use serde_valid::Validate;
+//!
+//! fn is_german(company: &Company) -> bool {
+//!     company.country == "DE"
+//! }
+//!
+//! fn is_draft(company: &Company) -> bool {
+//!     company.draft
+//! }
+//!
+//! #[derive(Validate)]
+//! struct Company {
+//!     country: String,
+//!     draft: bool,
+//!     #[validate(pattern = r"^DE\d{9}$", when = is_german)]
+//!     vat_id: String,
+//!     #[validate(skip_if = is_draft)]
+//!     #[validate(min_length = 1)]
+//!     name: String,
+//! }
+//!
+//! let s = Company {
+//!     country: "FR".to_owned(),
+//!     draft: true,
+//!     vat_id: "FR12345678901".to_owned(),
+//!     name: "".to_owned(),
+//! };
+//!
+//! assert!(s.validate().is_ok());
+//! ```
+//!
+//! ## Validation Groups
+//!
+//! Validators can be tagged with `groups(...)` and run by `validate_group`.
+//! Untagged validators and rules make up the default set run by `validate`.
+//!
+//! ```rust
+//! use serde_valid::Validate;
+//!
+//! #[derive(Validate)]
+//! struct SampleStruct {
+//!     #[validate(minimum = 1, groups(update))]
+//!     #[validate(maximum = 0, groups(create))]
+//!     id: i32,
+//!     #[validate(max_length = 5)]
+//!     name: String,
+//! }
+//!
+//! let s = SampleStruct {
+//!     id: 0,
+//!     name: "taro".to_owned(),
+//! };
+//!
+//! assert!(s.validate().is_ok());
+//! assert!(s.validate_group("create").is_ok());
+//! assert!(s.validate_group("update").is_err());
+//! ```
+//!
+//! ## Validation Context
+//!
+//! With `#[validate(context = Type)]`, the struct implements `ValidateWith<Type>` instead of `Validate`.
+//! The context is available as `ctx` in validation values,
+//! and custom and rule functions receive it as the last argument.
+//! Nested `#[validate]` fields are validated with the same context.
+//! A field named `ctx` would hide the context, so it is a compile error.
+//! Types with a hand-written `Validate` can be nested after `serde_valid::impl_validate_with!(Type)`.
+//!
+//! ```rust
+//! use serde_valid::ValidateWith;
+//!
+//! struct AppConfig {
+//!     max_upload: u64,
+//! }
+//!
+//! fn is_even(value: &u64, ctx: &AppConfig) -> Result<(), serde_valid::validation::Error> {
+//!     if ctx.max_upload > 0 && value % 2 == 0 {
+//!         Ok(())
+//!     } else {
+//!         Err(serde_valid::validation::Error::Custom("must be even.".to_owned()))
+//!     }
+//! }
+//!
+//! #[derive(serde_valid::Validate)]
+//! #[validate(context = AppConfig)]
+//! struct SampleStruct {
+//!     #[validate(maximum = ctx.max_upload)]
+//!     #[validate(custom(is_even))]
+//!     size: u64,
+//! }
+//!
+//! let config = AppConfig { max_upload: 100 };
+//!
+//! assert!(SampleStruct { size: 10 }.validate_with(&config).is_ok());
+//! assert!(SampleStruct { size: 200 }.validate_with(&config).is_err());
+//! ```
+//!
+//! In axum, `axum_serde_valid::JsonWith` validates the request with the router state as the context.
+//!
+//! ## Async Validation
+//!
+//! Validators that need I/O, such as uniqueness checks, can be declared with
+//! `custom_async` and `rule_async`, and are run by `#[derive(AsyncValidate)]`.
+//! `validate_async` runs the synchronous validators first,
+//! then awaits the asynchronous ones and merges both errors.
+//!
+//! ```rust
+//! use serde_valid::{AsyncValidate, Validate};
+//!
+//! async fn unique_name(name: &str) -> Result<(), serde_valid::validation::Error> {
+//!     if name == "taro" {
+//!         Err(serde_valid::validation::Error::Custom(
+//!             "The name is already taken.".to_owned(),
+//!         ))
+//!     } else {
+//!         Ok(())
+//!     }
+//! }
+//!
+//! #[derive(Validate, AsyncValidate)]
+//! struct SampleStruct {
+//!     #[validate(max_length = 10)]
+//!     #[validate(custom_async(unique_name))]
+//!     name: String,
+//! }
+//!
+//! #[tokio::main(flavor = "current_thread")]
+//! async fn main() {
+//!     let s = SampleStruct {
+//!         name: "taro".to_owned(),
+//!     };
+//!
+//!     assert!(s.validate().is_ok());
+//!     assert!(s.validate_async().await.is_err());
+//! }
+//! ```
+//!
+//! Nested `#[validate]` fields must also implement `AsyncValidate`.
+//! In axum, `axum_serde_valid::AsyncJson` awaits the validation before calling the handler.
+//!
+//! ## JSON Schema
+//!
+//! `#[derive(ValidateSchema)]` emits the JSON Schema keywords of the validators,
+//! so API documentation can show the same constraints that `validate` checks.
+//! Serde renames are respected, and nested `#[validate]` fields include the schema of the field type.
+//! Enums are an `anyOf` of their variants, following the serde tagging: external, `tag`, `tag` with `content`, or `untagged`.
+//!
+//! ```rust
+//! use serde::Deserialize;
+//! use serde_json::json;
+//! use serde_valid::{Validate, ValidateSchema};
+//!
+//! #[derive(Deserialize, Validate, ValidateSchema)]
+//! struct SampleStruct {
+//!     #[serde(rename = "userName")]
+//!     #[validate(max_length = 10)]
+//!     #[validate(pattern = r"^[a-z]+$")]
+//!     name: String,
+//!     #[validate(maximum = 100)]
+//!     age: u32,
+//! }
+//!
+//! assert_eq!(
+//!     SampleStruct::json_schema(),
+//!     json!({
+//!         "type": "object",
+//!         "properties": {
+//!             "userName": {"maxLength": 10, "pattern": "^[a-z]+$"},
+//!             "age": {"maximum": 100}
+//!         }
+//!     })
+//! );
+//! ```
+//!
+//! Validators with `groups`, `custom` and `custom_async` have no JSON Schema keyword and are not emitted.
+//!
+//! The `jsonschema` feature of `axum_serde_valid` does not use this schema.
+//! It checks requests against the `schemars` schema only, and the `#[validate]` attributes
+//! are enforced by `Validate` after deserialization.
+//!
+//! ## Runtime JSON Schema
+//!
+//! When the schema is only known at runtime, `serde_valid::schema::Schema` compiles a JSON Schema document
+//! and validates a `serde_json::Value`. Errors use the same `validation::Errors` as derived types.
+//!
+//! ```rust
+//! use serde_json::json;
+//! use serde_valid::schema::Schema;
+//!
+//! let schema = Schema::new(&json!({
+//!     "type": "object",
+//!     "properties": {
+//!         "timeout": {"type": "integer", "maximum": 60}
+//!     }
+//! }))
+//! .unwrap();
+//!
+//! assert_eq!(
+//!     schema.validate(&json!({"timeout": 90})).unwrap_err().to_string(),
+//!     json!({
+//!         "errors": [],
+//!         "properties": {
+//!             "timeout": {"errors": ["The number must be `<= 60`."]}
+//!         }
+//!     })
+//!     .to_string()
+//! );
+//! ```
+//!
+//! ## Nested Containers
+//!
+//! Nested `#[validate]` also works through maps, sets, smart pointers and tuples.
+//! Errors of map values are reported under the map key, so recursive types such as
+//! `Box<Self>` trees and dynamic settings are validated item by item.
+//! Sets have no stable order, so the errors of their items are reported at the set itself.
+//!
+//! ```rust
+//! use std::collections::HashMap;
+//!
+//! use serde_json::json;
+//! use serde_valid::Validate;
+//!
+//! #[derive(Validate)]
+//! struct Setting {
+//!     #[validate(maximum = 60)]
+//!     value: u32,
+//! }
+//!
+//! #[derive(Validate)]
+//! struct SampleStruct {
+//!     #[validate]
+//!     settings: HashMap<String, Setting>,
+//! }
+//!
+//! let s = SampleStruct {
+//!     settings: HashMap::from([("timeout".to_owned(), Setting { value: 90 })]),
+//! };
+//!
+//! assert_eq!(
+//!     s.validate().unwrap_err().to_string(),
+//!     json!({
+//!         "errors": [],
+//!         "properties": {
+//!             "settings": {
+//!                 "errors": [],
+//!                 "properties": {
+//!                     "timeout": {
+//!                         "errors": [],
+//!                         "properties": {
+//!                             "value": {"errors": ["The number must be `<= 60`."]}
+//!                         }
+//!                     }
+//!                 }
+//!             }
+//!         }
+//!     })
+//!     .to_string()
+//! );
+//! ```
+//!
 //! ## Validate Traits
 //!
 //! By implementing the validation trait, Your original type can uses Serde Valid validations.
@@ -342,7 +777,7 @@
 //! ```
 //!
 //! ### Named Enum
-//! Variant errors are output to `properties`.
+//! Variant errors are output to `properties`, under the name of the variant as in the externally tagged JSON.
 //!
 //! ```rust
 //! use serde_json::json;
@@ -365,11 +800,16 @@
 //!     json!({
 //!         "errors": [],
 //!         "properties": {
-//!             "a": {
-//!                 "errors": ["The number must be `<= 5`."]
-//!             },
-//!             "b": {
-//!                 "errors": ["The number must be `<= 5`."]
+//!             "Named": {
+//!                 "errors": [],
+//!                 "properties": {
+//!                     "a": {
+//!                         "errors": ["The number must be `<= 5`."]
+//!                     },
+//!                     "b": {
+//!                         "errors": ["The number must be `<= 5`."]
+//!                     }
+//!                 }
 //!             }
 //!         }
 //!     })
@@ -378,7 +818,7 @@
 //! ```
 //!
 //! ### Unnamed Enum
-//! Variant errors are output to `items`. The key for `items` is guaranteed to be a string of positive numbers.
+//! Variant errors are output to `items` under the name of the variant. The key for `items` is guaranteed to be a string of positive numbers.
 //!
 //! ```rust
 //! use serde_json::json;
@@ -398,12 +838,17 @@
 //!     s.validate().unwrap_err().to_string(),
 //!     json!({
 //!         "errors": [],
-//!         "items": {
-//!             "0": {
-//!                 "errors": ["The number must be `<= 5`."]
-//!             },
-//!             "1": {
-//!                 "errors": ["The number must be `<= 5`."]
+//!         "properties": {
+//!             "Unnamed": {
+//!                 "errors": [],
+//!                 "items": {
+//!                     "0": {
+//!                         "errors": ["The number must be `<= 5`."]
+//!                     },
+//!                     "1": {
+//!                         "errors": ["The number must be `<= 5`."]
+//!                     }
+//!                 }
 //!             }
 //!         }
 //!     })
@@ -412,7 +857,7 @@
 //! ```
 //!
 //! ### Newtype Enum
-//! Variant errors are output to `errors`.
+//! Variant errors are output to `errors` under the name of the variant.
 //!
 //! ```rust
 //! use serde_json::json;
@@ -430,30 +875,116 @@
 //! assert_eq!(
 //!     s.validate().unwrap_err().to_string(),
 //!     json!({
-//!         "errors": ["The number must be `<= 5`."]
+//!         "errors": [],
+//!         "properties": {
+//!             "NewType": {
+//!                 "errors": ["The number must be `<= 5`."]
+//!             }
+//!         }
+//!     })
+//!     .to_string()
+//! );
+//! ```
+//!
+//! ### Enum Representations
+//! Variant errors follow the serde representation of the enum and the variant renames.
+//! Adjacently tagged variants are nested under the `content` name,
+//! and internally tagged or untagged variants are output at the enum level.
+//!
+//! ```rust
+//! use serde::Deserialize;
+//! use serde_json::json;
+//! use serde_valid::Validate;
+//!
+//! #[derive(Deserialize, Validate)]
+//! #[serde(tag = "type", content = "data")]
+//! enum SampleEnum {
+//!     Named {
+//!         #[validate(maximum = 5)]
+//!         a: i32,
+//!     },
+//! }
+//!
+//! let s = SampleEnum::Named { a: 6 };
+//!
+//! assert_eq!(
+//!     s.validate().unwrap_err().to_string(),
+//!     json!({
+//!         "errors": [],
+//!         "properties": {
+//!             "data": {
+//!                 "errors": [],
+//!                 "properties": {
+//!                     "a": {
+//!                         "errors": ["The number must be `<= 5`."]
+//!                     }
+//!                 }
+//!             }
+//!         }
 //!     })
 //!     .to_string()
 //! );
 //! ```
+//!
+//! ### Error Codes
+//! For clients that localize messages on their own, `to_structured` replaces each message
+//! with its validator code, parameters and default message.
+//!
+//! ```rust
+//! use serde_json::json;
+//! use serde_valid::Validate;
+//!
+//! #[derive(Validate)]
+//! struct SampleStruct {
+//!     #[validate(maximum = 10)]
+//!     val: i32,
+//! }
+//!
+//! let s = SampleStruct { val: 12 };
+//!
+//! assert_eq!(
+//!     serde_json::to_value(s.validate().unwrap_err().to_structured()).unwrap(),
+//!     json!({
+//!         "errors": [],
+//!         "properties": {
+//!             "val": {
+//!                 "errors": [{
+//!                     "code": "maximum",
+//!                     "params": {"maximum": 10},
+//!                     "message": "The number must be `<= 10`."
+//!                 }]
+//!             }
+//!         }
+//!     })
+//! );
+//! ```
 
 pub mod error;
 mod features;
+pub mod format;
+mod impls;
 pub mod json;
+mod message_catalog;
+pub mod schema;
 mod traits;
 pub mod validation;
 
-use indexmap::IndexMap;
-
 pub use error::{
-    EnumerateError, Error, ExclusiveMaximumError, ExclusiveMinimumError, MaxItemsError,
-    MaxLengthError, MaxPropertiesError, MaximumError, MinItemsError, MinLengthError,
-    MinPropertiesError, MinimumError, MultipleOfError, PatternError, UniqueItemsError,
+    AdditionalPropertiesError, AllOfError, AnyOfError, BranchErrors, ContainsError, EnumerateError,
+    EqualsError, Error, ExclusiveMaximumError, ExclusiveMinimumError, FalseSchemaError,
+    FormatError, GreaterThanError, GreaterThanOrEqualError, LessThanError, LessThanOrEqualError,
+    MaxContainsError, MaxItemsError, MaxLengthError, MaxPropertiesError, MaximumError,
+    MinContainsError, MinItemsError, MinLengthError, MinPropertiesError, MinimumError,
+    MultipleOfError, NotEqualsError, NotError, OneOfError, PatternError, RequiredError, TypeError,
+    UniqueItemsError,
 };
+pub use message_catalog::{set_default_messages, BuiltinMessage, MessageCatalog};
 pub use validation::{
-    ValidateEnumerate, ValidateExclusiveMaximum, ValidateExclusiveMinimum, ValidateMaxItems,
-    ValidateMaxLength, ValidateMaxProperties, ValidateMaximum, ValidateMinItems, ValidateMinLength,
-    ValidateMinProperties, ValidateMinimum, ValidateMultipleOf, ValidatePattern,
-    ValidateUniqueItems,
+    ValidateContains, ValidateEnumerate, ValidateExclusiveMaximum, ValidateExclusiveMinimum,
+    ValidateFormat, ValidateMaxContains, ValidateMaxItems, ValidateMaxLength,
+    ValidateMaxProperties, ValidateMaximum, ValidateMinContains, ValidateMinItems,
+    ValidateMinLength, ValidateMinProperties, ValidateMinimum, ValidateMultipleOf, ValidatePattern,
+    ValidateUniqueItems, ValidateUniqueItemsBy,
 };
 
 #[cfg(feature = "flatten")]
@@ -466,69 +997,162 @@ pub use features::toml;
 pub use features::yaml;
 
 pub mod export {
+    pub use crate::impls::json_schema_of;
+    pub use crate::validation::{
+        UniqueItemsField, ValidateUniqueItemsByEq, ValidateUniqueItemsByHash,
+    };
     pub use once_cell::sync::OnceCell;
+    pub use serde_json;
 }
 
 pub trait Validate {
     fn validate(&self) -> std::result::Result<(), self::validation::Errors>;
-}
 
-impl<T> Validate for Vec<T>
-where
-    T: Validate,
-{
-    fn validate(&self) -> std::result::Result<(), self::validation::Errors> {
-        let mut items = IndexMap::new();
+    /// Validate with the validators tagged by `groups(...)` for `group`.
+    ///
+    /// Nested `#[validate]` fields receive the same group.
+    /// Manual implementations have no grouped validators by default.
+    fn validate_group(&self, group: &str) -> std::result::Result<(), self::validation::Errors> {
+        let _ = group;
+        Ok(())
+    }
+}
 
-        for (index, item) in self.iter().enumerate() {
-            if let Err(errors) = item.validate() {
-                items.insert(index, errors);
-            }
-        }
+/// Validate with a user-supplied context.
+///
+/// `#[derive(Validate)]` with `#[validate(context = Ctx)]` implements `ValidateWith<Ctx>`,
+/// and derives without a context implement `ValidateWith` for any context,
+/// so they can be nested in a struct with a context.
+/// Hand-written `Validate` impls get the same with [`impl_validate_with!`].
+pub trait ValidateWith<Ctx: ?Sized> {
+    fn validate_with(&self, context: &Ctx) -> std::result::Result<(), self::validation::Errors>;
 
-        if items.is_empty() {
-            Ok(())
-        } else {
-            Err(self::validation::Errors::Array(
-                validation::ArrayErrors::new(vec![], items),
-            ))
-        }
+    /// Validate with the validators tagged by `groups(...)` for `group`.
+    fn validate_group_with(
+        &self,
+        group: &str,
+        context: &Ctx,
+    ) -> std::result::Result<(), self::validation::Errors> {
+        let _ = (group, context);
+        Ok(())
     }
 }
 
-impl<T, const N: usize> Validate for [T; N]
-where
-    T: Validate,
-{
-    fn validate(&self) -> std::result::Result<(), self::validation::Errors> {
-        let mut items = IndexMap::new();
+/// Implement [`ValidateWith`] for any context by the hand-written [`Validate`] of the types,
+/// as `#[derive(Validate)]` does, so that they can be nested in a struct with a context.
+///
+/// ```rust
+/// use serde_valid::{Validate, ValidateWith};
+///
+/// struct AppConfig {
+///     max_upload: u64,
+/// }
+///
+/// struct Checksum(String);
+///
+/// impl Validate for Checksum {
+///     fn validate(&self) -> Result<(), serde_valid::validation::Errors> {
+///         Ok(())
+///     }
+/// }
+///
+/// serde_valid::impl_validate_with!(Checksum);
+///
+/// #[derive(Validate)]
+/// #[validate(context = AppConfig)]
+/// struct Upload {
+///     #[validate(maximum = ctx.max_upload)]
+///     size: u64,
+///     #[validate]
+///     checksum: Checksum,
+/// }
+///
+/// let upload = Upload {
+///     size: 10,
+///     checksum: Checksum("abc".to_owned()),
+/// };
+/// assert!(upload.validate_with(&AppConfig { max_upload: 100 }).is_ok());
+/// ```
+///
+/// Generic types implement `ValidateWith` by hand in the same way.
+#[macro_export]
+macro_rules! impl_validate_with {
+    ($($ty:ty),+ $(,)?) => {
+        $(
+            impl<__Ctx: ?::std::marker::Sized> $crate::ValidateWith<__Ctx> for $ty {
+                fn validate_with(
+                    &self,
+                    _: &__Ctx,
+                ) -> ::std::result::Result<(), $crate::validation::Errors> {
+                    $crate::Validate::validate(self)
+                }
 
-        for (index, item) in self.iter().enumerate() {
-            if let Err(errors) = item.validate() {
-                items.insert(index, errors);
+                fn validate_group_with(
+                    &self,
+                    group: &str,
+                    _: &__Ctx,
+                ) -> ::std::result::Result<(), $crate::validation::Errors> {
+                    $crate::Validate::validate_group(self, group)
+                }
             }
-        }
+        )+
+    };
+}
+
+/// Asynchronous validation for I/O-bound validators.
+///
+/// `#[derive(AsyncValidate)]` runs `#[validate(custom_async(f))]` and `#[rule_async(f(a, b))]`
+/// next to `#[derive(Validate)]`, which runs the synchronous validators.
+pub trait AsyncValidate: Validate + Sync {
+    /// Run only the asynchronous validators, including those of nested fields.
+    fn validate_async_only(
+        &self,
+    ) -> impl std::future::Future<Output = std::result::Result<(), self::validation::Errors>> + Send;
 
-        if items.is_empty() {
-            Ok(())
-        } else {
-            Err(self::validation::Errors::Array(
-                validation::ArrayErrors::new(vec![], items),
-            ))
+    /// Run the synchronous validators, then await the asynchronous ones,
+    /// and merge both results into the same errors.
+    fn validate_async(
+        &self,
+    ) -> impl std::future::Future<Output = std::result::Result<(), self::validation::Errors>> + Send
+    {
+        async move {
+            let sync_result = self.validate();
+            let async_result = self.validate_async_only().await;
+
+            match (sync_result, async_result) {
+                (Ok(()), Ok(())) => Ok(()),
+                (Err(errors), Ok(())) | (Ok(()), Err(errors)) => Err(errors),
+                (Err(mut errors), Err(async_errors)) => {
+                    if let Err(conflict) = errors.merge(async_errors) {
+                        use self::validation::{Error, Errors};
+
+                        // The conflict may be nested, so the rejected errors of any shape
+                        // are kept in the top-level errors.
+                        let rejected = match conflict.into_inner() {
+                            Errors::Array(array) => vec![Error::Items(array)],
+                            Errors::Object(object) => vec![Error::Properties(object)],
+                            Errors::NewType(errors) => errors,
+                        };
+                        match &mut errors {
+                            Errors::Array(array) => array.errors.extend(rejected),
+                            Errors::Object(object) => object.errors.extend(rejected),
+                            Errors::NewType(errors) => errors.extend(rejected),
+                        }
+                    }
+                    Err(errors)
+                }
+            }
         }
     }
 }
 
-impl<T> Validate for Option<T>
-where
-    T: Validate,
-{
-    fn validate(&self) -> std::result::Result<(), self::validation::Errors> {
-        match self {
-            Some(value) => value.validate(),
-            None => Ok(()),
-        }
-    }
+/// JSON Schema of the validations.
+///
+/// `#[derive(ValidateSchema)]` maps each `#[validate(...)]` attribute to its JSON Schema keyword,
+/// such as `maximum`, `maxLength`, `pattern` or `enum`, and uses the serde renames as property names.
+/// Custom functions, `groups(...)` validators and non-literal limits have no keyword.
+pub trait ValidateSchema {
+    fn json_schema() -> serde_json::Value;
 }
 
-pub use serde_valid_derive::Validate;
+pub use serde_valid_derive::{AsyncValidate, Validate, ValidateSchema};
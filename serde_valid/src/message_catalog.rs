@@ -0,0 +1,195 @@
+use std::sync::{Arc, PoisonError, RwLock};
+
+use crate::error::{
+    AdditionalPropertiesError, AllOfError, AnyOfError, ContainsError, EnumerateError, EqualsError,
+    ExclusiveMaximumError, ExclusiveMinimumError, FalseSchemaError, FormatError, GreaterThanError,
+    GreaterThanOrEqualError, LessThanError, LessThanOrEqualError, MaxContainsError, MaxItemsError,
+    MaxLengthError, MaxPropertiesError, MaximumError, MinContainsError, MinItemsError,
+    MinLengthError, MinPropertiesError, MinimumError, MultipleOfError, NotEqualsError, NotError,
+    OneOfError, PatternError, RequiredError, TypeError, UniqueItemsError,
+};
+
+/// Default messages of the built-in errors, used when a field has neither `message` nor `message_fn`.
+///
+/// Each method defaults to the built-in English message, so a catalog only overrides what it needs,
+/// and can fall back to [`BuiltinMessage`] itself.
+///
+/// ```rust
+/// use serde_valid::{BuiltinMessage, MaxLengthError, MaximumError, MessageCatalog};
+///
+/// struct Catalog;
+///
+/// impl MessageCatalog for Catalog {
+///     fn maximum(&self, error: &MaximumError) -> String {
+///         format!("at most {}", error.maximum)
+///     }
+///
+///     fn max_length(&self, error: &MaxLengthError) -> String {
+///         match error.max_length {
+///             0 => "must be empty".to_string(),
+///             _ => error.builtin_message(),
+///         }
+///     }
+/// }
+///
+/// serde_valid::set_default_messages(Catalog);
+/// ```
+pub trait MessageCatalog: Send + Sync {
+    fn minimum(&self, error: &MinimumError) -> String {
+        error.builtin_message()
+    }
+
+    fn maximum(&self, error: &MaximumError) -> String {
+        error.builtin_message()
+    }
+
+    fn exclusive_minimum(&self, error: &ExclusiveMinimumError) -> String {
+        error.builtin_message()
+    }
+
+    fn exclusive_maximum(&self, error: &ExclusiveMaximumError) -> String {
+        error.builtin_message()
+    }
+
+    fn multiple_of(&self, error: &MultipleOfError) -> String {
+        error.builtin_message()
+    }
+
+    fn min_length(&self, error: &MinLengthError) -> String {
+        error.builtin_message()
+    }
+
+    fn max_length(&self, error: &MaxLengthError) -> String {
+        error.builtin_message()
+    }
+
+    fn pattern(&self, error: &PatternError) -> String {
+        error.builtin_message()
+    }
+
+    fn format(&self, error: &FormatError) -> String {
+        error.builtin_message()
+    }
+
+    fn min_items(&self, error: &MinItemsError) -> String {
+        error.builtin_message()
+    }
+
+    fn max_items(&self, error: &MaxItemsError) -> String {
+        error.builtin_message()
+    }
+
+    fn unique_items(&self, error: &UniqueItemsError) -> String {
+        error.builtin_message()
+    }
+
+    fn contains(&self, error: &ContainsError) -> String {
+        error.builtin_message()
+    }
+
+    fn min_contains(&self, error: &MinContainsError) -> String {
+        error.builtin_message()
+    }
+
+    fn max_contains(&self, error: &MaxContainsError) -> String {
+        error.builtin_message()
+    }
+
+    fn min_properties(&self, error: &MinPropertiesError) -> String {
+        error.builtin_message()
+    }
+
+    fn max_properties(&self, error: &MaxPropertiesError) -> String {
+        error.builtin_message()
+    }
+
+    fn enumerate(&self, error: &EnumerateError) -> String {
+        error.builtin_message()
+    }
+
+    fn greater_than(&self, error: &GreaterThanError) -> String {
+        error.builtin_message()
+    }
+
+    fn greater_than_or_equal(&self, error: &GreaterThanOrEqualError) -> String {
+        error.builtin_message()
+    }
+
+    fn less_than(&self, error: &LessThanError) -> String {
+        error.builtin_message()
+    }
+
+    fn less_than_or_equal(&self, error: &LessThanOrEqualError) -> String {
+        error.builtin_message()
+    }
+
+    fn equals(&self, error: &EqualsError) -> String {
+        error.builtin_message()
+    }
+
+    fn not_equals(&self, error: &NotEqualsError) -> String {
+        error.builtin_message()
+    }
+
+    fn any_of(&self, error: &AnyOfError) -> String {
+        error.builtin_message()
+    }
+
+    fn all_of(&self, error: &AllOfError) -> String {
+        error.builtin_message()
+    }
+
+    fn one_of(&self, error: &OneOfError) -> String {
+        error.builtin_message()
+    }
+
+    fn not(&self, error: &NotError) -> String {
+        error.builtin_message()
+    }
+
+    fn type_(&self, error: &TypeError) -> String {
+        error.builtin_message()
+    }
+
+    fn required(&self, error: &RequiredError) -> String {
+        error.builtin_message()
+    }
+
+    fn additional_properties(&self, error: &AdditionalPropertiesError) -> String {
+        error.builtin_message()
+    }
+
+    fn false_schema(&self, error: &FalseSchemaError) -> String {
+        error.builtin_message()
+    }
+}
+
+/// Replace the default messages of the whole process with `catalog`.
+pub fn set_default_messages(catalog: impl MessageCatalog + 'static) {
+    *DEFAULT_MESSAGES
+        .write()
+        .unwrap_or_else(PoisonError::into_inner) = Some(Arc::new(catalog));
+}
+
+static DEFAULT_MESSAGES: RwLock<Option<Arc<dyn MessageCatalog>>> = RwLock::new(None);
+
+struct BuiltinMessages;
+
+impl MessageCatalog for BuiltinMessages {}
+
+/// Run `f` with the current catalog, outside the lock so that the catalog may use it again.
+pub(crate) fn with_default_messages<T>(f: impl FnOnce(&dyn MessageCatalog) -> T) -> T {
+    let catalog = DEFAULT_MESSAGES
+        .read()
+        .unwrap_or_else(PoisonError::into_inner)
+        .clone();
+    match catalog {
+        Some(catalog) => f(catalog.as_ref()),
+        None => f(&BuiltinMessages),
+    }
+}
+
+/// The English message of an error, before the [`MessageCatalog`] is consulted.
+pub trait BuiltinMessage {
+    fn builtin_message(&self) -> String;
+}
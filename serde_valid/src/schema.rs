@@ -0,0 +1,887 @@
+//! Runtime validation of [`serde_json::Value`] against a JSON Schema document.
+//!
+//! [`Schema`] compiles a JSON Schema once and validates values with the same
+//! [`Error`](crate::validation::Error) variants the derived validations use,
+//! so [`Errors`] can be flattened, localized and returned as usual.
+//! The keywords without a derived validation report [`TypeError`], [`RequiredError`],
+//! [`AdditionalPropertiesError`] and [`FalseSchemaError`].
+//!
+//! ```rust
+//! use serde_json::json;
+//! use serde_valid::schema::Schema;
+//!
+//! let schema = Schema::new(&json!({
+//!     "type": "object",
+//!     "properties": {
+//!         "timeout": {"type": "integer", "maximum": 60}
+//!     },
+//!     "required": ["timeout"]
+//! }))
+//! .unwrap();
+//!
+//! assert!(schema.validate(&json!({"timeout": 30})).is_ok());
+//! assert_eq!(
+//!     schema.validate(&json!({"timeout": 90})).unwrap_err().to_string(),
+//!     json!({
+//!         "errors": [],
+//!         "properties": {
+//!             "timeout": {"errors": ["The number must be `<= 60`."]}
+//!         }
+//!     })
+//!     .to_string()
+//! );
+//! ```
+//!
+//! Supported keywords are `type`, `enum`, `const`, the numeric, string, array and
+//! object validation keywords, `items`, `contains`, `properties`, `patternProperties`,
+//! `propertyNames`, `required`, `additionalProperties`, `allOf`, `anyOf`, `oneOf` and `not`,
+//! so the schemas of [`ValidateSchema`](crate::ValidateSchema) can be compiled.
+//! Annotations such as `title` are ignored,
+//! and schemas using other applicators such as `$ref` or `if` are rejected by [`Schema::new`].
+
+use std::cmp::Ordering;
+
+use indexmap::IndexMap;
+use serde_json::{Map, Value};
+
+use crate::error::{
+    AdditionalPropertiesError, AllOfError, AnyOfError, ContainsError, EnumerateError,
+    ExclusiveMaximumError, ExclusiveMinimumError, FalseSchemaError, FormatError, MaxContainsError,
+    MaxItemsError, MaxLengthError, MaxPropertiesError, MaximumError, MinContainsError,
+    MinItemsError, MinLengthError, MinPropertiesError, MinimumError, MultipleOfError, NotError,
+    OneOfError, PatternError, RequiredError, TypeError, UniqueItemsError,
+};
+use crate::traits::{Duplicates, Length};
+use crate::validation::{
+    ArrayErrors, Composited, Error, Errors, IntoError, Literal, Message, Number, ObjectErrors,
+    PropertyErrorsMap, ToDefaultMessage, VecErrors,
+};
+
+const UNSUPPORTED_KEYWORDS: &[&str] = &[
+    "$ref",
+    "$dynamicRef",
+    "if",
+    "then",
+    "else",
+    "prefixItems",
+    "additionalItems",
+    "dependencies",
+    "dependentRequired",
+    "dependentSchemas",
+    "unevaluatedItems",
+    "unevaluatedProperties",
+];
+
+/// Error compiling a JSON Schema document.
+#[derive(Debug, thiserror::Error)]
+pub enum SchemaError {
+    #[error("`{keyword}` must be {expected}.")]
+    InvalidKeyword {
+        keyword: &'static str,
+        expected: &'static str,
+    },
+
+    #[error("`{0}` is not supported.")]
+    UnsupportedKeyword(String),
+
+    #[error(transparent)]
+    Pattern(#[from] regex::Error),
+}
+
+/// A compiled JSON Schema.
+#[derive(Debug, Clone, Default)]
+pub struct Schema {
+    reject_all: bool,
+    types: Vec<&'static str>,
+    enumerate: Option<Enumerate>,
+    minimum: Option<serde_json::Number>,
+    maximum: Option<serde_json::Number>,
+    exclusive_minimum: Option<serde_json::Number>,
+    exclusive_maximum: Option<serde_json::Number>,
+    multiple_of: Option<serde_json::Number>,
+    min_length: Option<usize>,
+    max_length: Option<usize>,
+    pattern: Option<regex::Regex>,
+    format: Option<String>,
+    min_items: Option<usize>,
+    max_items: Option<usize>,
+    unique_items: bool,
+    items: Option<Items>,
+    contains: Option<Box<Schema>>,
+    min_contains: Option<usize>,
+    max_contains: Option<usize>,
+    min_properties: Option<usize>,
+    max_properties: Option<usize>,
+    properties: IndexMap<String, Schema>,
+    pattern_properties: Vec<(regex::Regex, Schema)>,
+    property_names: Option<Box<Schema>>,
+    required: Vec<String>,
+    additional_properties: AdditionalProperties,
+    all_of: Vec<Schema>,
+    any_of: Vec<Schema>,
+    one_of: Vec<Schema>,
+    not: Option<Box<Schema>>,
+}
+
+#[derive(Debug, Clone, Default)]
+enum AdditionalProperties {
+    #[default]
+    Allowed,
+    Forbidden,
+    Schema(Box<Schema>),
+}
+
+#[derive(Debug, Clone)]
+struct Enumerate {
+    values: Vec<Value>,
+    literals: Vec<Literal>,
+}
+
+#[derive(Debug, Clone)]
+enum Items {
+    All(Box<Schema>),
+    Tuple(Vec<Schema>),
+}
+
+impl Schema {
+    /// Compile a JSON Schema document.
+    pub fn new(schema: &Value) -> Result<Self, SchemaError> {
+        let keywords = match schema {
+            Value::Bool(true) => return Ok(Self::default()),
+            Value::Bool(false) => {
+                return Ok(Self {
+                    reject_all: true,
+                    ..Self::default()
+                })
+            }
+            Value::Object(keywords) => keywords,
+            _ => {
+                return Err(SchemaError::InvalidKeyword {
+                    keyword: "schema",
+                    expected: "an object or a boolean",
+                })
+            }
+        };
+
+        if let Some(keyword) = UNSUPPORTED_KEYWORDS
+            .iter()
+            .find(|keyword| keywords.contains_key(**keyword))
+        {
+            return Err(SchemaError::UnsupportedKeyword((*keyword).to_owned()));
+        }
+
+        Ok(Self {
+            reject_all: false,
+            types: compile_types(keywords)?,
+            enumerate: compile_enumerate(keywords)?,
+            minimum: number_keyword(keywords, "minimum")?,
+            maximum: number_keyword(keywords, "maximum")?,
+            exclusive_minimum: number_keyword(keywords, "exclusiveMinimum")?,
+            exclusive_maximum: number_keyword(keywords, "exclusiveMaximum")?,
+            multiple_of: match number_keyword(keywords, "multipleOf")? {
+                Some(multiple_of) if multiple_of.as_f64().is_some_and(|n| n <= 0.0) => {
+                    return Err(SchemaError::InvalidKeyword {
+                        keyword: "multipleOf",
+                        expected: "a number greater than 0",
+                    })
+                }
+                multiple_of => multiple_of,
+            },
+            min_length: usize_keyword(keywords, "minLength")?,
+            max_length: usize_keyword(keywords, "maxLength")?,
+            pattern: match str_keyword(keywords, "pattern")? {
+                Some(pattern) => Some(regex::Regex::new(pattern)?),
+                None => None,
+            },
+            format: str_keyword(keywords, "format")?.map(ToOwned::to_owned),
+            min_items: usize_keyword(keywords, "minItems")?,
+            max_items: usize_keyword(keywords, "maxItems")?,
+            unique_items: bool_keyword(keywords, "uniqueItems")?.unwrap_or(false),
+            items: match keywords.get("items") {
+                Some(Value::Array(schemas)) => Some(Items::Tuple(
+                    schemas.iter().map(Schema::new).collect::<Result<_, _>>()?,
+                )),
+                Some(schema) => Some(Items::All(Box::new(Schema::new(schema)?))),
+                None => None,
+            },
+            contains: schema_keyword(keywords, "contains")?,
+            min_contains: usize_keyword(keywords, "minContains")?,
+            max_contains: usize_keyword(keywords, "maxContains")?,
+            min_properties: usize_keyword(keywords, "minProperties")?,
+            max_properties: usize_keyword(keywords, "maxProperties")?,
+            properties: match keywords.get("properties") {
+                Some(Value::Object(properties)) => properties
+                    .iter()
+                    .map(|(key, schema)| Ok((key.clone(), Schema::new(schema)?)))
+                    .collect::<Result<_, SchemaError>>()?,
+                Some(_) => {
+                    return Err(SchemaError::InvalidKeyword {
+                        keyword: "properties",
+                        expected: "an object",
+                    })
+                }
+                None => IndexMap::new(),
+            },
+            pattern_properties: match keywords.get("patternProperties") {
+                Some(Value::Object(properties)) => properties
+                    .iter()
+                    .map(|(pattern, schema)| {
+                        Ok((regex::Regex::new(pattern)?, Schema::new(schema)?))
+                    })
+                    .collect::<Result<_, SchemaError>>()?,
+                Some(_) => {
+                    return Err(SchemaError::InvalidKeyword {
+                        keyword: "patternProperties",
+                        expected: "an object",
+                    })
+                }
+                None => vec![],
+            },
+            property_names: schema_keyword(keywords, "propertyNames")?,
+            required: match keywords.get("required") {
+                Some(Value::Array(required)) => required
+                    .iter()
+                    .map(|key| match key {
+                        Value::String(key) => Ok(key.clone()),
+                        _ => Err(SchemaError::InvalidKeyword {
+                            keyword: "required",
+                            expected: "an array of strings",
+                        }),
+                    })
+                    .collect::<Result<_, _>>()?,
+                Some(_) => {
+                    return Err(SchemaError::InvalidKeyword {
+                        keyword: "required",
+                        expected: "an array of strings",
+                    })
+                }
+                None => vec![],
+            },
+            additional_properties: match keywords.get("additionalProperties") {
+                Some(Value::Bool(true)) | None => AdditionalProperties::Allowed,
+                Some(Value::Bool(false)) => AdditionalProperties::Forbidden,
+                Some(schema @ Value::Object(_)) => {
+                    AdditionalProperties::Schema(Box::new(Self::new(schema)?))
+                }
+                Some(_) => {
+                    return Err(SchemaError::InvalidKeyword {
+                        keyword: "additionalProperties",
+                        expected: "a boolean or a schema",
+                    })
+                }
+            },
+            all_of: schemas_keyword(keywords, "allOf")?,
+            any_of: schemas_keyword(keywords, "anyOf")?,
+            one_of: schemas_keyword(keywords, "oneOf")?,
+            not: schema_keyword(keywords, "not")?,
+        })
+    }
+
+    /// Validate `value` against the schema.
+    pub fn validate(&self, value: &Value) -> Result<(), Errors> {
+        let mut errors = vec![];
+
+        if self.reject_all {
+            errors.push(Error::FalseSchema(Message::new(
+                FalseSchemaError {},
+                FalseSchemaError::to_default_message,
+            )));
+            return Err(Errors::NewType(errors));
+        }
+
+        if !self.types.is_empty() && !self.types.iter().any(|ty| is_type(value, ty)) {
+            errors.push(Error::Type(Message::new(
+                TypeError::new(self.types.clone()),
+                TypeError::to_default_message,
+            )));
+        }
+
+        if let Some(Enumerate { values, literals }) = &self.enumerate {
+            if !values.contains(value) {
+                errors.push(
+                    Composited::Single(EnumerateError {
+                        enumerate: literals.clone(),
+                    })
+                    .into_error(),
+                );
+            }
+        }
+
+        self.validate_composition(value, &mut errors);
+
+        match value {
+            Value::Number(number) => self.validate_number(number, &mut errors),
+            Value::String(string) => self.validate_string(string, &mut errors),
+            Value::Array(items) => return self.validate_array(items, errors),
+            Value::Object(properties) => return self.validate_object(properties, errors),
+            Value::Null | Value::Bool(_) => {}
+        }
+
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(Errors::NewType(errors))
+        }
+    }
+
+    fn validate_composition(&self, value: &Value, errors: &mut Vec<Error>) {
+        if !self.all_of.is_empty() {
+            let branch_errors = validate_branches(&self.all_of, value);
+            if branch_errors.iter().any(|errors| !errors.is_empty()) {
+                errors.push(Error::AllOf(Message::new(
+                    AllOfError::new(branch_errors),
+                    AllOfError::to_default_message,
+                )));
+            }
+        }
+        if !self.any_of.is_empty() {
+            let branch_errors = validate_branches(&self.any_of, value);
+            if branch_errors.iter().all(|errors| !errors.is_empty()) {
+                errors.push(Error::AnyOf(Message::new(
+                    AnyOfError::new(branch_errors),
+                    AnyOfError::to_default_message,
+                )));
+            }
+        }
+        if !self.one_of.is_empty() {
+            let branch_errors = validate_branches(&self.one_of, value);
+            if branch_errors
+                .iter()
+                .filter(|errors| errors.is_empty())
+                .count()
+                != 1
+            {
+                errors.push(Error::OneOf(Message::new(
+                    OneOfError::new(branch_errors),
+                    OneOfError::to_default_message,
+                )));
+            }
+        }
+        if let Some(not) = &self.not {
+            if not.validate(value).is_ok() {
+                errors.push(Error::Not(Message::new(
+                    NotError {},
+                    NotError::to_default_message,
+                )));
+            }
+        }
+    }
+
+    fn validate_number(&self, number: &serde_json::Number, errors: &mut Vec<Error>) {
+        if let Some(minimum) = &self.minimum {
+            if compare(number, minimum) == Some(Ordering::Less) {
+                errors.push(Composited::Single(MinimumError::new(to_number(minimum))).into_error());
+            }
+        }
+        if let Some(maximum) = &self.maximum {
+            if compare(number, maximum) == Some(Ordering::Greater) {
+                errors.push(Composited::Single(MaximumError::new(to_number(maximum))).into_error());
+            }
+        }
+        if let Some(exclusive_minimum) = &self.exclusive_minimum {
+            if compare(number, exclusive_minimum) != Some(Ordering::Greater) {
+                errors.push(
+                    Composited::Single(ExclusiveMinimumError::new(to_number(exclusive_minimum)))
+                        .into_error(),
+                );
+            }
+        }
+        if let Some(exclusive_maximum) = &self.exclusive_maximum {
+            if compare(number, exclusive_maximum) != Some(Ordering::Less) {
+                errors.push(
+                    Composited::Single(ExclusiveMaximumError::new(to_number(exclusive_maximum)))
+                        .into_error(),
+                );
+            }
+        }
+        if let Some(multiple_of) = &self.multiple_of {
+            if !is_multiple_of(number, multiple_of) {
+                errors.push(
+                    Composited::Single(MultipleOfError::new(to_number(multiple_of))).into_error(),
+                );
+            }
+        }
+    }
+
+    fn validate_string(&self, string: &str, errors: &mut Vec<Error>) {
+        if self.min_length.is_some() || self.max_length.is_some() {
+            let length = string.length();
+            if let Some(min_length) = self.min_length {
+                if length < min_length {
+                    errors.push(Composited::Single(MinLengthError::new(min_length)).into_error());
+                }
+            }
+            if let Some(max_length) = self.max_length {
+                if length > max_length {
+                    errors.push(Composited::Single(MaxLengthError::new(max_length)).into_error());
+                }
+            }
+        }
+        if let Some(pattern) = &self.pattern {
+            if !pattern.is_match(string) {
+                errors.push(Composited::Single(PatternError::new(pattern)).into_error());
+            }
+        }
+        if let Some(format) = &self.format {
+            if !crate::format::is_valid_format(format, string) {
+                errors.push(Composited::Single(FormatError::new(format.as_str())).into_error());
+            }
+        }
+    }
+
+    fn validate_array(&self, items: &[Value], mut errors: Vec<Error>) -> Result<(), Errors> {
+        if let Some(min_items) = self.min_items {
+            if items.len() < min_items {
+                errors.push(Composited::Single(MinItemsError::new(min_items)).into_error());
+            }
+        }
+        if let Some(max_items) = self.max_items {
+            if items.len() > max_items {
+                errors.push(Composited::Single(MaxItemsError::new(max_items)).into_error());
+            }
+        }
+        if self.unique_items {
+            let duplicates = items.duplicates();
+            if !duplicates.is_empty() {
+                errors.push(Composited::Single(UniqueItemsError::new(duplicates)).into_error());
+            }
+        }
+
+        if let Some(contains) = &self.contains {
+            let matched = items
+                .iter()
+                .filter(|item| contains.validate(item).is_ok())
+                .count();
+            match self.min_contains {
+                Some(min_contains) if matched < min_contains => {
+                    errors.push(Error::MinContains(Message::new(
+                        MinContainsError::new(min_contains),
+                        MinContainsError::to_default_message,
+                    )));
+                }
+                None if matched == 0 => {
+                    errors.push(Error::Contains(Message::new(
+                        ContainsError {},
+                        ContainsError::to_default_message,
+                    )));
+                }
+                _ => {}
+            }
+            if let Some(max_contains) = self.max_contains {
+                if matched > max_contains {
+                    errors.push(Error::MaxContains(Message::new(
+                        MaxContainsError::new(max_contains),
+                        MaxContainsError::to_default_message,
+                    )));
+                }
+            }
+        }
+
+        let item_errors = items
+            .iter()
+            .enumerate()
+            .filter_map(|(index, item)| {
+                let schema = match &self.items {
+                    Some(Items::All(schema)) => Some(schema.as_ref()),
+                    Some(Items::Tuple(schemas)) => schemas.get(index),
+                    None => None,
+                }?;
+                schema.validate(item).err().map(|error| (index, error))
+            })
+            .collect::<IndexMap<_, _>>();
+
+        if errors.is_empty() && item_errors.is_empty() {
+            Ok(())
+        } else {
+            Err(Errors::Array(ArrayErrors::new(errors, item_errors)))
+        }
+    }
+
+    fn validate_object(
+        &self,
+        properties: &Map<String, Value>,
+        mut errors: Vec<Error>,
+    ) -> Result<(), Errors> {
+        if let Some(min_properties) = self.min_properties {
+            if properties.len() < min_properties {
+                errors
+                    .push(Composited::Single(MinPropertiesError::new(min_properties)).into_error());
+            }
+        }
+        if let Some(max_properties) = self.max_properties {
+            if properties.len() > max_properties {
+                errors
+                    .push(Composited::Single(MaxPropertiesError::new(max_properties)).into_error());
+            }
+        }
+        let additional_properties = properties.iter().filter(|(key, _)| {
+            !self.properties.contains_key(*key)
+                && !self
+                    .pattern_properties
+                    .iter()
+                    .any(|(pattern, _)| pattern.is_match(key))
+        });
+        let mut property_errors = IndexMap::new();
+        match &self.additional_properties {
+            AdditionalProperties::Allowed => {}
+            AdditionalProperties::Forbidden => {
+                for (key, _) in additional_properties {
+                    errors.push(Error::AdditionalProperties(Message::new(
+                        AdditionalPropertiesError::new(key.as_str()),
+                        AdditionalPropertiesError::to_default_message,
+                    )));
+                }
+            }
+            AdditionalProperties::Schema(schema) => {
+                for (key, value) in additional_properties {
+                    if let Err(error) = schema.validate(value) {
+                        property_errors.insert(key.clone().into(), error);
+                    }
+                }
+            }
+        }
+
+        for (key, schema) in &self.properties {
+            match properties.get(key) {
+                Some(value) => {
+                    if let Err(error) = schema.validate(value) {
+                        property_errors.insert(key.clone().into(), error);
+                    }
+                }
+                None if self.required.contains(key) => {
+                    property_errors.insert(key.clone().into(), required_errors());
+                }
+                None => {}
+            }
+        }
+        for key in self
+            .required
+            .iter()
+            .filter(|key| !self.properties.contains_key(*key) && !properties.contains_key(*key))
+        {
+            property_errors.insert(key.clone().into(), required_errors());
+        }
+        for (key, value) in properties {
+            if let Some(property_names) = &self.property_names {
+                if let Err(error) = property_names.validate(&Value::String(key.clone())) {
+                    merge_property_errors(&mut property_errors, key, error);
+                }
+            }
+            for (pattern, schema) in &self.pattern_properties {
+                if pattern.is_match(key) {
+                    if let Err(error) = schema.validate(value) {
+                        merge_property_errors(&mut property_errors, key, error);
+                    }
+                }
+            }
+        }
+
+        if errors.is_empty() && property_errors.is_empty() {
+            Ok(())
+        } else {
+            Err(Errors::Object(ObjectErrors::new(errors, property_errors)))
+        }
+    }
+}
+
+fn required_errors() -> Errors {
+    Errors::NewType(vec![Error::Required(Message::new(
+        RequiredError {},
+        RequiredError::to_default_message,
+    ))])
+}
+
+/// Errors of each branch, which are empty for the branches that matched.
+fn validate_branches(schemas: &[Schema], value: &Value) -> Vec<VecErrors> {
+    schemas
+        .iter()
+        .map(|schema| match schema.validate(value) {
+            Ok(()) => vec![],
+            Err(errors) => errors.into_vec_errors(),
+        })
+        .collect()
+}
+
+/// Errors of a single value never mix `Array` and `Object`,
+/// so the errors of several keywords on the same property always merge.
+fn merge_property_errors(property_errors: &mut PropertyErrorsMap<Error>, key: &str, error: Errors) {
+    match property_errors.get_mut(key) {
+        Some(errors) => errors.merge_unchecked(error),
+        None => {
+            property_errors.insert(key.to_owned().into(), error);
+        }
+    }
+}
+
+fn compile_types(keywords: &Map<String, Value>) -> Result<Vec<&'static str>, SchemaError> {
+    const TYPES: &[&str] = &[
+        "null", "boolean", "integer", "number", "string", "array", "object",
+    ];
+    let invalid = || SchemaError::InvalidKeyword {
+        keyword: "type",
+        expected: "a JSON type name or an array of them",
+    };
+    let to_type = |value: &Value| {
+        value
+            .as_str()
+            .and_then(|ty| TYPES.iter().find(|candidate| **candidate == ty).copied())
+            .ok_or_else(invalid)
+    };
+
+    match keywords.get("type") {
+        Some(Value::Array(types)) => types.iter().map(to_type).collect(),
+        Some(ty) => Ok(vec![to_type(ty)?]),
+        None => Ok(vec![]),
+    }
+}
+
+fn compile_enumerate(keywords: &Map<String, Value>) -> Result<Option<Enumerate>, SchemaError> {
+    let (keyword, values) = match (keywords.get("enum"), keywords.get("const")) {
+        (Some(Value::Array(values)), _) => ("enum", values.clone()),
+        (Some(_), _) => {
+            return Err(SchemaError::InvalidKeyword {
+                keyword: "enum",
+                expected: "an array",
+            })
+        }
+        (None, Some(value)) => ("const", vec![value.clone()]),
+        (None, None) => return Ok(None),
+    };
+
+    let literals = values
+        .iter()
+        .map(|value| match value {
+            Value::Null => Ok(Literal::Null),
+            Value::Bool(value) => Ok(Literal::Bool(*value)),
+            Value::Number(value) => Ok(Literal::Number(to_number(value))),
+            Value::String(value) => Ok(Literal::from(value.clone())),
+            Value::Array(_) | Value::Object(_) => Err(SchemaError::InvalidKeyword {
+                keyword,
+                expected: "scalar values",
+            }),
+        })
+        .collect::<Result<_, _>>()?;
+
+    Ok(Some(Enumerate { values, literals }))
+}
+
+fn schema_keyword(
+    keywords: &Map<String, Value>,
+    keyword: &'static str,
+) -> Result<Option<Box<Schema>>, SchemaError> {
+    match keywords.get(keyword) {
+        Some(schema) => Ok(Some(Box::new(Schema::new(schema)?))),
+        None => Ok(None),
+    }
+}
+
+fn schemas_keyword(
+    keywords: &Map<String, Value>,
+    keyword: &'static str,
+) -> Result<Vec<Schema>, SchemaError> {
+    match keywords.get(keyword) {
+        Some(Value::Array(schemas)) if !schemas.is_empty() => {
+            schemas.iter().map(Schema::new).collect()
+        }
+        Some(_) => Err(SchemaError::InvalidKeyword {
+            keyword,
+            expected: "a non-empty array of schemas",
+        }),
+        None => Ok(vec![]),
+    }
+}
+
+fn number_keyword(
+    keywords: &Map<String, Value>,
+    keyword: &'static str,
+) -> Result<Option<serde_json::Number>, SchemaError> {
+    match keywords.get(keyword) {
+        Some(Value::Number(number)) => Ok(Some(number.clone())),
+        Some(_) => Err(SchemaError::InvalidKeyword {
+            keyword,
+            expected: "a number",
+        }),
+        None => Ok(None),
+    }
+}
+
+fn usize_keyword(
+    keywords: &Map<String, Value>,
+    keyword: &'static str,
+) -> Result<Option<usize>, SchemaError> {
+    match keywords.get(keyword) {
+        Some(value) => value
+            .as_u64()
+            .and_then(|value| usize::try_from(value).ok())
+            .map(Some)
+            .ok_or(SchemaError::InvalidKeyword {
+                keyword,
+                expected: "a non-negative integer",
+            }),
+        None => Ok(None),
+    }
+}
+
+fn str_keyword<'a>(
+    keywords: &'a Map<String, Value>,
+    keyword: &'static str,
+) -> Result<Option<&'a str>, SchemaError> {
+    match keywords.get(keyword) {
+        Some(Value::String(value)) => Ok(Some(value)),
+        Some(_) => Err(SchemaError::InvalidKeyword {
+            keyword,
+            expected: "a string",
+        }),
+        None => Ok(None),
+    }
+}
+
+fn bool_keyword(
+    keywords: &Map<String, Value>,
+    keyword: &'static str,
+) -> Result<Option<bool>, SchemaError> {
+    match keywords.get(keyword) {
+        Some(Value::Bool(value)) => Ok(Some(*value)),
+        Some(_) => Err(SchemaError::InvalidKeyword {
+            keyword,
+            expected: "a boolean",
+        }),
+        None => Ok(None),
+    }
+}
+
+fn is_type(value: &Value, ty: &str) -> bool {
+    match ty {
+        "null" => value.is_null(),
+        "boolean" => value.is_boolean(),
+        "integer" => match value {
+            Value::Number(number) => {
+                number.is_i64()
+                    || number.is_u64()
+                    || number.as_f64().is_some_and(|n| n.fract() == 0.0)
+            }
+            _ => false,
+        },
+        "number" => value.is_number(),
+        "string" => value.is_string(),
+        "array" => value.is_array(),
+        "object" => value.is_object(),
+        _ => false,
+    }
+}
+
+fn to_number(number: &serde_json::Number) -> Number {
+    if let Some(number) = number.as_i64() {
+        Number::I64(number)
+    } else if let Some(number) = number.as_u64() {
+        Number::U64(number)
+    } else {
+        Number::F64(number.as_f64().unwrap_or(f64::NAN))
+    }
+}
+
+fn compare(a: &serde_json::Number, b: &serde_json::Number) -> Option<Ordering> {
+    match (a.as_i64(), b.as_i64(), a.as_u64(), b.as_u64()) {
+        (Some(a), Some(b), _, _) => Some(a.cmp(&b)),
+        (_, _, Some(a), Some(b)) => Some(a.cmp(&b)),
+        _ => a.as_f64()?.partial_cmp(&b.as_f64()?),
+    }
+}
+
+fn is_multiple_of(number: &serde_json::Number, multiple_of: &serde_json::Number) -> bool {
+    match (number.as_i64(), multiple_of.as_i64()) {
+        (Some(number), Some(multiple_of)) => number % multiple_of == 0,
+        _ => match (number.as_f64(), multiple_of.as_f64()) {
+            (Some(number), Some(multiple_of)) => number % multiple_of == 0.0,
+            _ => false,
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_invalid_schema() {
+        assert!(matches!(
+            Schema::new(&json!({"maxLength": -1})),
+            Err(SchemaError::InvalidKeyword {
+                keyword: "maxLength",
+                ..
+            })
+        ));
+        assert!(matches!(
+            Schema::new(&json!({"multipleOf": 0})),
+            Err(SchemaError::InvalidKeyword {
+                keyword: "multipleOf",
+                ..
+            })
+        ));
+        assert!(matches!(
+            Schema::new(&json!({"pattern": "("})),
+            Err(SchemaError::Pattern(_))
+        ));
+        assert!(matches!(
+            Schema::new(&json!({"anyOf": []})),
+            Err(SchemaError::InvalidKeyword {
+                keyword: "anyOf",
+                ..
+            })
+        ));
+        assert!(matches!(
+            Schema::new(&json!({"$ref": "#/$defs/item"})),
+            Err(SchemaError::UnsupportedKeyword(keyword)) if keyword == "$ref"
+        ));
+    }
+
+    #[test]
+    fn test_boolean_schema() {
+        assert!(Schema::new(&json!(true))
+            .unwrap()
+            .validate(&json!(1))
+            .is_ok());
+        assert!(Schema::new(&json!(false))
+            .unwrap()
+            .validate(&json!(1))
+            .is_err());
+    }
+
+    #[test]
+    fn test_number_compare() {
+        let schema =
+            Schema::new(&json!({"minimum": -1, "maximum": 1.5, "multipleOf": 0.5})).unwrap();
+
+        assert!(schema.validate(&json!(-1)).is_ok());
+        assert!(schema.validate(&json!(1.5)).is_ok());
+        assert!(schema.validate(&json!(-2)).is_err());
+        assert!(schema.validate(&json!(u64::MAX)).is_err());
+        assert!(schema.validate(&json!(0.25)).is_err());
+    }
+
+    #[test]
+    fn test_pattern_properties_are_not_additional() {
+        let schema = Schema::new(&json!({
+            "patternProperties": {"^x-": {"type": "string"}},
+            "additionalProperties": false
+        }))
+        .unwrap();
+
+        assert!(schema.validate(&json!({"x-a": "a"})).is_ok());
+        assert!(schema.validate(&json!({"x-a": 1})).is_err());
+        assert!(schema.validate(&json!({"a": "a"})).is_err());
+    }
+
+    #[test]
+    fn test_contains_count() {
+        let schema = Schema::new(&json!({"contains": {"const": 1}})).unwrap();
+
+        assert!(schema.validate(&json!([0, 1])).is_ok());
+        assert!(schema.validate(&json!([0])).is_err());
+
+        let schema = Schema::new(&json!({"contains": {"const": 1}, "minContains": 0})).unwrap();
+
+        assert!(schema.validate(&json!([0])).is_ok());
+    }
+}
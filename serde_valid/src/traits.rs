@@ -1,9 +1,11 @@
+mod duplicates;
+mod is_format;
 mod is_match;
-mod is_unique;
 mod length;
 mod size;
 
+pub use duplicates::{Duplicates, HashedDuplicates};
+pub use is_format::IsFormat;
 pub use is_match::IsMatch;
-pub use is_unique::IsUnique;
 pub use length::Length;
 pub use size::Size;
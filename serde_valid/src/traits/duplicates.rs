@@ -0,0 +1,39 @@
+use std::collections::HashSet;
+use std::hash::Hash;
+
+pub trait Duplicates {
+    /// Indices of the items equal to an earlier item.
+    fn duplicates(&self) -> Vec<usize>;
+}
+
+impl<T> Duplicates for [T]
+where
+    T: PartialEq,
+{
+    fn duplicates(&self) -> Vec<usize> {
+        self.iter()
+            .enumerate()
+            .filter(|(index, item)| self[..*index].contains(item))
+            .map(|(index, _)| index)
+            .collect()
+    }
+}
+
+pub trait HashedDuplicates {
+    /// Indices of the items equal to an earlier item, found by hashing instead of comparing every pair.
+    fn hashed_duplicates(&self) -> Vec<usize>;
+}
+
+impl<T> HashedDuplicates for [T]
+where
+    T: Eq + Hash,
+{
+    fn hashed_duplicates(&self) -> Vec<usize> {
+        let mut seen = HashSet::with_capacity(self.len());
+        self.iter()
+            .enumerate()
+            .filter(|(_, item)| !seen.insert(*item))
+            .map(|(index, _)| index)
+            .collect()
+    }
+}
@@ -0,0 +1,33 @@
+pub trait IsFormat {
+    fn is_format(&self, format: &str) -> bool;
+}
+
+macro_rules! impl_for_str {
+    ($ty:ty) => {
+        impl IsFormat for $ty {
+            fn is_format(&self, format: &str) -> bool {
+                crate::format::is_valid_format(format, self)
+            }
+        }
+    };
+}
+
+impl_for_str!(str);
+impl_for_str!(&str);
+impl_for_str!(String);
+impl_for_str!(std::borrow::Cow<'_, str>);
+
+macro_rules! impl_for_os_str {
+    ($ty:ty) => {
+        impl IsFormat for $ty {
+            fn is_format(&self, format: &str) -> bool {
+                crate::format::is_valid_format(format, &self.to_string_lossy())
+            }
+        }
+    };
+}
+
+impl_for_os_str!(std::ffi::OsStr);
+impl_for_os_str!(&std::ffi::OsStr);
+impl_for_os_str!(std::ffi::OsString);
+impl_for_os_str!(std::borrow::Cow<'_, std::ffi::OsStr>);
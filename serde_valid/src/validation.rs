@@ -6,15 +6,20 @@ mod object;
 mod string;
 
 use crate::{
-    EnumerateError, ExclusiveMaximumError, ExclusiveMinimumError, MaxLengthError,
+    EnumerateError, ExclusiveMaximumError, ExclusiveMinimumError, FormatError, MaxLengthError,
     MaxPropertiesError, MaximumError, MinLengthError, MinPropertiesError, MinimumError,
     MultipleOfError, PatternError,
 };
-pub use array::{ValidateMaxItems, ValidateMinItems, ValidateUniqueItems};
+#[doc(hidden)]
+pub use array::{UniqueItemsField, ValidateUniqueItemsByEq, ValidateUniqueItemsByHash};
+pub use array::{
+    ValidateContains, ValidateMaxContains, ValidateMaxItems, ValidateMinContains, ValidateMinItems,
+    ValidateUniqueItems, ValidateUniqueItemsBy,
+};
 pub use error::{
-    ArrayErrors, Composited, CustomMessage, Error, Errors, IntoError, ItemErrorsMap,
-    ItemVecErrorsMap, Message, ObjectErrors, PropertyErrorsMap, PropertyVecErrorsMap,
-    ToDefaultMessage, VecErrors,
+    ArrayErrors, Composited, CustomMessage, Error, ErrorCode, Errors, IntoError, IntoRuleErrors,
+    ItemErrorsMap, ItemVecErrorsMap, Message, ObjectErrors, PropertyErrorsMap,
+    PropertyVecErrorsMap, RuleError, StructuredError, ToDefaultMessage, VecErrors,
 };
 pub use generic::ValidateEnumerate;
 use indexmap::IndexMap;
@@ -22,9 +27,11 @@ pub use numeric::{
     ValidateExclusiveMaximum, ValidateExclusiveMinimum, ValidateMaximum, ValidateMinimum,
     ValidateMultipleOf,
 };
-pub use object::{ValidateMaxProperties, ValidateMinProperties};
+pub use object::{PropertyEntries, ValidateMaxProperties, ValidateMinProperties};
 pub use serde_valid_literal::{Literal, Number, Pattern};
-pub use string::{ValidateMaxLength, ValidateMinLength, ValidatePattern};
+use std::borrow::Cow;
+use std::collections::{BTreeMap, HashMap, HashSet, VecDeque};
+pub use string::{ValidateFormat, ValidateMaxLength, ValidateMinLength, ValidatePattern};
 
 macro_rules! impl_composited_validation_1args {
     (
@@ -34,6 +41,25 @@ macro_rules! impl_composited_validation_1args {
                 $limit:ident: $limit_type:ty$(,)*
             ) -> Result<(), Composited<$Error:ty>>;
         }
+    ) => {
+        impl_composited_validation_1args!(
+            @single
+            pub trait $ValidateCompositedTrait {
+                fn $validate_composited_method(&self, $limit: $limit_type) -> Result<(), Composited<$Error>>;
+            }
+        );
+
+        impl_composited_items!($ValidateCompositedTrait, $validate_composited_method, $limit: $limit_type, $Error);
+        impl_composited_properties!($ValidateCompositedTrait, $validate_composited_method, $limit: $limit_type, $Error);
+    };
+    (
+        @single
+        pub trait $ValidateCompositedTrait:ident {
+            fn $validate_composited_method:ident(
+                &self,
+                $limit:ident: $limit_type:ty$(,)*
+            ) -> Result<(), Composited<$Error:ty>>;
+        }
     ) => {
         paste::paste! {
             pub trait $ValidateCompositedTrait {
@@ -55,75 +81,6 @@ macro_rules! impl_composited_validation_1args {
                         .map_err(|error| Composited::Single(error))
                 }
             }
-
-            impl<T> $ValidateCompositedTrait for Vec<T>
-            where
-                T: $ValidateCompositedTrait,
-            {
-                fn $validate_composited_method(
-                    &self,
-                    $limit: $limit_type,
-                ) -> Result<(), Composited<$Error>> {
-                    let errors: IndexMap<usize, crate::validation::Composited<$Error>> = self
-                        .iter()
-                        .enumerate()
-                        .filter_map(
-                            |(index, item)| match item.$validate_composited_method($limit) {
-                                Ok(_) => None,
-                                Err(error) => Some((index, error)),
-                            },
-                        )
-                        .collect();
-
-                    if errors.is_empty() {
-                        Ok(())
-                    } else {
-                        Err(Composited::Array(errors))
-                    }
-                }
-            }
-
-            impl<T, const N: usize> $ValidateCompositedTrait for [T; N]
-            where
-                T: $ValidateCompositedTrait,
-            {
-                fn $validate_composited_method(
-                    &self,
-                    $limit: $limit_type,
-                ) -> Result<(), Composited<$Error>> {
-                    let errors: IndexMap<usize, crate::validation::Composited<$Error>> = self
-                        .iter()
-                        .enumerate()
-                        .filter_map(
-                            |(index, item)| match item.$validate_composited_method($limit) {
-                                Ok(_) => None,
-                                Err(error) => Some((index, error)),
-                            },
-                        )
-                        .collect();
-
-                    if errors.is_empty() {
-                        Ok(())
-                    } else {
-                        Err(Composited::Array(errors))
-                    }
-                }
-            }
-
-            impl<T> $ValidateCompositedTrait for Option<T>
-            where
-                T: $ValidateCompositedTrait,
-            {
-                fn $validate_composited_method(
-                    &self,
-                    $limit: $limit_type,
-                ) -> Result<(), Composited<$Error>> {
-                    match self {
-                        Some(value) => value.$validate_composited_method($limit),
-                        None => Ok(()),
-                    }
-                }
-            }
         }
     };
     (
@@ -141,59 +98,74 @@ macro_rules! impl_composited_validation_1args {
             ) -> Result<(), crate::validation::Composited<$Error>>;
         }
 
-        impl<T, U> $ValidateCompositedTrait<T> for Vec<U>
+        impl_composited_items!($ValidateCompositedTrait<T>, $validate_composited_method, $Error);
+        impl_composited_properties!($ValidateCompositedTrait<T>, $validate_composited_method, $Error);
+    };
+}
+
+/// Composited validation of the sequences, whose errors are keyed by the item index,
+/// and of the sets, whose errors are reported at the set itself.
+macro_rules! impl_composited_items {
+    ($ValidateCompositedTrait:ident, $validate_composited_method:ident, $limit:ident: $limit_type:ty, $Error:ty) => {
+        impl_composited_items!(
+            @impl [T], $ValidateCompositedTrait, $validate_composited_method, $limit: $limit_type, $Error;
+            composited_items => Vec<T>, [T], &[T], VecDeque<T>
+        );
+        impl_composited_items!(
+            @impl [T], $ValidateCompositedTrait, $validate_composited_method, $limit: $limit_type, $Error;
+            composited_set_items => HashSet<T>
+        );
+
+        impl<T, const N: usize> $ValidateCompositedTrait for [T; N]
         where
-            T: Copy,
-            U: $ValidateCompositedTrait<T>,
+            T: $ValidateCompositedTrait,
         {
-            fn $validate_composited_method(
-                &self,
-                $limit: T,
-            ) -> Result<(), crate::validation::Composited<$Error>> {
-                let errors: IndexMap<usize, crate::validation::Composited<$Error>> = self
-                    .iter()
-                    .enumerate()
-                    .filter_map(
-                        |(index, item)| match item.$validate_composited_method($limit) {
-                            Ok(_) => None,
-                            Err(error) => Some((index, error)),
-                        },
-                    )
-                    .collect();
+            fn $validate_composited_method(&self, $limit: $limit_type) -> Result<(), Composited<$Error>> {
+                composited_items(self.iter().map(|item| item.$validate_composited_method($limit)))
+            }
+        }
 
-                if errors.is_empty() {
-                    Ok(())
-                } else {
-                    Err(Composited::Array(errors))
+        impl<T> $ValidateCompositedTrait for Option<T>
+        where
+            T: $ValidateCompositedTrait,
+        {
+            fn $validate_composited_method(&self, $limit: $limit_type) -> Result<(), Composited<$Error>> {
+                match self {
+                    Some(value) => value.$validate_composited_method($limit),
+                    None => Ok(()),
                 }
             }
         }
+    };
+    (@impl [T], $ValidateCompositedTrait:ident, $validate_composited_method:ident, $limit:ident: $limit_type:ty, $Error:ty; $composited:ident => $($Collection:ty),*) => {
+        $(
+            impl<T> $ValidateCompositedTrait for $Collection
+            where
+                T: $ValidateCompositedTrait,
+            {
+                fn $validate_composited_method(&self, $limit: $limit_type) -> Result<(), Composited<$Error>> {
+                    $composited(self.iter().map(|item| item.$validate_composited_method($limit)))
+                }
+            }
+        )*
+    };
+    ($ValidateCompositedTrait:ident<T>, $validate_composited_method:ident, $Error:ty) => {
+        impl_composited_items!(
+            @impl [U], $ValidateCompositedTrait<T>, $validate_composited_method, $Error;
+            composited_items => Vec<U>, [U], &[U], VecDeque<U>
+        );
+        impl_composited_items!(
+            @impl [U], $ValidateCompositedTrait<T>, $validate_composited_method, $Error;
+            composited_set_items => HashSet<U>
+        );
 
         impl<T, U, const N: usize> $ValidateCompositedTrait<T> for [U; N]
         where
             T: Copy,
             U: $ValidateCompositedTrait<T>,
         {
-            fn $validate_composited_method(
-                &self,
-                $limit: T,
-            ) -> Result<(), crate::validation::Composited<$Error>> {
-                let errors: IndexMap<usize, crate::validation::Composited<$Error>> = self
-                    .iter()
-                    .enumerate()
-                    .filter_map(
-                        |(index, item)| match item.$validate_composited_method($limit) {
-                            Ok(_) => None,
-                            Err(error) => Some((index, error)),
-                        },
-                    )
-                    .collect();
-
-                if errors.is_empty() {
-                    Ok(())
-                } else {
-                    Err(Composited::Array(errors))
-                }
+            fn $validate_composited_method(&self, limit: T) -> Result<(), Composited<$Error>> {
+                composited_items(self.iter().map(|item| item.$validate_composited_method(limit)))
             }
         }
 
@@ -202,10 +174,7 @@ macro_rules! impl_composited_validation_1args {
             T: Copy,
             U: $ValidateCompositedTrait<T>,
         {
-            fn $validate_composited_method(
-                &self,
-                limit: T,
-            ) -> Result<(), crate::validation::Composited<$Error>> {
+            fn $validate_composited_method(&self, limit: T) -> Result<(), Composited<$Error>> {
                 match self {
                     Some(value) => value.$validate_composited_method(limit),
                     None => Ok(()),
@@ -213,6 +182,143 @@ macro_rules! impl_composited_validation_1args {
             }
         }
     };
+    (@impl [U], $ValidateCompositedTrait:ident<T>, $validate_composited_method:ident, $Error:ty; $composited:ident => $($Collection:ty),*) => {
+        $(
+            impl<T, U> $ValidateCompositedTrait<T> for $Collection
+            where
+                T: Copy,
+                U: $ValidateCompositedTrait<T>,
+            {
+                fn $validate_composited_method(&self, limit: T) -> Result<(), Composited<$Error>> {
+                    $composited(self.iter().map(|item| item.$validate_composited_method(limit)))
+                }
+            }
+        )*
+    };
+}
+
+/// Composited validation of the map values, whose errors are keyed by the map key.
+macro_rules! impl_composited_properties {
+    ($ValidateCompositedTrait:ident, $validate_composited_method:ident, $limit:ident: $limit_type:ty, $Error:ty) => {
+        impl_composited_properties!(
+            @impl $ValidateCompositedTrait, $validate_composited_method, $limit: $limit_type, $Error;
+            HashMap<K, T>, BTreeMap<K, T>, IndexMap<K, T>
+        );
+    };
+    (@impl $ValidateCompositedTrait:ident, $validate_composited_method:ident, $limit:ident: $limit_type:ty, $Error:ty; $($Map:ty),*) => {
+        $(
+            impl<K, T> $ValidateCompositedTrait for $Map
+            where
+                K: std::fmt::Display,
+                T: $ValidateCompositedTrait,
+            {
+                fn $validate_composited_method(&self, $limit: $limit_type) -> Result<(), Composited<$Error>> {
+                    composited_properties(
+                        self.iter()
+                            .map(|(key, value)| (key, value.$validate_composited_method($limit))),
+                    )
+                }
+            }
+        )*
+    };
+    ($ValidateCompositedTrait:ident<T>, $validate_composited_method:ident, $Error:ty) => {
+        impl_composited_properties!(
+            @impl $ValidateCompositedTrait<T>, $validate_composited_method, $Error;
+            HashMap<K, U>, BTreeMap<K, U>, IndexMap<K, U>
+        );
+    };
+    (@impl $ValidateCompositedTrait:ident<T>, $validate_composited_method:ident, $Error:ty; $($Map:ty),*) => {
+        $(
+            impl<T, K, U> $ValidateCompositedTrait<T> for $Map
+            where
+                T: Copy,
+                K: std::fmt::Display,
+                U: $ValidateCompositedTrait<T>,
+            {
+                fn $validate_composited_method(&self, limit: T) -> Result<(), Composited<$Error>> {
+                    composited_properties(
+                        self.iter()
+                            .map(|(key, value)| (key, value.$validate_composited_method(limit))),
+                    )
+                }
+            }
+        )*
+    };
+}
+
+fn composited_items<E>(
+    results: impl Iterator<Item = Result<(), Composited<E>>>,
+) -> Result<(), Composited<E>> {
+    let errors: IndexMap<usize, Composited<E>> = results
+        .enumerate()
+        .filter_map(|(index, result)| result.err().map(|error| (index, error)))
+        .collect();
+
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(Composited::Array(errors))
+    }
+}
+
+/// The errors of the set items, merged into one error because sets have no stable order.
+///
+/// The items share a type, and the error params only depend on the limit,
+/// so the errors of the items only differ in their nested positions.
+fn composited_set_items<E>(
+    results: impl Iterator<Item = Result<(), Composited<E>>>,
+) -> Result<(), Composited<E>> {
+    match results.filter_map(Result::err).reduce(merge_composited) {
+        Some(error) => Err(error),
+        None => Ok(()),
+    }
+}
+
+fn merge_composited<E>(merged: Composited<E>, error: Composited<E>) -> Composited<E> {
+    match (merged, error) {
+        (Composited::Array(merged), Composited::Array(items)) => {
+            Composited::Array(merge_composited_entries(merged, items))
+        }
+        (Composited::Object(merged), Composited::Object(properties)) => {
+            Composited::Object(merge_composited_entries(merged, properties))
+        }
+        (merged, _) => merged,
+    }
+}
+
+fn merge_composited_entries<K, E>(
+    mut merged: IndexMap<K, Composited<E>>,
+    entries: IndexMap<K, Composited<E>>,
+) -> IndexMap<K, Composited<E>>
+where
+    K: std::hash::Hash + Ord,
+{
+    for (key, error) in entries {
+        let error = match merged.swap_remove(&key) {
+            Some(merged_error) => merge_composited(merged_error, error),
+            None => error,
+        };
+        merged.insert(key, error);
+    }
+    merged.sort_keys();
+    merged
+}
+
+fn composited_properties<K, E>(
+    results: impl Iterator<Item = (K, Result<(), Composited<E>>)>,
+) -> Result<(), Composited<E>>
+where
+    K: std::fmt::Display,
+{
+    let errors: IndexMap<Cow<'static, str>, Composited<E>> = results
+        .filter_map(|(key, result)| result.err().map(|error| (key.to_string().into(), error)))
+        .collect();
+
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(Composited::Object(errors))
+    }
 }
 
 macro_rules! impl_generic_composited_validation_1args {
@@ -307,8 +413,17 @@ impl_composited_validation_1args!(
     }
 );
 
+impl_composited_validation_1args!(
+    pub trait ValidateCompositedFormat {
+        fn validate_composited_format(&self, format: &str) -> Result<(), Composited<FormatError>>;
+    }
+);
+
 // Object
+//
+// The maps count their own properties, so only the sequences fan out over their items.
 impl_composited_validation_1args!(
+    @single
     pub trait ValidateCompositedMaxProperties {
         fn validate_composited_max_properties(
             &self,
@@ -317,7 +432,15 @@ impl_composited_validation_1args!(
     }
 );
 
+impl_composited_items!(
+    ValidateCompositedMaxProperties,
+    validate_composited_max_properties,
+    max_properties: usize,
+    MaxPropertiesError
+);
+
 impl_composited_validation_1args!(
+    @single
     pub trait ValidateCompositedMinProperties {
         fn validate_composited_min_properties(
             &self,
@@ -326,6 +449,13 @@ impl_composited_validation_1args!(
     }
 );
 
+impl_composited_items!(
+    ValidateCompositedMinProperties,
+    validate_composited_min_properties,
+    min_properties: usize,
+    MinPropertiesError
+);
+
 // Generic
 impl_composited_validation_1args!(
     pub trait ValidateCompositedEnumerate<T> {
@@ -1,10 +1,20 @@
+mod contains;
+mod max_contains;
 mod max_items;
+mod min_contains;
 mod min_items;
 mod unique_items;
+mod unique_items_by;
 
+pub use contains::ValidateContains;
+pub use max_contains::ValidateMaxContains;
 pub use max_items::ValidateMaxItems;
+pub use min_contains::ValidateMinContains;
 pub use min_items::ValidateMinItems;
 pub use unique_items::ValidateUniqueItems;
+#[doc(hidden)]
+pub use unique_items::{UniqueItemsField, ValidateUniqueItemsByEq, ValidateUniqueItemsByHash};
+pub use unique_items_by::ValidateUniqueItemsBy;
 
 use crate::{MaxItemsError, MinItemsError};
 
@@ -0,0 +1,114 @@
+/// Validation that the array contains an item matching the predicate.
+///
+/// See <https://json-schema.org/understanding-json-schema/reference/array.html#contains>
+///
+/// ```rust
+/// use serde_json::json;
+/// use serde_valid::{Validate, ValidateContains};
+///
+/// struct Member {
+///     admin: bool,
+/// }
+///
+/// fn is_admin(member: &Member) -> bool {
+///     member.admin
+/// }
+///
+/// #[derive(Validate)]
+/// struct TestStruct {
+///     #[validate(contains(custom(is_admin)))]
+///     members: Vec<Member>,
+/// }
+///
+/// let s = TestStruct {
+///     members: vec![Member { admin: false }],
+/// };
+///
+/// assert_eq!(
+///     s.validate().unwrap_err().to_string(),
+///     json!({
+///         "errors": [],
+///         "properties": {
+///             "members": {
+///                 "errors": ["The items must contain at least one matching item."]
+///             }
+///         }
+///     })
+///     .to_string()
+/// );
+/// ```
+pub trait ValidateContains {
+    type Item;
+
+    fn validate_contains(
+        &self,
+        predicate: impl Fn(&Self::Item) -> bool,
+    ) -> Result<(), crate::ContainsError>;
+}
+
+impl<T> ValidateContains for Vec<T> {
+    type Item = T;
+
+    fn validate_contains(
+        &self,
+        predicate: impl Fn(&Self::Item) -> bool,
+    ) -> Result<(), crate::ContainsError> {
+        if self.iter().any(predicate) {
+            Ok(())
+        } else {
+            Err(crate::ContainsError {})
+        }
+    }
+}
+
+impl<T, const N: usize> ValidateContains for [T; N] {
+    type Item = T;
+
+    fn validate_contains(
+        &self,
+        predicate: impl Fn(&Self::Item) -> bool,
+    ) -> Result<(), crate::ContainsError> {
+        if self.iter().any(predicate) {
+            Ok(())
+        } else {
+            Err(crate::ContainsError {})
+        }
+    }
+}
+
+impl<T> ValidateContains for Option<T>
+where
+    T: ValidateContains,
+{
+    type Item = T::Item;
+
+    fn validate_contains(
+        &self,
+        predicate: impl Fn(&Self::Item) -> bool,
+    ) -> Result<(), crate::ContainsError> {
+        match self {
+            Some(value) => value.validate_contains(predicate),
+            None => Ok(()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_validate_array_contains_is_true() {
+        assert!(ValidateContains::validate_contains(&[1, 2, 3], |item| *item == 2).is_ok());
+    }
+
+    #[test]
+    fn test_validate_array_contains_is_false() {
+        assert!(ValidateContains::validate_contains(&vec![1, 2, 3], |item| *item == 4).is_err());
+    }
+
+    #[test]
+    fn test_validate_array_contains_empty_is_false() {
+        assert!(ValidateContains::validate_contains(&Vec::<i32>::new(), |_| true).is_err());
+    }
+}
@@ -0,0 +1,120 @@
+/// Max count validation of the array items matching the predicate.
+///
+/// See <https://json-schema.org/understanding-json-schema/reference/array.html#mincontains-maxcontains>
+///
+/// ```rust
+/// use serde_json::json;
+/// use serde_valid::{Validate, ValidateMaxContains};
+///
+/// struct Member {
+///     admin: bool,
+/// }
+///
+/// fn is_admin(member: &Member) -> bool {
+///     member.admin
+/// }
+///
+/// #[derive(Validate)]
+/// struct TestStruct {
+///     #[validate(max_contains(1, custom(is_admin)))]
+///     members: Vec<Member>,
+/// }
+///
+/// let s = TestStruct {
+///     members: vec![Member { admin: true }, Member { admin: true }],
+/// };
+///
+/// assert_eq!(
+///     s.validate().unwrap_err().to_string(),
+///     json!({
+///         "errors": [],
+///         "properties": {
+///             "members": {
+///                 "errors": ["The number of matching items must be `<= 1`."]
+///             }
+///         }
+///     })
+///     .to_string()
+/// );
+/// ```
+pub trait ValidateMaxContains {
+    type Item;
+
+    fn validate_max_contains(
+        &self,
+        predicate: impl Fn(&Self::Item) -> bool,
+        max_contains: usize,
+    ) -> Result<(), crate::MaxContainsError>;
+}
+
+impl<T> ValidateMaxContains for Vec<T> {
+    type Item = T;
+
+    fn validate_max_contains(
+        &self,
+        predicate: impl Fn(&Self::Item) -> bool,
+        max_contains: usize,
+    ) -> Result<(), crate::MaxContainsError> {
+        let count = self.iter().filter(|item| predicate(item)).count();
+        if max_contains >= count {
+            Ok(())
+        } else {
+            Err(crate::MaxContainsError::new(max_contains))
+        }
+    }
+}
+
+impl<T, const N: usize> ValidateMaxContains for [T; N] {
+    type Item = T;
+
+    fn validate_max_contains(
+        &self,
+        predicate: impl Fn(&Self::Item) -> bool,
+        max_contains: usize,
+    ) -> Result<(), crate::MaxContainsError> {
+        let count = self.iter().filter(|item| predicate(item)).count();
+        if max_contains >= count {
+            Ok(())
+        } else {
+            Err(crate::MaxContainsError::new(max_contains))
+        }
+    }
+}
+
+impl<T> ValidateMaxContains for Option<T>
+where
+    T: ValidateMaxContains,
+{
+    type Item = T::Item;
+
+    fn validate_max_contains(
+        &self,
+        predicate: impl Fn(&Self::Item) -> bool,
+        max_contains: usize,
+    ) -> Result<(), crate::MaxContainsError> {
+        match self {
+            Some(value) => value.validate_max_contains(predicate, max_contains),
+            None => Ok(()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_validate_array_max_contains_is_true() {
+        assert!(
+            ValidateMaxContains::validate_max_contains(&[1, 2, 3], |item| *item == 2, 1).is_ok()
+        );
+    }
+
+    #[test]
+    fn test_validate_array_max_contains_is_false() {
+        assert!(
+            ValidateMaxContains::validate_max_contains(&vec![1, 2, 2], |item| *item == 2, 1)
+                .is_err()
+        );
+    }
+}
@@ -0,0 +1,120 @@
+/// Min count validation of the array items matching the predicate.
+///
+/// See <https://json-schema.org/understanding-json-schema/reference/array.html#mincontains-maxcontains>
+///
+/// ```rust
+/// use serde_json::json;
+/// use serde_valid::{Validate, ValidateMinContains};
+///
+/// struct Member {
+///     admin: bool,
+/// }
+///
+/// fn is_admin(member: &Member) -> bool {
+///     member.admin
+/// }
+///
+/// #[derive(Validate)]
+/// struct TestStruct {
+///     #[validate(min_contains(2, custom(is_admin)))]
+///     members: Vec<Member>,
+/// }
+///
+/// let s = TestStruct {
+///     members: vec![Member { admin: true }, Member { admin: false }],
+/// };
+///
+/// assert_eq!(
+///     s.validate().unwrap_err().to_string(),
+///     json!({
+///         "errors": [],
+///         "properties": {
+///             "members": {
+///                 "errors": ["The number of matching items must be `>= 2`."]
+///             }
+///         }
+///     })
+///     .to_string()
+/// );
+/// ```
+pub trait ValidateMinContains {
+    type Item;
+
+    fn validate_min_contains(
+        &self,
+        predicate: impl Fn(&Self::Item) -> bool,
+        min_contains: usize,
+    ) -> Result<(), crate::MinContainsError>;
+}
+
+impl<T> ValidateMinContains for Vec<T> {
+    type Item = T;
+
+    fn validate_min_contains(
+        &self,
+        predicate: impl Fn(&Self::Item) -> bool,
+        min_contains: usize,
+    ) -> Result<(), crate::MinContainsError> {
+        let count = self.iter().filter(|item| predicate(item)).count();
+        if min_contains <= count {
+            Ok(())
+        } else {
+            Err(crate::MinContainsError::new(min_contains))
+        }
+    }
+}
+
+impl<T, const N: usize> ValidateMinContains for [T; N] {
+    type Item = T;
+
+    fn validate_min_contains(
+        &self,
+        predicate: impl Fn(&Self::Item) -> bool,
+        min_contains: usize,
+    ) -> Result<(), crate::MinContainsError> {
+        let count = self.iter().filter(|item| predicate(item)).count();
+        if min_contains <= count {
+            Ok(())
+        } else {
+            Err(crate::MinContainsError::new(min_contains))
+        }
+    }
+}
+
+impl<T> ValidateMinContains for Option<T>
+where
+    T: ValidateMinContains,
+{
+    type Item = T::Item;
+
+    fn validate_min_contains(
+        &self,
+        predicate: impl Fn(&Self::Item) -> bool,
+        min_contains: usize,
+    ) -> Result<(), crate::MinContainsError> {
+        match self {
+            Some(value) => value.validate_min_contains(predicate, min_contains),
+            None => Ok(()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_validate_array_min_contains_is_true() {
+        assert!(
+            ValidateMinContains::validate_min_contains(&[1, 2, 2], |item| *item == 2, 2).is_ok()
+        );
+    }
+
+    #[test]
+    fn test_validate_array_min_contains_is_false() {
+        assert!(
+            ValidateMinContains::validate_min_contains(&vec![1, 2, 3], |item| *item == 2, 2)
+                .is_err()
+        );
+    }
+}
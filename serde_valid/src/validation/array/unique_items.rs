@@ -1,7 +1,11 @@
-use crate::traits::IsUnique;
+use crate::traits::{Duplicates, HashedDuplicates};
 
 /// Uniqueness validation of the array items.
 ///
+/// The items only need `PartialEq`, so `f64` and structs without `Hash` can be validated.
+/// `#[validate(unique_items)]` hashes the items instead when they are `Eq + Hash`,
+/// and only compares every pair of them otherwise.
+///
 /// See <https://json-schema.org/understanding-json-schema/reference/array.html#unique_items>
 ///
 /// ```rust
@@ -45,27 +49,19 @@ pub trait ValidateUniqueItems {
 
 impl<T> ValidateUniqueItems for Vec<T>
 where
-    T: std::cmp::Eq + std::hash::Hash + std::fmt::Debug,
+    T: PartialEq,
 {
     fn validate_unique_items(&self) -> Result<(), crate::UniqueItemsError> {
-        if self.is_unique() {
-            Ok(())
-        } else {
-            Err(crate::UniqueItemsError {})
-        }
+        unique_items_result(self.duplicates())
     }
 }
 
 impl<T, const N: usize> ValidateUniqueItems for [T; N]
 where
-    T: std::cmp::Eq + std::hash::Hash + std::fmt::Debug,
+    T: PartialEq,
 {
     fn validate_unique_items(&self) -> Result<(), crate::UniqueItemsError> {
-        if self.is_unique() {
-            Ok(())
-        } else {
-            Err(crate::UniqueItemsError {})
-        }
+        unique_items_result(self.duplicates())
     }
 }
 
@@ -81,6 +77,83 @@ where
     }
 }
 
+/// Uniqueness validation by hashing the items, which `#[validate(unique_items)]` prefers.
+#[doc(hidden)]
+pub trait ValidateHashedUniqueItems {
+    fn validate_hashed_unique_items(&self) -> Result<(), crate::UniqueItemsError>;
+}
+
+impl<T> ValidateHashedUniqueItems for Vec<T>
+where
+    T: Eq + std::hash::Hash,
+{
+    fn validate_hashed_unique_items(&self) -> Result<(), crate::UniqueItemsError> {
+        unique_items_result(self.hashed_duplicates())
+    }
+}
+
+impl<T, const N: usize> ValidateHashedUniqueItems for [T; N]
+where
+    T: Eq + std::hash::Hash,
+{
+    fn validate_hashed_unique_items(&self) -> Result<(), crate::UniqueItemsError> {
+        unique_items_result(self.hashed_duplicates())
+    }
+}
+
+impl<T> ValidateHashedUniqueItems for Option<T>
+where
+    T: ValidateHashedUniqueItems,
+{
+    fn validate_hashed_unique_items(&self) -> Result<(), crate::UniqueItemsError> {
+        match self {
+            Some(value) => value.validate_hashed_unique_items(),
+            None => Ok(()),
+        }
+    }
+}
+
+/// The field of `#[validate(unique_items)]`, whose `validate` picks the hashed validation
+/// by method resolution when the items are `Eq + Hash`, and [`ValidateUniqueItems`] otherwise.
+#[doc(hidden)]
+pub struct UniqueItemsField<'a, T: ?Sized>(pub &'a T);
+
+#[doc(hidden)]
+pub trait ValidateUniqueItemsByHash {
+    fn validate(&self) -> Result<(), crate::UniqueItemsError>;
+}
+
+impl<T> ValidateUniqueItemsByHash for UniqueItemsField<'_, T>
+where
+    T: ValidateHashedUniqueItems + ?Sized,
+{
+    fn validate(&self) -> Result<(), crate::UniqueItemsError> {
+        self.0.validate_hashed_unique_items()
+    }
+}
+
+#[doc(hidden)]
+pub trait ValidateUniqueItemsByEq {
+    fn validate(&self) -> Result<(), crate::UniqueItemsError>;
+}
+
+impl<T> ValidateUniqueItemsByEq for &UniqueItemsField<'_, T>
+where
+    T: ValidateUniqueItems + ?Sized,
+{
+    fn validate(&self) -> Result<(), crate::UniqueItemsError> {
+        self.0.validate_unique_items()
+    }
+}
+
+fn unique_items_result(duplicates: Vec<usize>) -> Result<(), crate::UniqueItemsError> {
+    if duplicates.is_empty() {
+        Ok(())
+    } else {
+        Err(crate::UniqueItemsError::new(duplicates))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -99,4 +172,25 @@ mod tests {
     fn test_validate_array_unique_items_is_false() {
         assert!(ValidateUniqueItems::validate_unique_items(&[1, 2, 3, 3]).is_err());
     }
+
+    #[test]
+    fn test_validate_array_unique_items_duplicates() {
+        assert_eq!(
+            ValidateUniqueItems::validate_unique_items(&vec![1.0, 2.5, 1.0, 2.5, 1.0])
+                .unwrap_err()
+                .duplicates,
+            vec![2, 3, 4]
+        );
+    }
+
+    #[test]
+    fn test_validate_array_hashed_unique_items_duplicates() {
+        assert_eq!(
+            vec![1, 2, 1, 2, 1]
+                .validate_hashed_unique_items()
+                .unwrap_err()
+                .duplicates,
+            vec![2, 3, 4]
+        );
+    }
 }
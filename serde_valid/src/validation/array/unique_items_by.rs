@@ -0,0 +1,136 @@
+use crate::traits::Duplicates;
+
+/// Uniqueness validation of the array items by the key of each item.
+///
+/// See <https://json-schema.org/understanding-json-schema/reference/array.html#unique_items>
+///
+/// ```rust
+/// use serde_json::json;
+/// use serde_valid::{Validate, ValidateUniqueItemsBy};
+///
+/// struct User {
+///     id: u32,
+///     name: String,
+/// }
+///
+/// fn user_id(user: &User) -> u32 {
+///     user.id
+/// }
+///
+/// #[derive(Validate)]
+/// struct TestStruct {
+///     #[validate(unique_items_by = user_id)]
+///     users: Vec<User>,
+/// }
+///
+/// let s = TestStruct {
+///     users: vec![
+///         User { id: 1, name: "taro".to_string() },
+///         User { id: 1, name: "jiro".to_string() },
+///     ],
+/// };
+///
+/// assert_eq!(
+///     s.validate().unwrap_err().to_string(),
+///     json!({
+///         "errors": [],
+///         "properties": {
+///             "users": {
+///                 "errors": ["The items must be unique."]
+///             }
+///         }
+///     })
+///     .to_string()
+/// );
+/// ```
+pub trait ValidateUniqueItemsBy {
+    type Item;
+
+    fn validate_unique_items_by<K: PartialEq>(
+        &self,
+        key: impl Fn(&Self::Item) -> K,
+    ) -> Result<(), crate::UniqueItemsError>;
+}
+
+impl<T> ValidateUniqueItemsBy for Vec<T> {
+    type Item = T;
+
+    fn validate_unique_items_by<K: PartialEq>(
+        &self,
+        key: impl Fn(&Self::Item) -> K,
+    ) -> Result<(), crate::UniqueItemsError> {
+        validate_unique_keys(self.iter().map(key).collect())
+    }
+}
+
+impl<T, const N: usize> ValidateUniqueItemsBy for [T; N] {
+    type Item = T;
+
+    fn validate_unique_items_by<K: PartialEq>(
+        &self,
+        key: impl Fn(&Self::Item) -> K,
+    ) -> Result<(), crate::UniqueItemsError> {
+        validate_unique_keys(self.iter().map(key).collect())
+    }
+}
+
+impl<T> ValidateUniqueItemsBy for Option<T>
+where
+    T: ValidateUniqueItemsBy,
+{
+    type Item = T::Item;
+
+    fn validate_unique_items_by<K: PartialEq>(
+        &self,
+        key: impl Fn(&Self::Item) -> K,
+    ) -> Result<(), crate::UniqueItemsError> {
+        match self {
+            Some(value) => value.validate_unique_items_by(key),
+            None => Ok(()),
+        }
+    }
+}
+
+fn validate_unique_keys<K: PartialEq>(keys: Vec<K>) -> Result<(), crate::UniqueItemsError> {
+    let duplicates = keys.duplicates();
+    if duplicates.is_empty() {
+        Ok(())
+    } else {
+        Err(crate::UniqueItemsError::new(duplicates))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_validate_array_unique_items_by_is_true() {
+        assert!(
+            ValidateUniqueItemsBy::validate_unique_items_by(&[(1, "a"), (2, "a")], |item| item.0)
+                .is_ok()
+        );
+    }
+
+    #[test]
+    fn test_validate_array_unique_items_by_is_false() {
+        assert_eq!(
+            ValidateUniqueItemsBy::validate_unique_items_by(
+                &vec![(1, "a"), (2, "b"), (1, "c")],
+                |item| item.0
+            )
+            .unwrap_err()
+            .duplicates,
+            vec![2]
+        );
+    }
+
+    #[test]
+    fn test_validate_array_unique_items_by_none_is_true() {
+        assert!(ValidateUniqueItemsBy::validate_unique_items_by(
+            &None::<Vec<(i32, i32)>>,
+            |item| item.0
+        )
+        .is_ok());
+    }
+}
@@ -1,25 +1,37 @@
 mod array_erros;
 mod composited;
 mod custom_message;
+mod error_code;
 mod errors;
 mod into_error;
+mod merge_conflict;
 mod message;
 mod object_errors;
+mod rule_error;
+mod structured;
 mod to_default_message;
 
 pub use crate::error::{
-    EnumerateError, ExclusiveMaximumError, ExclusiveMinimumError, MaxItemsError, MaxLengthError,
-    MaxPropertiesError, MaximumError, MinItemsError, MinLengthError, MinPropertiesError,
-    MinimumError, MultipleOfError, PatternError, UniqueItemsError,
+    AdditionalPropertiesError, AllOfError, AnyOfError, ContainsError, EnumerateError, EqualsError,
+    ExclusiveMaximumError, ExclusiveMinimumError, FalseSchemaError, FormatError, GreaterThanError,
+    GreaterThanOrEqualError, LessThanError, LessThanOrEqualError, MaxContainsError, MaxItemsError,
+    MaxLengthError, MaxPropertiesError, MaximumError, MinContainsError, MinItemsError,
+    MinLengthError, MinPropertiesError, MinimumError, MultipleOfError, NotEqualsError, NotError,
+    OneOfError, PatternError, RequiredError, TypeError, UniqueItemsError,
 };
 pub use array_erros::ArrayErrors;
 pub use composited::Composited;
 pub use custom_message::CustomMessage;
+pub use error_code::ErrorCode;
 pub use errors::Errors;
 use indexmap::IndexMap;
 pub use into_error::IntoError;
+pub use merge_conflict::MergeConflict;
 pub use message::Message;
 pub use object_errors::ObjectErrors;
+pub use rule_error::{IntoRuleErrors, RuleError};
+use std::borrow::Cow;
+pub use structured::StructuredError;
 pub use to_default_message::ToDefaultMessage;
 
 #[derive(Debug, Clone, serde::Serialize, thiserror::Error)]
@@ -57,6 +69,10 @@ pub enum Error {
     #[serde(serialize_with = "serialize_error_message")]
     Pattern(Message<PatternError>),
 
+    #[error("{0}")]
+    #[serde(serialize_with = "serialize_error_message")]
+    Format(Message<FormatError>),
+
     #[error("{0}")]
     #[serde(serialize_with = "serialize_error_message")]
     MinItems(Message<MinItemsError>),
@@ -69,6 +85,18 @@ pub enum Error {
     #[serde(serialize_with = "serialize_error_message")]
     UniqueItems(Message<UniqueItemsError>),
 
+    #[error("{0}")]
+    #[serde(serialize_with = "serialize_error_message")]
+    Contains(Message<ContainsError>),
+
+    #[error("{0}")]
+    #[serde(serialize_with = "serialize_error_message")]
+    MinContains(Message<MinContainsError>),
+
+    #[error("{0}")]
+    #[serde(serialize_with = "serialize_error_message")]
+    MaxContains(Message<MaxContainsError>),
+
     #[error("{0}")]
     #[serde(serialize_with = "serialize_error_message")]
     MinProperties(Message<MinPropertiesError>),
@@ -81,6 +109,62 @@ pub enum Error {
     #[serde(serialize_with = "serialize_error_message")]
     Enumerate(Message<EnumerateError>),
 
+    #[error("{0}")]
+    #[serde(serialize_with = "serialize_error_message")]
+    GreaterThan(Message<GreaterThanError>),
+
+    #[error("{0}")]
+    #[serde(serialize_with = "serialize_error_message")]
+    GreaterThanOrEqual(Message<GreaterThanOrEqualError>),
+
+    #[error("{0}")]
+    #[serde(serialize_with = "serialize_error_message")]
+    LessThan(Message<LessThanError>),
+
+    #[error("{0}")]
+    #[serde(serialize_with = "serialize_error_message")]
+    LessThanOrEqual(Message<LessThanOrEqualError>),
+
+    #[error("{0}")]
+    #[serde(serialize_with = "serialize_error_message")]
+    Equals(Message<EqualsError>),
+
+    #[error("{0}")]
+    #[serde(serialize_with = "serialize_error_message")]
+    NotEquals(Message<NotEqualsError>),
+
+    #[error("{0}")]
+    #[serde(serialize_with = "serialize_error_message")]
+    AnyOf(Message<AnyOfError>),
+
+    #[error("{0}")]
+    #[serde(serialize_with = "serialize_error_message")]
+    AllOf(Message<AllOfError>),
+
+    #[error("{0}")]
+    #[serde(serialize_with = "serialize_error_message")]
+    OneOf(Message<OneOfError>),
+
+    #[error("{0}")]
+    #[serde(serialize_with = "serialize_error_message")]
+    Not(Message<NotError>),
+
+    #[error("{0}")]
+    #[serde(serialize_with = "serialize_error_message")]
+    Type(Message<TypeError>),
+
+    #[error("{0}")]
+    #[serde(serialize_with = "serialize_error_message")]
+    Required(Message<RequiredError>),
+
+    #[error("{0}")]
+    #[serde(serialize_with = "serialize_error_message")]
+    AdditionalProperties(Message<AdditionalPropertiesError>),
+
+    #[error("{0}")]
+    #[serde(serialize_with = "serialize_error_message")]
+    FalseSchema(Message<FalseSchemaError>),
+
     #[error("{0}")]
     #[serde(serialize_with = "serialize_error_message")]
     Custom(String),
@@ -108,5 +192,5 @@ where
 pub type VecErrors<E = crate::validation::Error> = Vec<E>;
 pub type ItemErrorsMap<E> = IndexMap<usize, Errors<E>>;
 pub type ItemVecErrorsMap<E> = IndexMap<usize, VecErrors<E>>;
-pub type PropertyErrorsMap<E> = IndexMap<&'static str, Errors<E>>;
-pub type PropertyVecErrorsMap<E> = IndexMap<&'static str, VecErrors<E>>;
+pub type PropertyErrorsMap<E> = IndexMap<Cow<'static, str>, Errors<E>>;
+pub type PropertyVecErrorsMap<E> = IndexMap<Cow<'static, str>, VecErrors<E>>;
@@ -1,6 +1,6 @@
 use serde::ser::SerializeStruct;
 
-use super::{ItemErrorsMap, VecErrors};
+use super::{ItemErrorsMap, MergeConflict, VecErrors};
 
 #[derive(Debug, Clone, thiserror::Error)]
 pub struct ArrayErrors<E = crate::validation::Error> {
@@ -29,22 +29,40 @@ impl<E> ArrayErrors<E> {
     }
 }
 
-impl<E> ArrayErrors<E>
-where
-    E: Clone,
-{
-    pub fn merge(mut self, other: ArrayErrors<E>) -> Self {
+impl<E> ArrayErrors<E> {
+    /// Merge `other` into `self`, item by item.
+    ///
+    /// See [`Errors::merge`](super::Errors::merge) for the conflict rules.
+    pub fn merge(&mut self, other: ArrayErrors<E>) -> Result<(), MergeConflict<ArrayErrors<E>>> {
+        if !self.is_mergeable(&other) {
+            return Err(MergeConflict::new(other));
+        }
+        self.merge_unchecked(other);
+
+        Ok(())
+    }
+
+    pub(crate) fn is_mergeable(&self, other: &ArrayErrors<E>) -> bool {
+        other
+            .items
+            .iter()
+            .all(|(index, item)| match self.items.get(index) {
+                Some(errors) => errors.is_mergeable(item),
+                None => true,
+            })
+    }
+
+    pub(crate) fn merge_unchecked(&mut self, other: ArrayErrors<E>) {
         self.errors.extend(other.errors);
 
         for (index, item) in other.items {
             match self.items.get_mut(&index) {
-                Some(errors) => errors.merge(item),
+                Some(errors) => errors.merge_unchecked(item),
                 None => {
                     self.items.insert(index, item);
                 }
             };
         }
-        self
     }
 }
 
@@ -1,16 +1,18 @@
 use super::into_error::IntoError;
 use super::{custom_message::CustomMessage, Error};
 use crate::error::{
-    EnumerateError, ExclusiveMaximumError, ExclusiveMinimumError, MaxItemsError, MaxLengthError,
-    MaxPropertiesError, MaximumError, MinItemsError, MinLengthError, MinPropertiesError,
-    MinimumError, MultipleOfError, PatternError, UniqueItemsError,
+    EnumerateError, ExclusiveMaximumError, ExclusiveMinimumError, FormatError, MaxItemsError,
+    MaxLengthError, MaxPropertiesError, MaximumError, MinItemsError, MinLengthError,
+    MinPropertiesError, MinimumError, MultipleOfError, PatternError, UniqueItemsError,
 };
 use indexmap::IndexMap;
+use std::borrow::Cow;
 
 #[derive(Debug)]
 pub enum Composited<Error> {
     Single(Error),
     Array(IndexMap<usize, Composited<Error>>),
+    Object(IndexMap<Cow<'static, str>, Composited<Error>>),
 }
 
 macro_rules! impl_into_error {
@@ -32,6 +34,16 @@ macro_rules! impl_into_error {
                                 })
                                 .collect::<IndexMap<_, _>>(),
                         ))},
+                        Composited::Object(object) => {
+                            Error::Properties(crate::validation::ObjectErrors::new(
+                            Vec::with_capacity(0),
+                            object
+                                .into_iter()
+                                .map(|(property, params)| {
+                                    (property, crate::validation::Errors::NewType(vec![params.into_error_by(custom.clone())]))
+                                })
+                                .collect::<IndexMap<_, _>>(),
+                        ))},
                     }
                 }
             }
@@ -53,6 +65,7 @@ impl_into_error!(MultipleOf);
 impl_into_error!(MaxLength);
 impl_into_error!(MinLength);
 impl_into_error!(Pattern);
+impl_into_error!(Format);
 
 // Array
 impl_into_error!(MaxItems);
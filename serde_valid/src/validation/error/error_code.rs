@@ -0,0 +1,4 @@
+/// Machine-readable code of an error, named after its validator (`maximum`, `pattern`, ...).
+pub trait ErrorCode {
+    fn code(&self) -> &'static str;
+}
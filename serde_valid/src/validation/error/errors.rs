@@ -1,4 +1,4 @@
-use super::{ArrayErrors, ObjectErrors, VecErrors};
+use super::{ArrayErrors, MergeConflict, ObjectErrors, VecErrors};
 
 #[derive(Debug, Clone, thiserror::Error)]
 pub enum Errors<E = crate::validation::Error> {
@@ -30,51 +30,72 @@ where
     }
 }
 
-impl<E> Errors<E>
-where
-    E: Clone,
-{
-    pub fn merge(&mut self, other: Errors<E>) {
-        match self {
-            Errors::Array(a) => match other {
-                Errors::Array(b) => {
-                    a.errors.extend(b.errors);
-
-                    for (index, item) in b.items {
-                        match a.items.get_mut(&index) {
-                            Some(errors) => errors.merge(item),
-                            None => {
-                                a.items.insert(index, item);
-                            }
-                        };
-                    }
-                }
-                Errors::Object(_) => {
-                    unreachable!("conflict Array and Object in serde_valid::validation::Errors")
-                }
-                Errors::NewType(errors) => {
-                    a.errors.extend(errors.into_iter());
-                }
-            },
-            Errors::NewType(a) => match other {
-                Errors::Array(b) => {
-                    a.extend(b.errors);
-                    *self = Errors::Array(ArrayErrors::new(a.to_vec(), b.items));
-                }
-                Errors::Object(_) => {
-                    unreachable!("conflict Array and Object in serde_valid::validation::Errors")
+impl<E> Errors<E> {
+    /// Merge `other` into `self`.
+    ///
+    /// - `Array` and `Array` are merged item by item.
+    /// - `Object` and `Object` are merged property by property.
+    /// - `NewType` errors are folded into the `errors` of the other side.
+    ///
+    /// Merging `Array` and `Object` at any depth is a conflict.
+    /// In that case `self` is left unchanged and `other` is returned in the error.
+    pub fn merge(&mut self, other: Errors<E>) -> Result<(), MergeConflict<Errors<E>>> {
+        if !self.is_mergeable(&other) {
+            return Err(MergeConflict::new(other));
+        }
+        self.merge_unchecked(other);
+
+        Ok(())
+    }
+
+    pub(crate) fn is_mergeable(&self, other: &Errors<E>) -> bool {
+        match (self, other) {
+            (Errors::Array(a), Errors::Array(b)) => a.is_mergeable(b),
+            (Errors::Object(a), Errors::Object(b)) => a.is_mergeable(b),
+            (Errors::Array(_), Errors::Object(_)) | (Errors::Object(_), Errors::Array(_)) => false,
+            (Errors::NewType(_), _) | (_, Errors::NewType(_)) => true,
+        }
+    }
+
+    pub(crate) fn merge_unchecked(&mut self, other: Errors<E>) {
+        match (self, other) {
+            (Errors::Array(a), Errors::Array(b)) => a.merge_unchecked(b),
+            (Errors::Object(a), Errors::Object(b)) => a.merge_unchecked(b),
+            (Errors::Array(ArrayErrors { errors, .. }), Errors::NewType(b))
+            | (Errors::Object(ObjectErrors { errors, .. }), Errors::NewType(b))
+            | (Errors::NewType(errors), Errors::NewType(b)) => errors.extend(b),
+            (this @ Errors::NewType(_), Errors::Array(mut b)) => {
+                if let Errors::NewType(a) = std::mem::replace(this, Errors::NewType(vec![])) {
+                    b.errors.splice(0..0, a);
                 }
-                Errors::NewType(b) => {
-                    a.extend(b);
+                *this = Errors::Array(b);
+            }
+            (this @ Errors::NewType(_), Errors::Object(mut b)) => {
+                if let Errors::NewType(a) = std::mem::replace(this, Errors::NewType(vec![])) {
+                    b.errors.splice(0..0, a);
                 }
-            },
-            Errors::Object(_) => {
-                unimplemented!("Object does not support yet.")
+                *this = Errors::Object(b);
+            }
+            (Errors::Array(_), Errors::Object(_)) | (Errors::Object(_), Errors::Array(_)) => {
+                unreachable!("checked by is_mergeable")
             }
         }
     }
 }
 
+impl Errors {
+    /// Errors of a single field, with nested errors kept as `Items` or `Properties`.
+    pub(crate) fn into_vec_errors(self) -> VecErrors {
+        match self {
+            Errors::Array(array_errors) => vec![crate::validation::Error::Items(array_errors)],
+            Errors::Object(object_errors) => {
+                vec![crate::validation::Error::Properties(object_errors)]
+            }
+            Errors::NewType(errors) => errors,
+        }
+    }
+}
+
 impl std::fmt::Display for Errors {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
@@ -91,3 +112,169 @@ impl std::fmt::Display for Errors {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use indexmap::indexmap;
+    use serde_json::json;
+
+    fn object(errors: &[&str], properties: Vec<(&'static str, Errors<String>)>) -> Errors<String> {
+        Errors::Object(ObjectErrors::new(
+            errors.iter().map(ToString::to_string).collect(),
+            properties
+                .into_iter()
+                .map(|(property, errors)| (property.into(), errors))
+                .collect(),
+        ))
+    }
+
+    fn new_type(errors: &[&str]) -> Errors<String> {
+        Errors::NewType(errors.iter().map(ToString::to_string).collect())
+    }
+
+    #[test]
+    fn test_merge_object_errors() {
+        let mut errors = object(
+            &["a"],
+            vec![
+                ("name", new_type(&["name1"])),
+                ("address", object(&[], vec![("city", new_type(&["city1"]))])),
+            ],
+        );
+
+        errors
+            .merge(object(
+                &["b"],
+                vec![
+                    ("name", new_type(&["name2"])),
+                    ("address", object(&[], vec![("zip", new_type(&["zip1"]))])),
+                    ("age", new_type(&["age1"])),
+                ],
+            ))
+            .unwrap();
+
+        assert_eq!(
+            serde_json::to_value(&errors).unwrap(),
+            json!({
+                "errors": ["a", "b"],
+                "properties": {
+                    "name": {"errors": ["name1", "name2"]},
+                    "address": {
+                        "errors": [],
+                        "properties": {
+                            "city": {"errors": ["city1"]},
+                            "zip": {"errors": ["zip1"]}
+                        }
+                    },
+                    "age": {"errors": ["age1"]}
+                }
+            })
+        );
+    }
+
+    #[test]
+    fn test_merge_new_type_into_object_errors() {
+        let mut errors = object(&[], vec![("name", new_type(&["name1"]))]);
+        errors.merge(new_type(&["a"])).unwrap();
+
+        let mut other = new_type(&["b"]);
+        other.merge(errors).unwrap();
+
+        assert_eq!(
+            serde_json::to_value(&other).unwrap(),
+            json!({
+                "errors": ["b", "a"],
+                "properties": {"name": {"errors": ["name1"]}}
+            })
+        );
+    }
+
+    #[test]
+    fn test_merge_array_errors() {
+        let mut errors = Errors::Array(ArrayErrors::new(
+            vec!["a".to_string()],
+            indexmap! {0 => new_type(&["item0"])},
+        ));
+
+        errors
+            .merge(Errors::Array(ArrayErrors::new(
+                vec![],
+                indexmap! {0 => new_type(&["item0'"]), 2 => new_type(&["item2"])},
+            )))
+            .unwrap();
+
+        assert_eq!(
+            serde_json::to_value(&errors).unwrap(),
+            json!({
+                "errors": ["a"],
+                "items": {
+                    "0": {"errors": ["item0", "item0'"]},
+                    "2": {"errors": ["item2"]}
+                }
+            })
+        );
+    }
+
+    #[test]
+    fn test_merge_conflict_leaves_errors_unchanged() {
+        let mut errors = object(
+            &[],
+            vec![
+                ("name", new_type(&["name1"])),
+                ("tags", object(&[], vec![])),
+            ],
+        );
+
+        let conflict = errors
+            .merge(object(
+                &["b"],
+                vec![
+                    ("name", new_type(&["name2"])),
+                    (
+                        "tags",
+                        Errors::Array(ArrayErrors::new(vec![], indexmap! {})),
+                    ),
+                ],
+            ))
+            .unwrap_err();
+
+        assert!(matches!(conflict.into_inner(), Errors::Object(_)));
+        assert_eq!(
+            serde_json::to_value(&errors).unwrap(),
+            json!({
+                "errors": [],
+                "properties": {
+                    "name": {"errors": ["name1"]},
+                    "tags": {"errors": [], "properties": {}}
+                }
+            })
+        );
+    }
+
+    #[test]
+    fn test_merge_owned_and_borrowed_property_keys() {
+        let mut errors = object(&[], vec![("timeout", new_type(&["timeout1"]))]);
+
+        errors
+            .merge(Errors::Object(ObjectErrors::new(
+                vec![],
+                indexmap! {
+                    String::from("timeout").into() => new_type(&["timeout2"]),
+                    format!("retry_{}", 1).into() => new_type(&["retry1"]),
+                },
+            )))
+            .unwrap();
+
+        assert_eq!(
+            serde_json::to_value(&errors).unwrap(),
+            json!({
+                "errors": [],
+                "properties": {
+                    "timeout": {"errors": ["timeout1", "timeout2"]},
+                    "retry_1": {"errors": ["retry1"]}
+                }
+            })
+        );
+    }
+}
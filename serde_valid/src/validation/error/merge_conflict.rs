@@ -0,0 +1,20 @@
+/// Conflict returned when merging errors of a different shape,
+/// e.g. [`Errors::Array`](super::Errors::Array) into [`Errors::Object`](super::Errors::Object).
+///
+/// The merge target is left unchanged and the rejected errors are
+/// handed back in `other`.
+#[derive(Debug, Clone, thiserror::Error)]
+#[error("conflict Array and Object in serde_valid::validation::Errors")]
+pub struct MergeConflict<T> {
+    pub other: T,
+}
+
+impl<T> MergeConflict<T> {
+    pub fn new(other: T) -> Self {
+        Self { other }
+    }
+
+    pub fn into_inner(self) -> T {
+        self.other
+    }
+}
@@ -16,6 +16,11 @@ impl<E> Message<E> {
         }
     }
 
+    /// The error struct holding the validator parameters.
+    pub fn inner(&self) -> &E {
+        &self.error
+    }
+
     pub fn error(&self) -> String {
         (self.format_fn)(&self.error)
     }
@@ -1,6 +1,6 @@
 use serde::ser::SerializeStruct;
 
-use super::{PropertyErrorsMap, VecErrors};
+use super::{MergeConflict, PropertyErrorsMap, VecErrors};
 
 #[derive(Debug, Clone, thiserror::Error)]
 pub struct ObjectErrors<E = crate::validation::Error> {
@@ -29,6 +29,43 @@ impl<E> ObjectErrors<E> {
     }
 }
 
+impl<E> ObjectErrors<E> {
+    /// Merge `other` into `self`, property by property.
+    ///
+    /// See [`Errors::merge`](super::Errors::merge) for the conflict rules.
+    pub fn merge(&mut self, other: ObjectErrors<E>) -> Result<(), MergeConflict<ObjectErrors<E>>> {
+        if !self.is_mergeable(&other) {
+            return Err(MergeConflict::new(other));
+        }
+        self.merge_unchecked(other);
+
+        Ok(())
+    }
+
+    pub(crate) fn is_mergeable(&self, other: &ObjectErrors<E>) -> bool {
+        other
+            .properties
+            .iter()
+            .all(|(property, errors)| match self.properties.get(property) {
+                Some(self_errors) => self_errors.is_mergeable(errors),
+                None => true,
+            })
+    }
+
+    pub(crate) fn merge_unchecked(&mut self, other: ObjectErrors<E>) {
+        self.errors.extend(other.errors);
+
+        for (property, errors) in other.properties {
+            match self.properties.get_mut(&property) {
+                Some(self_errors) => self_errors.merge_unchecked(errors),
+                None => {
+                    self.properties.insert(property, errors);
+                }
+            };
+        }
+    }
+}
+
 impl<E> std::fmt::Display for ObjectErrors<E>
 where
     E: std::fmt::Display + serde::Serialize,
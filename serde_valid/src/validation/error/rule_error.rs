@@ -0,0 +1,138 @@
+use std::borrow::Cow;
+
+use super::{Error, Errors, ItemVecErrorsMap, PropertyVecErrorsMap, VecErrors};
+
+/// Error of a `#[rule(...)]` function, placed on the struct or on one of its fields.
+///
+/// A rule function may return any type implementing [`IntoRuleErrors`],
+/// so `Result<(), Error>` still reports the error on the struct itself.
+///
+/// ```rust
+/// use serde_valid::validation::{Error, RuleError};
+///
+/// fn date_rule(start_date: &u32, end_date: &u32) -> Result<(), RuleError> {
+///     if start_date <= end_date {
+///         Ok(())
+///     } else {
+///         Err(RuleError::at(
+///             "end_date",
+///             Error::Custom("The end date must be after the start date.".to_owned()),
+///         ))
+///     }
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub enum RuleError {
+    /// Error of the struct itself.
+    Root(Error),
+
+    /// Error of a named field, by its Rust name or its serde name.
+    Property(Cow<'static, str>, Box<Error>),
+
+    /// Error of an unnamed field, by its index.
+    Item(usize, Box<Error>),
+
+    /// Partial errors tree, merged into the errors of the struct.
+    Errors(Errors),
+}
+
+impl RuleError {
+    pub fn at(property: impl Into<Cow<'static, str>>, error: Error) -> Self {
+        Self::Property(property.into(), Box::new(error))
+    }
+
+    pub fn at_index(index: usize, error: Error) -> Self {
+        Self::Item(index, Box::new(error))
+    }
+
+    #[doc(hidden)]
+    pub fn push_to_properties(
+        self,
+        errors: &mut VecErrors,
+        properties: &mut PropertyVecErrorsMap<Error>,
+        rename: fn(&str) -> Option<&'static str>,
+    ) {
+        let key = |property: Cow<'static, str>| match rename(&property) {
+            Some(rename) => Cow::Borrowed(rename),
+            None => property,
+        };
+
+        match self {
+            Self::Root(error) => errors.push(error),
+            Self::Item(_, error) => errors.push(*error),
+            Self::Property(property, error) => {
+                properties.entry(key(property)).or_default().push(*error)
+            }
+            Self::Errors(Errors::Object(object_errors)) => {
+                errors.extend(object_errors.errors);
+                for (property, property_errors) in object_errors.properties {
+                    properties
+                        .entry(key(property))
+                        .or_default()
+                        .extend(property_errors.into_vec_errors());
+                }
+            }
+            Self::Errors(other) => errors.extend(other.into_vec_errors()),
+        }
+    }
+
+    #[doc(hidden)]
+    pub fn push_to_items(self, errors: &mut VecErrors, items: &mut ItemVecErrorsMap<Error>) {
+        match self {
+            Self::Root(error) => errors.push(error),
+            Self::Property(_, error) => errors.push(*error),
+            Self::Item(index, error) => items.entry(index).or_default().push(*error),
+            Self::Errors(Errors::Array(array_errors)) => {
+                errors.extend(array_errors.errors);
+                for (index, item_errors) in array_errors.items {
+                    items
+                        .entry(index)
+                        .or_default()
+                        .extend(item_errors.into_vec_errors());
+                }
+            }
+            Self::Errors(other) => errors.extend(other.into_vec_errors()),
+        }
+    }
+}
+
+impl From<Error> for RuleError {
+    fn from(error: Error) -> Self {
+        Self::Root(error)
+    }
+}
+
+impl From<Errors> for RuleError {
+    fn from(errors: Errors) -> Self {
+        Self::Errors(errors)
+    }
+}
+
+/// Errors that a `#[rule(...)]` function can return.
+pub trait IntoRuleErrors {
+    fn into_rule_errors(self) -> Vec<RuleError>;
+}
+
+impl IntoRuleErrors for Error {
+    fn into_rule_errors(self) -> Vec<RuleError> {
+        vec![self.into()]
+    }
+}
+
+impl IntoRuleErrors for Errors {
+    fn into_rule_errors(self) -> Vec<RuleError> {
+        vec![self.into()]
+    }
+}
+
+impl IntoRuleErrors for RuleError {
+    fn into_rule_errors(self) -> Vec<RuleError> {
+        vec![self]
+    }
+}
+
+impl IntoRuleErrors for Vec<RuleError> {
+    fn into_rule_errors(self) -> Vec<RuleError> {
+        self
+    }
+}
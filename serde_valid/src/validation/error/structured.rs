@@ -0,0 +1,237 @@
+use serde_json::{Map, Value};
+
+use super::{ArrayErrors, ErrorCode, Errors, Message, ObjectErrors};
+
+/// An error as its code, parameters and message,
+/// for clients that localize the message on their own.
+///
+/// ```json
+/// {"code": "maximum", "params": {"maximum": 10}, "message": "The number must be `<= 10`."}
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize)]
+pub struct StructuredError {
+    pub code: &'static str,
+    pub params: Map<String, Value>,
+    pub message: String,
+}
+
+impl StructuredError {
+    pub fn new(code: &'static str, params: Map<String, Value>, message: String) -> Self {
+        Self {
+            code,
+            params,
+            message,
+        }
+    }
+}
+
+impl std::fmt::Display for StructuredError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.message)
+    }
+}
+
+impl<E> Message<E>
+where
+    E: ErrorCode + serde::Serialize,
+{
+    pub fn to_structured(&self) -> StructuredError {
+        StructuredError::new(self.inner().code(), to_params(self.inner()), self.error())
+    }
+}
+
+impl crate::validation::Error {
+    /// The code and parameters of the error, drawn from the error struct such as
+    /// [`MaximumError`](crate::MaximumError), along with the message.
+    ///
+    /// Nested `Items` and `Properties` errors are kept structured in the `params`.
+    pub fn to_structured(&self) -> StructuredError {
+        match self {
+            Self::Minimum(message) => message.to_structured(),
+            Self::Maximum(message) => message.to_structured(),
+            Self::ExclusiveMinimum(message) => message.to_structured(),
+            Self::ExclusiveMaximum(message) => message.to_structured(),
+            Self::MultipleOf(message) => message.to_structured(),
+            Self::MinLength(message) => message.to_structured(),
+            Self::MaxLength(message) => message.to_structured(),
+            Self::Pattern(message) => message.to_structured(),
+            Self::Format(message) => message.to_structured(),
+            Self::MinItems(message) => message.to_structured(),
+            Self::MaxItems(message) => message.to_structured(),
+            Self::UniqueItems(message) => message.to_structured(),
+            Self::Contains(message) => message.to_structured(),
+            Self::MinContains(message) => message.to_structured(),
+            Self::MaxContains(message) => message.to_structured(),
+            Self::MinProperties(message) => message.to_structured(),
+            Self::MaxProperties(message) => message.to_structured(),
+            Self::Enumerate(message) => message.to_structured(),
+            Self::GreaterThan(message) => message.to_structured(),
+            Self::GreaterThanOrEqual(message) => message.to_structured(),
+            Self::LessThan(message) => message.to_structured(),
+            Self::LessThanOrEqual(message) => message.to_structured(),
+            Self::Equals(message) => message.to_structured(),
+            Self::NotEquals(message) => message.to_structured(),
+            Self::AnyOf(message) => message.to_structured(),
+            Self::AllOf(message) => message.to_structured(),
+            Self::OneOf(message) => message.to_structured(),
+            Self::Not(message) => message.to_structured(),
+            Self::Type(message) => message.to_structured(),
+            Self::Required(message) => message.to_structured(),
+            Self::AdditionalProperties(message) => message.to_structured(),
+            Self::FalseSchema(message) => message.to_structured(),
+            Self::Custom(message) => StructuredError::new("custom", Map::new(), message.clone()),
+            Self::Items(errors) => StructuredError::new(
+                "items",
+                to_params(&errors.to_structured()),
+                self.to_string(),
+            ),
+            Self::Properties(errors) => StructuredError::new(
+                "properties",
+                to_params(&errors.to_structured()),
+                self.to_string(),
+            ),
+            #[cfg(feature = "fluent")]
+            Self::Fluent(message) => StructuredError::new(
+                message.id,
+                message
+                    .args
+                    .iter()
+                    .map(|(key, value)| (key.to_string(), fluent_value_to_json(value)))
+                    .collect(),
+                message.to_string(),
+            ),
+        }
+    }
+}
+
+impl Errors {
+    /// Errors of the same shape, with each error as a [`StructuredError`].
+    pub fn to_structured(&self) -> Errors<StructuredError> {
+        match self {
+            Self::Array(errors) => Errors::Array(errors.to_structured()),
+            Self::Object(errors) => Errors::Object(errors.to_structured()),
+            Self::NewType(errors) => Errors::NewType(to_structured_vec(errors)),
+        }
+    }
+}
+
+impl ArrayErrors {
+    pub fn to_structured(&self) -> ArrayErrors<StructuredError> {
+        ArrayErrors::new(
+            to_structured_vec(&self.errors),
+            self.items
+                .iter()
+                .map(|(index, errors)| (*index, errors.to_structured()))
+                .collect(),
+        )
+    }
+}
+
+impl ObjectErrors {
+    pub fn to_structured(&self) -> ObjectErrors<StructuredError> {
+        ObjectErrors::new(
+            to_structured_vec(&self.errors),
+            self.properties
+                .iter()
+                .map(|(property, errors)| (property.clone(), errors.to_structured()))
+                .collect(),
+        )
+    }
+}
+
+fn to_structured_vec(errors: &[crate::validation::Error]) -> Vec<StructuredError> {
+    errors
+        .iter()
+        .map(crate::validation::Error::to_structured)
+        .collect()
+}
+
+fn to_params<T>(params: &T) -> Map<String, Value>
+where
+    T: serde::Serialize,
+{
+    match serde_json::to_value(params) {
+        Ok(Value::Object(params)) => params,
+        _ => Map::new(),
+    }
+}
+
+#[cfg(feature = "fluent")]
+fn fluent_value_to_json(value: &fluent_0::FluentValue<'static>) -> Value {
+    match value {
+        fluent_0::FluentValue::String(value) => Value::String(value.to_string()),
+        fluent_0::FluentValue::Number(value) => serde_json::Number::from_f64(value.value)
+            .map(Value::Number)
+            .unwrap_or(Value::Null),
+        _ => Value::Null,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use indexmap::indexmap;
+    use serde_json::json;
+
+    use super::*;
+    use crate::validation::{Error, ToDefaultMessage};
+    use crate::{MaximumError, PatternError, UniqueItemsError};
+
+    #[test]
+    fn test_structured_errors_json() {
+        let errors = Errors::Object(ObjectErrors::new(
+            vec![Error::Custom("custom error".to_string())],
+            indexmap! {
+                "age".into() => Errors::NewType(vec![Error::Maximum(Message::new(
+                    MaximumError::new(10),
+                    MaximumError::to_default_message,
+                ))]),
+                "tags".into() => Errors::Array(ArrayErrors::new(
+                    vec![Error::UniqueItems(Message::new(
+                        UniqueItemsError::new(vec![2]),
+                        UniqueItemsError::to_default_message,
+                    ))],
+                    indexmap! {
+                        0 => Errors::NewType(vec![Error::Pattern(Message::new(
+                            PatternError::new(regex::Regex::new("^[a-z]+$").unwrap()),
+                            |_| "Lowercase only.".to_string(),
+                        ))]),
+                    },
+                )),
+            },
+        ));
+
+        assert_eq!(
+            serde_json::to_value(errors.to_structured()).unwrap(),
+            json!({
+                "errors": [
+                    {"code": "custom", "params": {}, "message": "custom error"}
+                ],
+                "properties": {
+                    "age": {
+                        "errors": [{
+                            "code": "maximum",
+                            "params": {"maximum": 10},
+                            "message": "The number must be `<= 10`."
+                        }]
+                    },
+                    "tags": {
+                        "errors": [{
+                            "code": "unique_items",
+                            "params": {"duplicates": [2]},
+                            "message": "The items must be unique."
+                        }],
+                        "items": {
+                            "0": {
+                                "errors": [{
+                                    "code": "pattern",
+                                    "params": {"pattern": "^[a-z]+$"},
+                                    "message": "Lowercase only."
+                                }]
+                            }
+                        }
+                    }
+                }
+            })
+        );
+    }
+}
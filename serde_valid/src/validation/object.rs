@@ -1,5 +1,7 @@
 mod max_properties;
 mod min_properties;
+mod property_entries;
 
 pub use max_properties::ValidateMaxProperties;
 pub use min_properties::ValidateMinProperties;
+pub use property_entries::PropertyEntries;
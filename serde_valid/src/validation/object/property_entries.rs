@@ -0,0 +1,138 @@
+use std::collections::BTreeMap;
+use std::collections::HashMap;
+
+/// Entries of the object properties,
+/// whose keys `property_names` and values `pattern_properties` validate.
+///
+/// See <https://json-schema.org/understanding-json-schema/reference/object.html#property-names>
+///
+/// ```rust
+/// use std::collections::BTreeMap;
+///
+/// use serde_json::json;
+/// use serde_valid::validation::PropertyEntries;
+/// use serde_valid::Validate;
+///
+/// struct MyType(BTreeMap<String, String>);
+///
+/// impl PropertyEntries for MyType {
+///     type Key = String;
+///     type Value = String;
+///
+///     fn property_entries(&self) -> Vec<(&String, &String)> {
+///         self.0.property_entries()
+///     }
+/// }
+///
+/// #[derive(Validate)]
+/// struct TestStruct {
+///     #[validate(property_names(pattern = "^[a-z_]+$"))]
+///     val: MyType,
+/// }
+///
+/// let mut map = BTreeMap::new();
+/// map.insert("key_1".to_string(), "value1".to_string());
+/// map.insert("key".to_string(), "value2".to_string());
+///
+/// let s = TestStruct { val: MyType(map) };
+///
+/// assert_eq!(
+///     s.validate().unwrap_err().to_string(),
+///     json!({
+///         "errors": [],
+///         "properties": {
+///             "val": {
+///                 "errors": [],
+///                 "properties": {
+///                     "key_1": {
+///                         "errors": ["The value must match the pattern of \"^[a-z_]+$\"."]
+///                     }
+///                 }
+///             }
+///         }
+///     })
+///     .to_string()
+/// );
+/// ```
+pub trait PropertyEntries {
+    type Key: ?Sized;
+    type Value: ?Sized;
+
+    fn property_entries(&self) -> Vec<(&Self::Key, &Self::Value)>;
+}
+
+impl<K, V> PropertyEntries for HashMap<K, V> {
+    type Key = K;
+    type Value = V;
+
+    fn property_entries(&self) -> Vec<(&K, &V)> {
+        self.iter().collect()
+    }
+}
+
+impl<K, V> PropertyEntries for BTreeMap<K, V> {
+    type Key = K;
+    type Value = V;
+
+    fn property_entries(&self) -> Vec<(&K, &V)> {
+        self.iter().collect()
+    }
+}
+
+impl PropertyEntries for serde_json::Map<String, serde_json::Value> {
+    type Key = String;
+    type Value = serde_json::Value;
+
+    fn property_entries(&self) -> Vec<(&String, &serde_json::Value)> {
+        self.iter().collect()
+    }
+}
+
+impl<T> PropertyEntries for Option<T>
+where
+    T: PropertyEntries,
+{
+    type Key = T::Key;
+    type Value = T::Value;
+
+    fn property_entries(&self) -> Vec<(&Self::Key, &Self::Value)> {
+        match self {
+            Some(value) => value.property_entries(),
+            None => vec![],
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_property_entries_btree_map_type() {
+        let mut map = BTreeMap::new();
+        map.insert("key2".to_string(), 2);
+        map.insert("key1".to_string(), 1);
+
+        assert_eq!(
+            map.property_entries(),
+            vec![(&"key1".to_string(), &1), (&"key2".to_string(), &2)]
+        );
+    }
+
+    #[test]
+    fn test_property_entries_json_map_type() {
+        let value = json!({"key1": "value1"});
+        let map = value.as_object().unwrap();
+
+        assert_eq!(
+            map.property_entries(),
+            vec![(&"key1".to_string(), &json!("value1"))]
+        );
+    }
+
+    #[test]
+    fn test_property_entries_none_type() {
+        assert!(None::<HashMap<String, i32>>.property_entries().is_empty());
+    }
+}
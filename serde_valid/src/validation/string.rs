@@ -1,6 +1,8 @@
+mod format;
 mod max_length;
 mod min_length;
 mod pattern;
+pub use format::ValidateFormat;
 pub use max_length::ValidateMaxLength;
 pub use min_length::ValidateMinLength;
 pub use pattern::ValidatePattern;
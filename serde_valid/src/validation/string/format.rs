@@ -0,0 +1,96 @@
+use crate::{traits::IsFormat, FormatError};
+
+/// Format validation of the string.
+///
+/// See <https://json-schema.org/understanding-json-schema/reference/string.html#format>
+///
+/// Supported formats are listed in [`format`](crate::format).
+///
+/// ```rust
+/// use serde_json::json;
+/// use serde_valid::{Validate, ValidateFormat};
+///
+/// struct MyType(String);
+///
+/// impl ValidateFormat for MyType {
+///     fn validate_format(&self, format: &str) -> Result<(), serde_valid::FormatError> {
+///         self.0.validate_format(format)
+///     }
+/// }
+///
+/// #[derive(Validate)]
+/// struct TestStruct {
+///     #[validate(format = "email")]
+///     val: MyType,
+/// }
+///
+/// let s = TestStruct {
+///     val: MyType(String::from("taro.example.com")),
+/// };
+///
+/// assert_eq!(
+///     s.validate().unwrap_err().to_string(),
+///     json!({
+///         "errors": [],
+///         "properties": {
+///             "val": {
+///                 "errors": ["The value must match the format of \"email\"."]
+///             }
+///         }
+///     })
+///     .to_string()
+/// );
+/// ```
+pub trait ValidateFormat {
+    fn validate_format(&self, format: &str) -> Result<(), FormatError>;
+}
+
+impl<T> ValidateFormat for T
+where
+    T: IsFormat + ?Sized,
+{
+    fn validate_format(&self, format: &str) -> Result<(), FormatError> {
+        if self.is_format(format) {
+            Ok(())
+        } else {
+            Err(FormatError::new(format))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::borrow::Cow;
+    use std::ffi::{OsStr, OsString};
+
+    #[test]
+    fn test_validate_string_format_str_type() {
+        assert!(ValidateFormat::validate_format("2020-09-10", "date").is_ok());
+    }
+
+    #[test]
+    fn test_validate_string_format_string_type() {
+        assert!(ValidateFormat::validate_format(&String::from("2020-09-10"), "date").is_ok());
+    }
+
+    #[test]
+    fn test_validate_string_format_cow_str_type() {
+        assert!(ValidateFormat::validate_format(&Cow::from("2020-09-10"), "date").is_ok());
+    }
+
+    #[test]
+    fn test_validate_string_format_os_str_type() {
+        assert!(ValidateFormat::validate_format(OsStr::new("2020-09-10"), "date").is_ok());
+    }
+
+    #[test]
+    fn test_validate_string_format_os_string_type() {
+        assert!(ValidateFormat::validate_format(&OsString::from("2020-09-10"), "date").is_ok());
+    }
+
+    #[test]
+    fn test_validate_string_format_is_false() {
+        assert!(ValidateFormat::validate_format("2020/09/10", "date").is_err());
+    }
+}
@@ -11,7 +11,7 @@ fn items_err_message() {
 
     #[derive(Validate)]
     struct TestStruct {
-        #[validate(min_items = 5)]
+        #[validate(min_items = 1)]
         #[validate(max_items = 2)]
         #[validate]
         val: Vec<TestChildStruct>,
@@ -39,10 +39,7 @@ fn items_err_message() {
             "errors": [],
             "properties": {
                 "val": {
-                    "errors": [
-                        "The length of the items must be `>= 5`.",
-                        "The length of the items must be `<= 2`.",
-                    ],
+                    "errors": ["The length of the items must be `<= 2`."],
                     "items": {
                         "0": {
                             "errors": ["Rule error."],
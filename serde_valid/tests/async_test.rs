@@ -0,0 +1,257 @@
+use serde_json::json;
+use serde_valid::{AsyncValidate, Validate};
+
+/// In-memory stand-in for a user store.
+struct UserRepository {
+    names: Vec<&'static str>,
+}
+
+impl UserRepository {
+    async fn exists(&self, name: &str) -> bool {
+        tokio::task::yield_now().await;
+        self.names.contains(&name)
+    }
+}
+
+fn repository() -> UserRepository {
+    UserRepository {
+        names: vec!["taro", "hanako"],
+    }
+}
+
+async fn unique_name(name: &str) -> Result<(), serde_valid::validation::Error> {
+    if repository().exists(name).await {
+        Err(serde_valid::validation::Error::Custom(format!(
+            "`{name}` is already taken."
+        )))
+    } else {
+        Ok(())
+    }
+}
+
+async fn different_names(name: &str, nickname: &str) -> Result<(), serde_valid::validation::Error> {
+    tokio::task::yield_now().await;
+    if name == nickname {
+        Err(serde_valid::validation::Error::Custom(
+            "nickname must differ from name.".to_owned(),
+        ))
+    } else {
+        Ok(())
+    }
+}
+
+#[tokio::test]
+async fn async_validate_is_ok() {
+    #[derive(Validate, AsyncValidate)]
+    struct TestStruct {
+        #[validate(max_length = 10)]
+        #[validate(custom_async(unique_name))]
+        name: String,
+    }
+
+    let s = TestStruct {
+        name: "jiro".to_owned(),
+    };
+
+    assert!(s.validate().is_ok());
+    assert!(s.validate_async().await.is_ok());
+}
+
+#[tokio::test]
+async fn async_validate_merges_sync_and_async_errors() {
+    #[derive(Validate, AsyncValidate)]
+    #[rule_async(different_names(name, nickname))]
+    struct TestStruct {
+        #[validate(max_length = 3)]
+        #[validate(custom_async(unique_name))]
+        name: String,
+        #[validate(min_length = 5)]
+        nickname: String,
+    }
+
+    let s = TestStruct {
+        name: "taro".to_owned(),
+        nickname: "taro".to_owned(),
+    };
+
+    assert_eq!(
+        s.validate().unwrap_err().to_string(),
+        json!({
+            "errors": [],
+            "properties": {
+                "name": {"errors": ["The length of the value must be `<= 3`."]},
+                "nickname": {"errors": ["The length of the value must be `>= 5`."]}
+            }
+        })
+        .to_string()
+    );
+    assert_eq!(
+        s.validate_async().await.unwrap_err().to_string(),
+        json!({
+            "errors": ["nickname must differ from name."],
+            "properties": {
+                "name": {
+                    "errors": [
+                        "The length of the value must be `<= 3`.",
+                        "`taro` is already taken."
+                    ]
+                },
+                "nickname": {"errors": ["The length of the value must be `>= 5`."]}
+            }
+        })
+        .to_string()
+    );
+}
+
+#[tokio::test]
+async fn async_validate_nested_struct() {
+    #[derive(Validate, AsyncValidate)]
+    struct Parent {
+        #[validate]
+        children: Vec<Child>,
+    }
+
+    #[derive(Validate, AsyncValidate)]
+    struct Child {
+        #[validate(custom_async(unique_name))]
+        name: String,
+    }
+
+    let s = Parent {
+        children: vec![
+            Child {
+                name: "jiro".to_owned(),
+            },
+            Child {
+                name: "hanako".to_owned(),
+            },
+        ],
+    };
+
+    assert!(s.validate().is_ok());
+    assert_eq!(
+        s.validate_async().await.unwrap_err().to_string(),
+        json!({
+            "errors": [],
+            "properties": {
+                "children": {
+                    "errors": [],
+                    "items": {
+                        "1": {
+                            "errors": [],
+                            "properties": {
+                                "name": {"errors": ["`hanako` is already taken."]}
+                            }
+                        }
+                    }
+                }
+            }
+        })
+        .to_string()
+    );
+}
+
+#[tokio::test]
+async fn async_validate_unnamed_struct_and_enum() {
+    #[derive(Validate, AsyncValidate)]
+    struct TestNewType(#[validate(custom_async(unique_name))] String);
+
+    #[derive(Validate, AsyncValidate)]
+    enum TestEnum {
+        Named {
+            #[validate(custom_async(unique_name))]
+            name: String,
+        },
+        Unnamed(
+            #[validate(max_length = 10)] String,
+            #[validate(custom_async(unique_name))] String,
+        ),
+        Unit,
+    }
+
+    assert!(TestNewType("jiro".to_owned())
+        .validate_async()
+        .await
+        .is_ok());
+    assert_eq!(
+        TestNewType("taro".to_owned())
+            .validate_async()
+            .await
+            .unwrap_err()
+            .to_string(),
+        json!({"errors": ["`taro` is already taken."]}).to_string()
+    );
+    assert!(TestEnum::Named {
+        name: "hanako".to_owned()
+    }
+    .validate_async()
+    .await
+    .is_err());
+    assert!(TestEnum::Unnamed("jiro".to_owned(), "taro".to_owned())
+        .validate_async()
+        .await
+        .is_err());
+    assert!(TestEnum::Unit.validate_async().await.is_ok());
+}
+
+#[tokio::test]
+async fn async_validate_keeps_conflicting_nested_errors() {
+    async fn registered_names(names: &[String]) -> Result<(), serde_valid::validation::Error> {
+        tokio::task::yield_now().await;
+        Err(serde_valid::validation::Error::Properties(
+            serde_valid::validation::ObjectErrors::new(
+                vec![],
+                names
+                    .iter()
+                    .map(|name| {
+                        (
+                            name.clone().into(),
+                            serde_valid::validation::Errors::NewType(vec![
+                                serde_valid::validation::Error::Custom(format!(
+                                    "`{name}` is not registered."
+                                )),
+                            ]),
+                        )
+                    })
+                    .collect(),
+            ),
+        ))
+    }
+
+    #[derive(Validate, AsyncValidate)]
+    struct TestStruct {
+        #[validate(max_length = 1)]
+        #[validate(custom_async(registered_names))]
+        names: Vec<String>,
+    }
+
+    let s = TestStruct {
+        names: vec!["ab".to_owned()],
+    };
+
+    assert_eq!(
+        s.validate_async().await.unwrap_err().to_string(),
+        json!({
+            "errors": [{
+                "errors": [],
+                "properties": {
+                    "names": {
+                        "errors": [],
+                        "properties": {
+                            "ab": {"errors": ["`ab` is not registered."]}
+                        }
+                    }
+                }
+            }],
+            "properties": {
+                "names": {
+                    "errors": [],
+                    "items": {
+                        "0": {"errors": ["The length of the value must be `<= 1`."]}
+                    }
+                }
+            }
+        })
+        .to_string()
+    );
+}
@@ -0,0 +1,223 @@
+use std::collections::{BTreeMap, HashMap, HashSet, VecDeque};
+
+use indexmap::IndexMap;
+use serde_json::json;
+use serde_valid::Validate;
+
+#[test]
+fn map_values_is_ok() {
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(max_length = 5)]
+        hash_map: HashMap<String, String>,
+        #[validate(maximum = 10)]
+        btree_map: BTreeMap<String, i32>,
+        #[validate(enumerate("a", "b"))]
+        index_map: IndexMap<String, String>,
+    }
+
+    let s = TestStruct {
+        hash_map: HashMap::from([("key".to_owned(), "value".to_owned())]),
+        btree_map: BTreeMap::from([("key".to_owned(), 10)]),
+        index_map: IndexMap::from([("key".to_owned(), "a".to_owned())]),
+    };
+    assert!(s.validate().is_ok());
+}
+
+#[test]
+fn map_values_is_err() {
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(max_length = 5)]
+        #[validate(max_properties = 2)]
+        labels: BTreeMap<String, String>,
+        #[validate(minimum = 0)]
+        counts: IndexMap<u32, Option<i32>>,
+    }
+
+    let s = TestStruct {
+        labels: BTreeMap::from([
+            ("env".to_owned(), "production".to_owned()),
+            ("team".to_owned(), "core".to_owned()),
+            ("tier".to_owned(), "frontend".to_owned()),
+        ]),
+        counts: IndexMap::from([(1, Some(1)), (2, Some(-1)), (3, None)]),
+    };
+
+    assert_eq!(
+        serde_json::to_value(s.validate().unwrap_err()).unwrap(),
+        json!({
+            "errors": [],
+            "properties": {
+                "labels": {
+                    "errors": ["The size of the properties must be `<= 2`."],
+                    "properties": {
+                        "env": {"errors": ["The length of the value must be `<= 5`."]},
+                        "tier": {"errors": ["The length of the value must be `<= 5`."]}
+                    }
+                },
+                "counts": {
+                    "errors": [],
+                    "properties": {
+                        "2": {"errors": ["The number must be `>= 0`."]}
+                    }
+                }
+            }
+        })
+    );
+}
+
+#[test]
+fn nested_map_values_is_err() {
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(pattern = r"^\d+$")]
+        val: Vec<BTreeMap<String, String>>,
+    }
+
+    let s = TestStruct {
+        val: vec![
+            BTreeMap::from([("a".to_owned(), "1".to_owned())]),
+            BTreeMap::from([("b".to_owned(), "x".to_owned())]),
+        ],
+    };
+
+    assert_eq!(
+        serde_json::to_value(s.validate().unwrap_err()).unwrap(),
+        json!({
+            "errors": [],
+            "properties": {
+                "val": {
+                    "errors": [],
+                    "items": {
+                        "1": {
+                            "errors": [{
+                                "errors": [],
+                                "properties": {
+                                    "b": {"errors": ["The value must match the pattern of \"^\\d+$\"."]}
+                                }
+                            }]
+                        }
+                    }
+                }
+            }
+        })
+    );
+}
+
+#[test]
+fn sequence_items_is_err() {
+    #[derive(Validate)]
+    struct TestStruct<'a> {
+        #[validate(max_length = 3)]
+        deque: VecDeque<String>,
+        #[validate(maximum = 10)]
+        slice: &'a [i32],
+        #[validate(min_length = 2)]
+        set: HashSet<String>,
+    }
+
+    let s = TestStruct {
+        deque: VecDeque::from(["abc".to_owned(), "abcd".to_owned()]),
+        slice: &[1, 20, 3],
+        set: HashSet::from(["a".to_owned(), "b".to_owned(), "cd".to_owned()]),
+    };
+
+    assert_eq!(
+        serde_json::to_value(s.validate().unwrap_err()).unwrap(),
+        json!({
+            "errors": [],
+            "properties": {
+                "deque": {
+                    "errors": [],
+                    "items": {
+                        "1": {"errors": ["The length of the value must be `<= 3`."]}
+                    }
+                },
+                "slice": {
+                    "errors": [],
+                    "items": {
+                        "1": {"errors": ["The number must be `<= 10`."]}
+                    }
+                },
+                "set": {"errors": ["The length of the value must be `>= 2`."]}
+            }
+        })
+    );
+}
+
+#[test]
+fn set_type_is_ok() {
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(exclusive_maximum = 10)]
+        val: HashSet<i32>,
+    }
+
+    assert!(TestStruct {
+        val: HashSet::from([1, 2, 9])
+    }
+    .validate()
+    .is_ok());
+}
+
+#[test]
+fn set_item_errors_are_merged() {
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(max_length = 1)]
+        val: HashSet<Vec<String>>,
+    }
+
+    let s = TestStruct {
+        val: HashSet::from([
+            vec!["ab".to_owned(), "a".to_owned()],
+            vec!["a".to_owned(), "ab".to_owned()],
+            vec!["a".to_owned()],
+        ]),
+    };
+
+    assert_eq!(
+        serde_json::to_value(s.validate().unwrap_err()).unwrap(),
+        json!({
+            "errors": [],
+            "properties": {
+                "val": {
+                    "errors": [],
+                    "items": {
+                        "0": {"errors": ["The length of the value must be `<= 1`."]},
+                        "1": {"errors": ["The length of the value must be `<= 1`."]}
+                    }
+                }
+            }
+        })
+    );
+}
+
+#[cfg(feature = "flatten")]
+#[test]
+fn map_values_in_flat_path() {
+    use serde_valid::flatten::IntoFlat;
+
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(max_length = 5)]
+        labels: HashMap<String, String>,
+    }
+
+    let s = TestStruct {
+        labels: HashMap::from([("env".to_owned(), "production".to_owned())]),
+    };
+
+    assert_eq!(
+        serde_json::to_value(s.validate().unwrap_err().into_flat()).unwrap(),
+        json!({
+            "errors": [{
+                "error": "The length of the value must be `<= 5`.",
+                "code": "max_length",
+                "params": {"max_length": 5},
+                "instance_location": "/labels/env"
+            }]
+        })
+    );
+}
@@ -0,0 +1,154 @@
+use serde::Deserialize;
+use serde_json::json;
+use serde_valid::Validate;
+
+#[test]
+fn compare_named_struct_is_ok() {
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(greater_than = start)]
+        end: i32,
+        start: i32,
+        #[validate(equals = password)]
+        confirm_password: String,
+        password: String,
+    }
+
+    let s = TestStruct {
+        end: 2,
+        start: 1,
+        confirm_password: "secret".to_owned(),
+        password: "secret".to_owned(),
+    };
+
+    assert!(s.validate().is_ok());
+}
+
+#[test]
+fn compare_named_struct_is_err() {
+    #[derive(Validate)]
+    struct TestStruct {
+        start: i32,
+        #[validate(greater_than = start)]
+        #[validate(less_than_or_equal = limit)]
+        end: i32,
+        limit: i32,
+        #[validate(greater_than_or_equal = start)]
+        #[validate(less_than = start)]
+        middle: i32,
+        password: String,
+        #[validate(equals = password)]
+        confirm_password: String,
+        #[validate(not_equals = password)]
+        hint: String,
+    }
+
+    let s = TestStruct {
+        start: 5,
+        end: 5,
+        limit: 4,
+        middle: 4,
+        password: "secret".to_owned(),
+        confirm_password: "secrets".to_owned(),
+        hint: "secret".to_owned(),
+    };
+
+    assert_eq!(
+        serde_json::to_value(s.validate().unwrap_err()).unwrap(),
+        json!({
+            "errors": [],
+            "properties": {
+                "end": {
+                    "errors": [
+                        "The value must be `> start`.",
+                        "The value must be `<= limit`."
+                    ]
+                },
+                "middle": {"errors": ["The value must be `>= start`."]},
+                "confirm_password": {"errors": ["The value must be `== password`."]},
+                "hint": {"errors": ["The value must be `!= password`."]}
+            }
+        })
+    );
+}
+
+#[test]
+fn compare_serde_rename_and_custom_message() {
+    #[derive(Deserialize, Validate)]
+    #[serde(rename_all = "camelCase")]
+    struct TestStruct {
+        start_date: u32,
+        #[validate(greater_than = start_date, message = "must be after {greater_than}.")]
+        end_date: u32,
+    }
+
+    let s = TestStruct {
+        start_date: 20,
+        end_date: 10,
+    };
+
+    assert_eq!(
+        s.validate().unwrap_err().to_string(),
+        json!({
+            "errors": [],
+            "properties": {
+                "endDate": {"errors": ["must be after startDate."]}
+            }
+        })
+        .to_string()
+    );
+}
+
+#[test]
+fn compare_enum_variant_is_err() {
+    #[derive(Validate)]
+    enum TestEnum {
+        Range {
+            #[validate(less_than = end)]
+            start: i32,
+            end: i32,
+        },
+    }
+
+    let s = TestEnum::Range { start: 3, end: 1 };
+
+    assert_eq!(
+        s.validate().unwrap_err().to_string(),
+        json!({
+            "errors": [],
+            "properties": {
+                "Range": {
+                    "errors": [],
+                    "properties": {
+                        "start": {"errors": ["The value must be `< end`."]}
+                    }
+                }
+            }
+        })
+        .to_string()
+    );
+}
+
+#[test]
+fn compare_with_groups() {
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(greater_than = start, groups(update))]
+        end: i32,
+        start: i32,
+    }
+
+    let s = TestStruct { end: 1, start: 1 };
+
+    assert!(s.validate().is_ok());
+    assert_eq!(
+        s.validate_group("update").unwrap_err().to_string(),
+        json!({
+            "errors": [],
+            "properties": {
+                "end": {"errors": ["The value must be `> start`."]}
+            }
+        })
+        .to_string()
+    );
+}
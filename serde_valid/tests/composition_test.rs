@@ -0,0 +1,207 @@
+use serde::Deserialize;
+use serde_json::json;
+use serde_valid::{Validate, ValidateSchema};
+
+#[test]
+fn any_of_is_ok() {
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(any_of(pattern = r"^\d+$", enumerate("auto", "none")))]
+        width: String,
+    }
+
+    for width in ["120", "auto", "none"] {
+        let s = TestStruct {
+            width: width.to_owned(),
+        };
+        assert!(s.validate().is_ok());
+    }
+}
+
+#[test]
+fn any_of_is_err() {
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(any_of(pattern = r"^\d+$", enumerate("auto", "none")))]
+        width: String,
+    }
+
+    let s = TestStruct {
+        width: "wide".to_owned(),
+    };
+
+    assert_eq!(
+        serde_json::to_value(s.validate().unwrap_err()).unwrap(),
+        json!({
+            "errors": [],
+            "properties": {
+                "width": {
+                    "errors": [
+                        "The value must match any of the validations: The value must match the pattern of \"^\\d+$\". / The value must be in [auto, none]."
+                    ]
+                }
+            }
+        })
+    );
+}
+
+#[test]
+fn all_of_with_message_is_err() {
+    #[derive(Validate)]
+    struct TestStruct(
+        #[validate(all_of(min_length = 2, max_length = 4), message = "invalid: {all_of}")] String,
+    );
+
+    assert!(TestStruct("abc".to_owned()).validate().is_ok());
+    assert_eq!(
+        TestStruct("abcde".to_owned())
+            .validate()
+            .unwrap_err()
+            .to_string(),
+        json!({
+            "errors": ["invalid: The length of the value must be `<= 4`."]
+        })
+        .to_string()
+    );
+}
+
+#[test]
+fn one_of_is_err() {
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(one_of(maximum = 10, minimum = 5))]
+        val: i32,
+    }
+
+    assert!(TestStruct { val: 3 }.validate().is_ok());
+    assert!(TestStruct { val: 12 }.validate().is_ok());
+    assert_eq!(
+        TestStruct { val: 7 }.validate().unwrap_err().to_string(),
+        json!({
+            "errors": [],
+            "properties": {
+                "val": {
+                    "errors": ["The value must match exactly one of the validations, but 2 matched."]
+                }
+            }
+        })
+        .to_string()
+    );
+}
+
+#[test]
+fn not_is_err() {
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(not(enumerate("admin", "root")))]
+        name: String,
+        #[validate(not(any_of(maximum = 0, minimum = 100)))]
+        val: i32,
+    }
+
+    assert!(TestStruct {
+        name: "taro".to_owned(),
+        val: 50,
+    }
+    .validate()
+    .is_ok());
+    assert_eq!(
+        TestStruct {
+            name: "root".to_owned(),
+            val: 100,
+        }
+        .validate()
+        .unwrap_err()
+        .to_string(),
+        json!({
+            "errors": [],
+            "properties": {
+                "name": {"errors": ["The value must not match the validation."]},
+                "val": {"errors": ["The value must not match the validation."]}
+            }
+        })
+        .to_string()
+    );
+}
+
+#[test]
+fn any_of_to_structured() {
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(any_of(maximum = 0, minimum = 100))]
+        val: i32,
+    }
+
+    let errors = TestStruct { val: 50 }.validate().unwrap_err();
+
+    assert_eq!(
+        serde_json::to_value(errors.to_structured()).unwrap(),
+        json!({
+            "errors": [],
+            "properties": {
+                "val": {
+                    "errors": [{
+                        "code": "any_of",
+                        "params": {
+                            "any_of": [
+                                [{
+                                    "code": "maximum",
+                                    "params": {"maximum": 0},
+                                    "message": "The number must be `<= 0`."
+                                }],
+                                [{
+                                    "code": "minimum",
+                                    "params": {"minimum": 100},
+                                    "message": "The number must be `>= 100`."
+                                }]
+                            ]
+                        },
+                        "message": "The value must match any of the validations: The number must be `<= 0`. / The number must be `>= 100`."
+                    }]
+                }
+            }
+        })
+    );
+}
+
+#[test]
+fn composition_schema() {
+    #[derive(Deserialize, Validate, ValidateSchema)]
+    struct TestStruct {
+        #[validate(any_of(pattern = r"^\d+$", enumerate("auto", "none")))]
+        width: String,
+        #[validate(not(enumerate("admin")))]
+        name: String,
+    }
+
+    assert_eq!(
+        TestStruct::json_schema(),
+        json!({
+            "type": "object",
+            "properties": {
+                "width": {
+                    "anyOf": [
+                        {"pattern": "^\\d+$"},
+                        {"enum": ["auto", "none"]}
+                    ]
+                },
+                "name": {
+                    "not": {"enum": ["admin"]}
+                }
+            }
+        })
+    );
+}
+
+#[test]
+fn composition_branch_limits_are_not_compared() {
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(any_of(minimum = 10, maximum = 5))]
+        #[validate(maximum = 100)]
+        val: i32,
+    }
+
+    assert!(TestStruct { val: 3 }.validate().is_ok());
+    assert!(TestStruct { val: 7 }.validate().is_err());
+}
@@ -0,0 +1,172 @@
+use serde_json::json;
+use serde_valid::Validate;
+
+#[derive(Validate)]
+struct Company {
+    country: String,
+    #[validate(pattern = r"^DE\d{9}$", when = is_german)]
+    #[validate(max_length = 14)]
+    vat_id: String,
+}
+
+fn is_german(company: &Company) -> bool {
+    company.country == "DE"
+}
+
+#[test]
+fn when_is_ok() {
+    let s = Company {
+        country: "FR".to_owned(),
+        vat_id: "FR12345678901".to_owned(),
+    };
+
+    assert!(s.validate().is_ok());
+}
+
+#[test]
+fn when_is_err() {
+    let s = Company {
+        country: "DE".to_owned(),
+        vat_id: "FR12345678901".to_owned(),
+    };
+
+    assert_eq!(
+        s.validate().unwrap_err().to_string(),
+        json!({
+            "errors": [],
+            "properties": {
+                "vat_id": {
+                    "errors": ["The value must match the pattern of \"^DE\\d{9}$\"."]
+                }
+            }
+        })
+        .to_string()
+    );
+}
+
+#[test]
+fn when_with_message_is_err() {
+    fn is_positive_mode(s: &TestStruct) -> bool {
+        s.0
+    }
+
+    #[derive(Validate)]
+    struct TestStruct(
+        bool,
+        #[validate(minimum = 0, message = "must be positive.", when = is_positive_mode)] i32,
+    );
+
+    assert!(TestStruct(false, -1).validate().is_ok());
+    assert_eq!(
+        TestStruct(true, -1).validate().unwrap_err().to_string(),
+        json!({
+            "errors": [],
+            "items": {
+                "1": {"errors": ["must be positive."]}
+            }
+        })
+        .to_string()
+    );
+}
+
+#[test]
+fn skip_if_is_ok() {
+    fn is_draft(s: &TestStruct) -> bool {
+        s.draft
+    }
+
+    #[derive(Validate)]
+    struct TestStruct {
+        draft: bool,
+        #[validate(skip_if = is_draft)]
+        #[validate(min_length = 1)]
+        #[validate(max_length = 4)]
+        title: String,
+    }
+
+    let s = TestStruct {
+        draft: true,
+        title: "".to_owned(),
+    };
+    assert!(s.validate().is_ok());
+
+    let s = TestStruct {
+        draft: false,
+        title: "".to_owned(),
+    };
+    assert_eq!(
+        s.validate().unwrap_err().to_string(),
+        json!({
+            "errors": [],
+            "properties": {
+                "title": {"errors": ["The length of the value must be `>= 1`."]}
+            }
+        })
+        .to_string()
+    );
+}
+
+#[test]
+fn enum_when_is_err() {
+    fn is_strict(s: &TestEnum) -> bool {
+        matches!(s, TestEnum::Named { strict: true, .. })
+    }
+
+    #[derive(Validate)]
+    enum TestEnum {
+        Named {
+            strict: bool,
+            #[validate(maximum = 10, when = is_strict)]
+            val: i32,
+        },
+    }
+
+    assert!(TestEnum::Named {
+        strict: false,
+        val: 12
+    }
+    .validate()
+    .is_ok());
+    assert!(TestEnum::Named {
+        strict: true,
+        val: 12
+    }
+    .validate()
+    .is_err());
+}
+
+#[test]
+fn conditional_limits_are_not_compared() {
+    fn is_small(s: &TestStruct) -> bool {
+        s.small
+    }
+
+    fn is_large(s: &TestStruct) -> bool {
+        !s.small
+    }
+
+    fn is_draft(s: &TestStruct) -> bool {
+        s.draft
+    }
+
+    #[derive(Validate)]
+    struct TestStruct {
+        small: bool,
+        draft: bool,
+        #[validate(minimum = 10, when = is_large)]
+        #[validate(maximum = 5, when = is_small)]
+        size: i32,
+        #[validate(skip_if = is_draft)]
+        #[validate(min_length = 3)]
+        #[validate(max_length = 1)]
+        placeholder: String,
+    }
+
+    let s = TestStruct {
+        small: true,
+        draft: true,
+        size: 3,
+        placeholder: "".to_owned(),
+    };
+    assert!(s.validate().is_ok());
+}
@@ -0,0 +1,228 @@
+use std::borrow::Cow;
+use std::collections::{BTreeMap, HashMap, HashSet, VecDeque};
+use std::rc::Rc;
+use std::sync::Arc;
+
+use serde_json::json;
+use serde_valid::{AsyncValidate, Validate, ValidateSchema};
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Validate, AsyncValidate, ValidateSchema)]
+struct Item {
+    #[validate(maximum = 10)]
+    val: i32,
+}
+
+#[test]
+fn map_errors_are_keyed_by_map_key() {
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate]
+        settings: BTreeMap<String, Item>,
+    }
+
+    let s = TestStruct {
+        settings: BTreeMap::from([
+            ("retry".to_owned(), Item { val: 3 }),
+            ("timeout".to_owned(), Item { val: 30 }),
+        ]),
+    };
+
+    assert_eq!(
+        s.validate().unwrap_err().to_string(),
+        json!({
+            "errors": [],
+            "properties": {
+                "settings": {
+                    "errors": [],
+                    "properties": {
+                        "timeout": {
+                            "errors": [],
+                            "properties": {
+                                "val": {"errors": ["The number must be `<= 10`."]}
+                            }
+                        }
+                    }
+                }
+            }
+        })
+        .to_string()
+    );
+}
+
+#[test]
+fn hash_map_and_index_map_is_ok() {
+    let hash_map = HashMap::from([(1, Item { val: 1 }), (2, Item { val: 2 })]);
+    let index_map = indexmap::indexmap! {"a" => Item { val: 1 }};
+
+    assert!(hash_map.validate().is_ok());
+    assert!(index_map.validate().is_ok());
+    assert!(HashMap::from([(1, Item { val: 11 })]).validate().is_err());
+}
+
+#[test]
+fn sequence_errors_are_keyed_by_position() {
+    let deque = VecDeque::from([Item { val: 1 }, Item { val: 11 }]);
+
+    assert_eq!(
+        deque.validate().unwrap_err().to_string(),
+        json!({
+            "errors": [],
+            "items": {
+                "1": {
+                    "errors": [],
+                    "properties": {
+                        "val": {"errors": ["The number must be `<= 10`."]}
+                    }
+                }
+            }
+        })
+        .to_string()
+    );
+}
+
+#[test]
+fn set_errors_have_no_position() {
+    let set = HashSet::from([Item { val: 11 }]);
+
+    assert_eq!(
+        serde_json::to_value(set.validate().unwrap_err()).unwrap(),
+        json!({
+            "errors": [{
+                "errors": [],
+                "properties": {
+                    "val": {"errors": ["The number must be `<= 10`."]}
+                }
+            }]
+        })
+    );
+}
+
+#[test]
+fn pointer_delegates_to_inner_value() {
+    let item = Item { val: 11 };
+
+    assert!(Box::new(item.clone()).validate().is_err());
+    assert!(Rc::new(item.clone()).validate().is_err());
+    assert!(Arc::new(item.clone()).validate().is_err());
+    assert!(Cow::Borrowed(&item).validate().is_err());
+    assert!(Cow::<Item>::Owned(Item { val: 1 }).validate().is_ok());
+}
+
+#[test]
+fn tuple_errors_are_keyed_by_position() {
+    let tuple = (Item { val: 1 }, Some(Item { val: 11 }));
+
+    assert_eq!(
+        tuple.validate().unwrap_err().to_string(),
+        json!({
+            "errors": [],
+            "items": {
+                "1": {
+                    "errors": [],
+                    "properties": {
+                        "val": {"errors": ["The number must be `<= 10`."]}
+                    }
+                }
+            }
+        })
+        .to_string()
+    );
+}
+
+#[derive(Validate, AsyncValidate, ValidateSchema)]
+struct Tree {
+    #[validate(max_length = 4)]
+    name: String,
+    #[validate]
+    child: Option<Box<Tree>>,
+}
+
+fn tree() -> Tree {
+    Tree {
+        name: "root".to_owned(),
+        child: Some(Box::new(Tree {
+            name: "child".to_owned(),
+            child: None,
+        })),
+    }
+}
+
+#[test]
+fn recursive_box_is_validated() {
+    assert_eq!(
+        tree().validate().unwrap_err().to_string(),
+        json!({
+            "errors": [],
+            "properties": {
+                "child": {
+                    "errors": [],
+                    "properties": {
+                        "name": {"errors": ["The length of the value must be `<= 4`."]}
+                    }
+                }
+            }
+        })
+        .to_string()
+    );
+}
+
+#[tokio::test]
+async fn recursive_box_is_validated_async() {
+    assert!(tree().validate_async().await.is_err());
+}
+
+#[test]
+fn recursive_schema_terminates() {
+    assert_eq!(
+        Tree::json_schema(),
+        json!({
+            "type": "object",
+            "properties": {
+                "name": {"maxLength": 4},
+                "child": {"anyOf": [{}, {"type": "null"}]}
+            }
+        })
+    );
+}
+
+#[test]
+fn map_schema() {
+    assert_eq!(
+        HashMap::<String, Item>::json_schema(),
+        json!({
+            "type": "object",
+            "additionalProperties": {
+                "type": "object",
+                "properties": {"val": {"maximum": 10}}
+            }
+        })
+    );
+}
+
+#[cfg(feature = "flatten")]
+#[test]
+fn map_key_in_flat_path() {
+    use serde_valid::flatten::IntoFlat;
+
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate]
+        settings: HashMap<String, Item>,
+    }
+
+    let s = TestStruct {
+        settings: HashMap::from([("timeout".to_owned(), Item { val: 30 })]),
+    };
+
+    assert_eq!(
+        serde_json::to_value(s.validate().unwrap_err().into_flat()).unwrap(),
+        json!({
+            "errors": [{
+                "error": "The number must be `<= 10`.",
+                "code": "maximum",
+                "params": {"maximum": 10},
+                "instance_location": "/settings/timeout/val"
+            }]
+        })
+    );
+}
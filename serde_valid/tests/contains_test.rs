@@ -0,0 +1,208 @@
+use serde::Deserialize;
+use serde_json::json;
+use serde_valid::{Validate, ValidateSchema};
+
+struct Member {
+    admin: bool,
+    primary: bool,
+}
+
+fn is_admin(member: &Member) -> bool {
+    member.admin
+}
+
+fn is_primary(member: &Member) -> bool {
+    member.primary
+}
+
+fn member(admin: bool, primary: bool) -> Member {
+    Member { admin, primary }
+}
+
+#[test]
+fn contains_custom_is_ok() {
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(contains(custom(is_admin)))]
+        members: Vec<Member>,
+    }
+
+    let s = TestStruct {
+        members: vec![member(false, false), member(true, false)],
+    };
+    assert!(s.validate().is_ok());
+}
+
+#[test]
+fn contains_custom_is_err() {
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(contains(custom(is_admin)))]
+        members: Vec<Member>,
+    }
+
+    let s = TestStruct {
+        members: vec![member(false, false)],
+    };
+
+    assert_eq!(
+        s.validate().unwrap_err().to_string(),
+        json!({
+            "errors": [],
+            "properties": {
+                "members": {
+                    "errors": ["The items must contain at least one matching item."]
+                }
+            }
+        })
+        .to_string()
+    );
+}
+
+#[test]
+fn contains_literal_is_err() {
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(contains = "admin", message = "admin role is required.")]
+        roles: Vec<String>,
+        #[validate(contains(3))]
+        numbers: [i32; 3],
+        #[validate(contains = "admin")]
+        optional_roles: Option<Vec<&'static str>>,
+    }
+
+    let s = TestStruct {
+        roles: vec!["admin".to_owned()],
+        numbers: [1, 2, 3],
+        optional_roles: None,
+    };
+    assert!(s.validate().is_ok());
+
+    let s = TestStruct {
+        roles: vec!["user".to_owned()],
+        numbers: [1, 2, 4],
+        optional_roles: Some(vec!["user"]),
+    };
+    assert_eq!(
+        serde_json::to_value(s.validate().unwrap_err()).unwrap(),
+        json!({
+            "errors": [],
+            "properties": {
+                "roles": {"errors": ["admin role is required."]},
+                "numbers": {"errors": ["The items must contain at least one matching item."]},
+                "optional_roles": {"errors": ["The items must contain at least one matching item."]}
+            }
+        })
+    );
+}
+
+#[test]
+fn min_max_contains_is_err() {
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(min_contains(1, custom(is_primary)))]
+        #[validate(max_contains(1, custom(is_primary)))]
+        addresses: Vec<Member>,
+    }
+
+    let s = TestStruct {
+        addresses: vec![member(false, true), member(false, false)],
+    };
+    assert!(s.validate().is_ok());
+
+    let s = TestStruct {
+        addresses: vec![member(false, false)],
+    };
+    assert_eq!(
+        s.validate().unwrap_err().to_string(),
+        json!({
+            "errors": [],
+            "properties": {
+                "addresses": {"errors": ["The number of matching items must be `>= 1`."]}
+            }
+        })
+        .to_string()
+    );
+
+    let s = TestStruct {
+        addresses: vec![member(false, true), member(false, true)],
+    };
+    assert_eq!(
+        s.validate().unwrap_err().to_string(),
+        json!({
+            "errors": [],
+            "properties": {
+                "addresses": {"errors": ["The number of matching items must be `<= 1`."]}
+            }
+        })
+        .to_string()
+    );
+}
+
+#[test]
+fn min_contains_message_template() {
+    #[derive(Validate)]
+    struct TestStruct(
+        #[validate(min_contains(2, "x"), message = "need {min_contains} x.")] Vec<&'static str>,
+    );
+
+    assert!(TestStruct(vec!["x", "y", "x"]).validate().is_ok());
+    assert_eq!(
+        TestStruct(vec!["x", "y"])
+            .validate()
+            .unwrap_err()
+            .to_string(),
+        json!({"errors": ["need 2 x."]}).to_string()
+    );
+}
+
+#[test]
+fn contains_to_structured() {
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(max_contains(0, "root"))]
+        names: Vec<String>,
+    }
+
+    let s = TestStruct {
+        names: vec!["root".to_owned()],
+    };
+
+    assert_eq!(
+        serde_json::to_value(s.validate().unwrap_err().to_structured()).unwrap(),
+        json!({
+            "errors": [],
+            "properties": {
+                "names": {
+                    "errors": [{
+                        "code": "max_contains",
+                        "params": {"max_contains": 0},
+                        "message": "The number of matching items must be `<= 0`."
+                    }]
+                }
+            }
+        })
+    );
+}
+
+#[test]
+fn contains_schema() {
+    #[derive(Deserialize, Validate, ValidateSchema)]
+    struct TestStruct {
+        #[validate(contains = "admin")]
+        roles: Vec<String>,
+        #[validate(min_contains(2, 1))]
+        flags: Vec<i32>,
+    }
+
+    assert_eq!(
+        TestStruct::json_schema(),
+        json!({
+            "type": "object",
+            "properties": {
+                "roles": {"contains": {"const": "admin"}},
+                "flags": {"contains": {"const": 1}, "minContains": 2}
+            }
+        })
+    );
+}
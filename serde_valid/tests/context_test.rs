@@ -0,0 +1,260 @@
+use serde_json::json;
+use serde_valid::{Validate, ValidateWith};
+
+struct AppConfig {
+    max_upload: u64,
+    reserved_names: Vec<&'static str>,
+}
+
+fn config() -> AppConfig {
+    AppConfig {
+        max_upload: 100,
+        reserved_names: vec!["admin"],
+    }
+}
+
+fn not_reserved(name: &str, ctx: &AppConfig) -> Result<(), serde_valid::validation::Error> {
+    if ctx.reserved_names.contains(&name) {
+        Err(serde_valid::validation::Error::Custom(format!(
+            "`{name}` is reserved."
+        )))
+    } else {
+        Ok(())
+    }
+}
+
+#[test]
+fn context_numeric_limit_is_ok() {
+    #[derive(Validate)]
+    #[validate(context = AppConfig)]
+    struct TestStruct {
+        #[validate(maximum = ctx.max_upload)]
+        size: u64,
+    }
+
+    let s = TestStruct { size: 100 };
+    assert!(s.validate_with(&config()).is_ok());
+}
+
+#[test]
+fn context_numeric_limit_is_err() {
+    #[derive(Validate)]
+    #[validate(context = AppConfig)]
+    struct TestStruct {
+        #[validate(maximum = ctx.max_upload, message = "too large.")]
+        size: u64,
+        #[validate(max_length = ctx.reserved_names.len())]
+        tag: String,
+    }
+
+    let s = TestStruct {
+        size: 101,
+        tag: "ab".to_owned(),
+    };
+
+    assert_eq!(
+        s.validate_with(&config()).unwrap_err().to_string(),
+        json!({
+            "errors": [],
+            "properties": {
+                "size": {"errors": ["too large."]},
+                "tag": {"errors": ["The length of the value must be `<= 1`."]}
+            }
+        })
+        .to_string()
+    );
+}
+
+#[test]
+fn context_custom_and_rule() {
+    fn within_upload(
+        size: &u64,
+        count: &u64,
+        ctx: &AppConfig,
+    ) -> Result<(), serde_valid::validation::Error> {
+        if size * count > ctx.max_upload {
+            Err(serde_valid::validation::Error::Custom(
+                "total upload is too large.".to_owned(),
+            ))
+        } else {
+            Ok(())
+        }
+    }
+
+    #[derive(Validate)]
+    #[validate(context = AppConfig)]
+    #[rule(within_upload(size, count))]
+    struct TestStruct {
+        #[validate(custom(not_reserved))]
+        name: String,
+        size: u64,
+        count: u64,
+    }
+
+    let s = TestStruct {
+        name: "admin".to_owned(),
+        size: 30,
+        count: 4,
+    };
+
+    assert_eq!(
+        s.validate_with(&config()).unwrap_err().to_string(),
+        json!({
+            "errors": ["total upload is too large."],
+            "properties": {
+                "name": {"errors": ["`admin` is reserved."]}
+            }
+        })
+        .to_string()
+    );
+}
+
+#[test]
+fn context_nested_struct() {
+    #[derive(Validate)]
+    #[validate(context = AppConfig)]
+    struct Parent {
+        #[validate]
+        files: Vec<File>,
+        #[validate]
+        owner: Owner,
+    }
+
+    #[derive(Validate)]
+    #[validate(context = AppConfig)]
+    struct File {
+        #[validate(maximum = ctx.max_upload)]
+        size: u64,
+    }
+
+    #[derive(Validate)]
+    struct Owner {
+        #[validate(min_length = 1)]
+        name: String,
+    }
+
+    let s = Parent {
+        files: vec![File { size: 10 }, File { size: 200 }],
+        owner: Owner {
+            name: "".to_owned(),
+        },
+    };
+
+    assert_eq!(
+        s.validate_with(&config()).unwrap_err().to_string(),
+        json!({
+            "errors": [],
+            "properties": {
+                "files": {
+                    "errors": [],
+                    "items": {
+                        "1": {
+                            "errors": [],
+                            "properties": {
+                                "size": {"errors": ["The number must be `<= 100`."]}
+                            }
+                        }
+                    }
+                },
+                "owner": {
+                    "errors": [],
+                    "properties": {
+                        "name": {"errors": ["The length of the value must be `>= 1`."]}
+                    }
+                }
+            }
+        })
+        .to_string()
+    );
+}
+
+#[test]
+fn context_nested_hand_written_validate() {
+    struct Tags(Vec<String>);
+
+    impl Validate for Tags {
+        fn validate(&self) -> Result<(), serde_valid::validation::Errors> {
+            if self.0.is_empty() {
+                Err(serde_valid::validation::Errors::NewType(vec![
+                    serde_valid::validation::Error::Custom("no tags.".to_owned()),
+                ]))
+            } else {
+                Ok(())
+            }
+        }
+    }
+
+    serde_valid::impl_validate_with!(Tags);
+
+    #[derive(Validate)]
+    #[validate(context = AppConfig)]
+    struct Post {
+        #[validate]
+        tags: Tags,
+    }
+
+    assert!(Post {
+        tags: Tags(vec!["rust".to_owned()])
+    }
+    .validate_with(&config())
+    .is_ok());
+    assert_eq!(
+        Post { tags: Tags(vec![]) }
+            .validate_with(&config())
+            .unwrap_err()
+            .to_string(),
+        json!({
+            "errors": [],
+            "properties": {
+                "tags": {"errors": ["no tags."]}
+            }
+        })
+        .to_string()
+    );
+}
+
+#[test]
+fn context_unnamed_struct_and_enum() {
+    #[derive(Validate)]
+    #[validate(context = AppConfig)]
+    struct TestNewType(#[validate(maximum = ctx.max_upload)] u64);
+
+    #[derive(Validate)]
+    #[validate(context = AppConfig)]
+    enum TestEnum {
+        Named {
+            #[validate(custom(not_reserved))]
+            name: String,
+        },
+        Unnamed(#[validate(maximum = ctx.max_upload)] u64),
+    }
+
+    assert!(TestNewType(100).validate_with(&config()).is_ok());
+    assert!(TestNewType(101).validate_with(&config()).is_err());
+    assert!(TestEnum::Named {
+        name: "taro".to_owned()
+    }
+    .validate_with(&config())
+    .is_ok());
+    assert!(TestEnum::Named {
+        name: "admin".to_owned()
+    }
+    .validate_with(&config())
+    .is_err());
+    assert!(TestEnum::Unnamed(101).validate_with(&config()).is_err());
+}
+
+#[test]
+fn context_with_groups() {
+    #[derive(Validate)]
+    #[validate(context = AppConfig)]
+    struct TestStruct {
+        #[validate(maximum = ctx.max_upload, groups(upload))]
+        size: u64,
+    }
+
+    let s = TestStruct { size: 101 };
+
+    assert!(s.validate_with(&config()).is_ok());
+    assert!(s.validate_group_with("upload", &config()).is_err());
+}
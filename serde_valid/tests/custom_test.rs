@@ -71,3 +71,56 @@ fn custom_validation_error() {
         .to_string()
     );
 }
+
+#[cfg(feature = "flatten")]
+#[test]
+fn custom_validation_with_dynamic_property_keys() {
+    use serde_valid::flatten::IntoFlat;
+    use serde_valid::validation::{Error, Errors, ObjectErrors};
+
+    fn settings_validation(
+        settings: &serde_json::Map<String, serde_json::Value>,
+    ) -> Result<(), Error> {
+        let properties = settings
+            .iter()
+            .filter(|(_, value)| !value.is_number())
+            .map(|(key, _)| {
+                (
+                    key.clone().into(),
+                    Errors::NewType(vec![Error::Custom("The value must be a number.".into())]),
+                )
+            })
+            .collect::<indexmap::IndexMap<_, _>>();
+
+        if properties.is_empty() {
+            Ok(())
+        } else {
+            Err(Error::Properties(ObjectErrors::new(vec![], properties)))
+        }
+    }
+
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(custom(settings_validation))]
+        settings: serde_json::Map<String, serde_json::Value>,
+    }
+
+    let s = TestStruct {
+        settings: json!({"retry": 3, "timeout": "30s"})
+            .as_object()
+            .unwrap()
+            .clone(),
+    };
+
+    assert_eq!(
+        serde_json::to_value(s.validate().unwrap_err().into_flat()).unwrap(),
+        json!({
+            "errors": [{
+                "error": "The value must be a number.",
+                "code": "custom",
+                "params": {},
+                "instance_location": "/settings/timeout"
+            }]
+        })
+    );
+}
@@ -1,3 +1,4 @@
+use serde::Deserialize;
 use serde_json::json;
 use serde_valid::Validate;
 
@@ -102,21 +103,26 @@ fn enum_named_variant_validation_is_err() {
     assert_eq!(
         s.validate().unwrap_err().to_string(),
         json!({
-            "errors": ["Rule error."],
+            "errors": [],
             "properties": {
-                "a": {
-                    "errors": [],
+                "Named": {
+                    "errors": ["Rule error."],
                     "properties": {
-                        "val": {
-                            "errors": ["The number must be `<= 10`."]
-                        }
-                    }
-                },
-                "b": {
-                    "errors": [],
-                    "properties": {
-                        "val": {
-                            "errors": ["The number must be `<= 10`."]
+                        "a": {
+                            "errors": [],
+                            "properties": {
+                                "val": {
+                                    "errors": ["The number must be `<= 10`."]
+                                }
+                            }
+                        },
+                        "b": {
+                            "errors": [],
+                            "properties": {
+                                "val": {
+                                    "errors": ["The number must be `<= 10`."]
+                                }
+                            }
                         }
                     }
                 }
@@ -152,21 +158,26 @@ fn enum_unnamed_variant_validation_is_err() {
     assert_eq!(
         s.validate().unwrap_err().to_string(),
         json!({
-            "errors": ["Rule error."],
-            "items": {
-                "0": {
-                    "errors": [],
-                    "properties": {
-                        "val": {
-                            "errors": ["The number must be `<= 10`."]
-                        }
-                    }
-                },
-                "1": {
-                    "errors": [],
-                    "properties": {
-                        "val": {
-                            "errors": ["The number must be `<= 10`."]
+            "errors": [],
+            "properties": {
+                "Named": {
+                    "errors": ["Rule error."],
+                    "items": {
+                        "0": {
+                            "errors": [],
+                            "properties": {
+                                "val": {
+                                    "errors": ["The number must be `<= 10`."]
+                                }
+                            }
+                        },
+                        "1": {
+                            "errors": [],
+                            "properties": {
+                                "val": {
+                                    "errors": ["The number must be `<= 10`."]
+                                }
+                            }
                         }
                     }
                 }
@@ -195,8 +206,181 @@ fn enum_newtype_variant_validation_is_err() {
     assert_eq!(
         s.validate().unwrap_err().to_string(),
         json!({
-            "errors": ["Rule error.", "The number must be `>= 5`."]
+            "errors": [],
+            "properties": {
+                "NewType": {
+                    "errors": ["Rule error.", "The number must be `>= 5`."]
+                }
+            }
+        })
+        .to_string()
+    );
+}
+
+#[test]
+fn enum_variant_rename_validation_is_err() {
+    #[derive(Deserialize, Validate)]
+    #[serde(rename_all = "snake_case")]
+    enum TestEnum {
+        NamedVariant {
+            #[validate(maximum = 10)]
+            val: i32,
+        },
+        #[serde(rename = "new-type")]
+        NewType(#[validate(maximum = 10)] i32),
+    }
+
+    assert_eq!(
+        TestEnum::NamedVariant { val: 12 }
+            .validate()
+            .unwrap_err()
+            .to_string(),
+        json!({
+            "errors": [],
+            "properties": {
+                "named_variant": {
+                    "errors": [],
+                    "properties": {
+                        "val": {"errors": ["The number must be `<= 10`."]}
+                    }
+                }
+            }
+        })
+        .to_string()
+    );
+    assert_eq!(
+        TestEnum::NewType(12).validate().unwrap_err().to_string(),
+        json!({
+            "errors": [],
+            "properties": {
+                "new-type": {"errors": ["The number must be `<= 10`."]}
+            }
+        })
+        .to_string()
+    );
+}
+
+#[test]
+fn enum_adjacently_tagged_validation_is_err() {
+    #[derive(Deserialize, Validate)]
+    #[serde(tag = "type", content = "data")]
+    enum TestEnum {
+        Unnamed(#[validate(maximum = 10)] i32, #[validate(maximum = 10)] i32),
+    }
+
+    assert_eq!(
+        TestEnum::Unnamed(1, 12).validate().unwrap_err().to_string(),
+        json!({
+            "errors": [],
+            "properties": {
+                "data": {
+                    "errors": [],
+                    "items": {
+                        "1": {"errors": ["The number must be `<= 10`."]}
+                    }
+                }
+            }
         })
         .to_string()
     );
 }
+
+#[test]
+fn enum_internally_tagged_and_untagged_validation_is_err() {
+    #[derive(Deserialize, Validate)]
+    #[serde(tag = "type")]
+    enum InternallyTagged {
+        Named {
+            #[validate(maximum = 10)]
+            val: i32,
+        },
+    }
+
+    #[derive(Deserialize, Validate)]
+    #[serde(untagged)]
+    enum Untagged {
+        Named {
+            #[validate(maximum = 10)]
+            val: i32,
+        },
+    }
+
+    let expected = json!({
+        "errors": [],
+        "properties": {
+            "val": {"errors": ["The number must be `<= 10`."]}
+        }
+    })
+    .to_string();
+
+    assert_eq!(
+        InternallyTagged::Named { val: 12 }
+            .validate()
+            .unwrap_err()
+            .to_string(),
+        expected
+    );
+    assert_eq!(
+        Untagged::Named { val: 12 }
+            .validate()
+            .unwrap_err()
+            .to_string(),
+        expected
+    );
+}
+
+#[cfg(feature = "flatten")]
+#[test]
+fn enum_variant_into_flat() {
+    use serde_valid::flatten::IntoFlat;
+
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate]
+        external: External,
+        #[validate]
+        adjacent: Adjacent,
+    }
+
+    #[derive(Validate)]
+    enum External {
+        Named {
+            #[validate(maximum = 10)]
+            val: i32,
+        },
+    }
+
+    #[derive(Deserialize, Validate)]
+    #[serde(tag = "type", content = "data")]
+    enum Adjacent {
+        Named {
+            #[validate(maximum = 10)]
+            val: i32,
+        },
+    }
+
+    let s = TestStruct {
+        external: External::Named { val: 12 },
+        adjacent: Adjacent::Named { val: 12 },
+    };
+
+    assert_eq!(
+        serde_json::to_value(s.validate().unwrap_err().into_flat()).unwrap(),
+        json!({
+            "errors": [
+                {
+                    "error": "The number must be `<= 10`.",
+                    "code": "maximum",
+                    "params": {"maximum": 10},
+                    "instance_location": "/external/Named/val"
+                },
+                {
+                    "error": "The number must be `<= 10`.",
+                    "code": "maximum",
+                    "params": {"maximum": 10},
+                    "instance_location": "/adjacent/data/val"
+                }
+            ]
+        })
+    );
+}
@@ -209,6 +209,30 @@ fn enumerate_custom_err_message() {
     );
 }
 
+#[test]
+fn enumerate_custom_err_message_template() {
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(enumerate(1, 2, 3), message = "{enumerate} only.")]
+        val: i32,
+    }
+
+    let s = TestStruct { val: 4 };
+
+    assert_eq!(
+        s.validate().unwrap_err().to_string(),
+        json!({
+            "errors": [],
+            "properties": {
+                "val": {
+                    "errors": ["1, 2, 3 only."]
+                }
+            }
+        })
+        .to_string()
+    );
+}
+
 #[test]
 fn enumerate_numeric_trait() {
     struct MyType(i32);
@@ -0,0 +1,248 @@
+use serde_json::json;
+use serde_valid::{Validate, ValidateFormat};
+use std::borrow::Cow;
+
+#[test]
+fn format_string_type() {
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(format = "email")]
+        email: String,
+        #[validate(format = "uri")]
+        uri: String,
+        #[validate(format = "uuid")]
+        uuid: String,
+        #[validate(format = "ipv4")]
+        ipv4: String,
+        #[validate(format = "ipv6")]
+        ipv6: String,
+        #[validate(format = "hostname")]
+        hostname: String,
+        #[validate(format = "date")]
+        date: String,
+        #[validate(format = "date-time")]
+        date_time: String,
+    }
+
+    let s = TestStruct {
+        email: String::from("taro@example.com"),
+        uri: String::from("https://example.com/path?query=1"),
+        uuid: String::from("f81d4fae-7dec-11d0-a765-00a0c91e6bf6"),
+        ipv4: String::from("192.168.0.1"),
+        ipv6: String::from("2001:db8::1"),
+        hostname: String::from("example.com"),
+        date: String::from("2020-09-10"),
+        date_time: String::from("2020-09-10T12:34:56+09:00"),
+    };
+    assert!(s.validate().is_ok());
+}
+
+#[test]
+fn format_str_type() {
+    #[derive(Validate)]
+    struct TestStruct<'a> {
+        #[validate(format = "date")]
+        val: &'a str,
+    }
+
+    let s = TestStruct { val: "2020-09-10" };
+    assert!(s.validate().is_ok());
+}
+
+#[test]
+fn format_cow_str_type() {
+    #[derive(Validate)]
+    struct TestStruct<'a> {
+        #[validate(format = "date")]
+        val: Cow<'a, str>,
+    }
+
+    let s = TestStruct {
+        val: Cow::from("2020-09-10"),
+    };
+    assert!(s.validate().is_ok());
+}
+
+#[test]
+fn format_is_err() {
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(format = "email")]
+        val: String,
+    }
+
+    let s = TestStruct {
+        val: String::from("taro.example.com"),
+    };
+    assert!(s.validate().is_err());
+}
+
+#[test]
+fn format_unknown_is_err() {
+    const UNKNOWN_FORMAT: &str = "unknown-format";
+
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(format = UNKNOWN_FORMAT)]
+        val: String,
+    }
+
+    let s = TestStruct {
+        val: String::from("value"),
+    };
+    assert!(s.validate().is_err());
+}
+
+#[test]
+fn format_vec_type_is_err() {
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(format = "uuid")]
+        val: Vec<String>,
+    }
+
+    let s = TestStruct {
+        val: vec![
+            String::from("f81d4fae-7dec-11d0-a765-00a0c91e6bf6"),
+            String::from("not-a-uuid"),
+        ],
+    };
+
+    assert_eq!(
+        s.validate().unwrap_err().to_string(),
+        json!({
+            "errors": [],
+            "properties": {
+                "val": {
+                    "errors": [],
+                    "items": {
+                        "1": {
+                            "errors": ["The value must match the format of \"uuid\"."]
+                        }
+                    }
+                }
+            }
+        })
+        .to_string()
+    );
+}
+
+#[test]
+fn format_option_type_is_ok() {
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(format = "ipv4")]
+        val: Option<String>,
+        #[validate(format = "ipv4")]
+        none: Option<String>,
+    }
+
+    let s = TestStruct {
+        val: Some(String::from("127.0.0.1")),
+        none: None,
+    };
+    assert!(s.validate().is_ok());
+}
+
+#[test]
+fn format_err_message() {
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(format = "date")]
+        val: String,
+    }
+
+    let s = TestStruct {
+        val: String::from("2020/09/10"),
+    };
+
+    assert_eq!(
+        s.validate().unwrap_err().to_string(),
+        json!({
+            "errors": [],
+            "properties": {
+                "val": {
+                    "errors": ["The value must match the format of \"date\"."]
+                }
+            }
+        })
+        .to_string()
+    );
+}
+
+#[test]
+fn format_custom_err_message() {
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(format = "email", message = "this is custom message.")]
+        val: String,
+    }
+
+    let s = TestStruct {
+        val: String::from("taro.example.com"),
+    };
+
+    assert_eq!(
+        s.validate().unwrap_err().to_string(),
+        json!({
+            "errors": [],
+            "properties": {
+                "val": {
+                    "errors": ["this is custom message."]
+                }
+            }
+        })
+        .to_string()
+    );
+}
+
+#[test]
+fn format_registered() {
+    fn is_postal_code(value: &str) -> bool {
+        value.len() == 7 && value.chars().all(|c| c.is_ascii_digit())
+    }
+
+    const JP_POSTAL_CODE: &str = "jp-postal-code";
+
+    serde_valid::format::register_format(JP_POSTAL_CODE, is_postal_code);
+
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(format = JP_POSTAL_CODE)]
+        val: String,
+    }
+
+    assert!(TestStruct {
+        val: String::from("1000001")
+    }
+    .validate()
+    .is_ok());
+    assert!(TestStruct {
+        val: String::from("100-0001")
+    }
+    .validate()
+    .is_err());
+}
+
+#[test]
+fn format_trait() {
+    struct MyType(String);
+
+    impl ValidateFormat for MyType {
+        fn validate_format(&self, format: &str) -> Result<(), serde_valid::FormatError> {
+            self.0.validate_format(format)
+        }
+    }
+
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(format = "hostname")]
+        val: MyType,
+    }
+
+    let s = TestStruct {
+        val: MyType(String::from("example.com")),
+    };
+
+    assert!(s.validate().is_ok());
+}
@@ -0,0 +1,173 @@
+use serde_json::json;
+use serde_valid::Validate;
+
+#[test]
+fn groups_named_struct() {
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(minimum = 1, groups(update))]
+        #[validate(maximum = 0, groups(create))]
+        id: i32,
+        #[validate(max_length = 3, groups(create, update))]
+        name: String,
+        #[validate(min_length = 5)]
+        memo: String,
+    }
+
+    let s = TestStruct {
+        id: 0,
+        name: "taro".to_owned(),
+        memo: "memo".to_owned(),
+    };
+
+    assert_eq!(
+        s.validate().unwrap_err().to_string(),
+        json!({
+            "errors": [],
+            "properties": {
+                "memo": {"errors": ["The length of the value must be `>= 5`."]}
+            }
+        })
+        .to_string()
+    );
+    assert_eq!(
+        s.validate_group("create").unwrap_err().to_string(),
+        json!({
+            "errors": [],
+            "properties": {
+                "name": {"errors": ["The length of the value must be `<= 3`."]}
+            }
+        })
+        .to_string()
+    );
+    assert_eq!(
+        s.validate_group("update").unwrap_err().to_string(),
+        json!({
+            "errors": [],
+            "properties": {
+                "id": {"errors": ["The number must be `>= 1`."]},
+                "name": {"errors": ["The length of the value must be `<= 3`."]}
+            }
+        })
+        .to_string()
+    );
+    assert!(s.validate_group("delete").is_ok());
+}
+
+#[test]
+fn groups_with_custom_message() {
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(maximum = 0, message = "id is forbidden.", groups(create))]
+        id: i32,
+    }
+
+    let s = TestStruct { id: 1 };
+
+    assert!(s.validate().is_ok());
+    assert_eq!(
+        s.validate_group("create").unwrap_err().to_string(),
+        json!({
+            "errors": [],
+            "properties": {
+                "id": {"errors": ["id is forbidden."]}
+            }
+        })
+        .to_string()
+    );
+}
+
+#[test]
+fn groups_nested_struct_forward_group() {
+    #[derive(Validate)]
+    struct Inner {
+        #[validate(maximum = 10, groups(create))]
+        val: i32,
+    }
+
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate]
+        inner: Inner,
+        #[validate]
+        inners: Vec<Inner>,
+    }
+
+    let s = TestStruct {
+        inner: Inner { val: 20 },
+        inners: vec![Inner { val: 5 }, Inner { val: 20 }],
+    };
+
+    assert!(s.validate().is_ok());
+    assert_eq!(
+        s.validate_group("create").unwrap_err().to_string(),
+        json!({
+            "errors": [],
+            "properties": {
+                "inner": {
+                    "errors": [],
+                    "properties": {
+                        "val": {"errors": ["The number must be `<= 10`."]}
+                    }
+                },
+                "inners": {
+                    "errors": [],
+                    "items": {
+                        "1": {
+                            "errors": [],
+                            "properties": {
+                                "val": {"errors": ["The number must be `<= 10`."]}
+                            }
+                        }
+                    }
+                }
+            }
+        })
+        .to_string()
+    );
+}
+
+#[test]
+fn groups_rules_run_in_default_group() {
+    fn sample_rule(_val: &i32) -> Result<(), serde_valid::validation::Error> {
+        Err(serde_valid::validation::Error::Custom(
+            "Rule error.".to_owned(),
+        ))
+    }
+
+    #[derive(Validate)]
+    #[rule(sample_rule(val))]
+    struct TestStruct {
+        val: i32,
+    }
+
+    let s = TestStruct { val: 1 };
+
+    assert!(s.validate().is_err());
+    assert!(s.validate_group("create").is_ok());
+}
+
+#[test]
+fn groups_unnamed_struct_and_enum() {
+    #[derive(Validate)]
+    struct TestNewType(#[validate(maximum = 0, groups(create))] i32);
+
+    #[derive(Validate)]
+    enum TestEnum {
+        Named {
+            #[validate(maximum = 0, groups(create))]
+            val: i32,
+        },
+        Unnamed(
+            #[validate(maximum = 0, groups(create))] i32,
+            #[validate(maximum = 0)] i32,
+        ),
+    }
+
+    assert!(TestNewType(1).validate().is_ok());
+    assert!(TestNewType(1).validate_group("create").is_err());
+    assert!(TestEnum::Named { val: 1 }.validate().is_ok());
+    assert!(TestEnum::Named { val: 1 }.validate_group("create").is_err());
+    assert!(TestEnum::Unnamed(1, 0).validate().is_ok());
+    assert!(TestEnum::Unnamed(1, 0).validate_group("create").is_err());
+}
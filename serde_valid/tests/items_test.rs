@@ -180,22 +180,26 @@ fn items_custom_err_message_fn() {
     #[derive(Validate)]
     struct TestStruct {
         #[validate(min_items = 4, message_fn(min_error_message))]
+        val1: Vec<i32>,
         #[validate(max_items = 2, message_fn(max_error_message))]
-        val: Vec<i32>,
+        val2: Vec<i32>,
     }
 
-    let s = TestStruct { val: vec![1, 2, 3] };
+    let s = TestStruct {
+        val1: vec![1, 2, 3],
+        val2: vec![1, 2, 3],
+    };
 
     assert_eq!(
         s.validate().unwrap_err().to_string(),
         json!({
         "errors": [],
         "properties": {
-            "val": {
-                "errors": [
-                    "this is min custom message.",
-                    "this is max custom message."
-                ]
+            "val1": {
+                "errors": ["this is min custom message."]
+            },
+            "val2": {
+                "errors": ["this is max custom message."]
             }
         }
         })
@@ -208,22 +212,26 @@ fn items_custom_err_message() {
     #[derive(Validate)]
     struct TestStruct {
         #[validate(min_items = 4, message = "this is min custom message.")]
+        val1: Vec<i32>,
         #[validate(max_items = 2, message = "this is max custom message.")]
-        val: Vec<i32>,
+        val2: Vec<i32>,
     }
 
-    let s = TestStruct { val: vec![1, 2, 3] };
+    let s = TestStruct {
+        val1: vec![1, 2, 3],
+        val2: vec![1, 2, 3],
+    };
 
     assert_eq!(
         s.validate().unwrap_err().to_string(),
         json!({
             "errors": [],
             "properties": {
-                "val": {
-                    "errors": [
-                        "this is min custom message.",
-                        "this is max custom message."
-                    ]
+                "val1": {
+                    "errors": ["this is min custom message."]
+                },
+                "val2": {
+                    "errors": ["this is max custom message."]
                 }
             }
         })
@@ -282,12 +282,14 @@ fn length_custom_err_message_fn() {
     #[derive(Validate)]
     struct TestStruct {
         #[validate(min_length = 5, message_fn(custom_min_error_message))]
+        val1: String,
         #[validate(max_length = 3, message_fn(custom_max_error_message))]
-        val: String,
+        val2: String,
     }
 
     let s = TestStruct {
-        val: String::from("test"),
+        val1: String::from("test"),
+        val2: String::from("test"),
     };
 
     assert_eq!(
@@ -295,11 +297,11 @@ fn length_custom_err_message_fn() {
         json!({
             "errors": [],
             "properties": {
-                "val": {
-                    "errors": [
-                        "this is min custom message.",
-                        "this is max custom message."
-                    ]
+                "val1": {
+                    "errors": ["this is min custom message."]
+                },
+                "val2": {
+                    "errors": ["this is max custom message."]
                 }
             }
         })
@@ -312,12 +314,14 @@ fn length_custom_err_message() {
     #[derive(Validate)]
     struct TestStruct {
         #[validate(min_length = 5, message = "this is min custom message.")]
+        val1: String,
         #[validate(max_length = 3, message = "this is max custom message.")]
-        val: String,
+        val2: String,
     }
 
     let s = TestStruct {
-        val: String::from("test"),
+        val1: String::from("test"),
+        val2: String::from("test"),
     };
 
     assert_eq!(
@@ -325,11 +329,46 @@ fn length_custom_err_message() {
         json!({
             "errors": [],
             "properties": {
-                "val": {
-                    "errors": [
-                        "this is min custom message.",
-                        "this is max custom message."
-                    ]
+                "val1": {
+                    "errors": ["this is min custom message."]
+                },
+                "val2": {
+                    "errors": ["this is max custom message."]
+                }
+            }
+        })
+        .to_string()
+    );
+}
+
+#[test]
+fn length_custom_err_message_template() {
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(min_length = 5, message = "must be at least {min_length} characters.")]
+        val1: String,
+        #[validate(
+            max_length = 3,
+            message = "must be at most {max_length} characters, not {{}}."
+        )]
+        val2: String,
+    }
+
+    let s = TestStruct {
+        val1: String::from("test"),
+        val2: String::from("test"),
+    };
+
+    assert_eq!(
+        s.validate().unwrap_err().to_string(),
+        json!({
+            "errors": [],
+            "properties": {
+                "val1": {
+                    "errors": ["must be at least 5 characters."]
+                },
+                "val2": {
+                    "errors": ["must be at most 3 characters, not {}."]
                 }
             }
         })
@@ -342,12 +381,14 @@ fn length_vec_err_message() {
     #[derive(Validate)]
     struct TestStruct {
         #[validate(min_length = 5, message = "this is min custom message.")]
+        val1: Vec<String>,
         #[validate(max_length = 3, message = "this is max custom message.")]
-        val: Vec<String>,
+        val2: Vec<String>,
     }
 
     let s = TestStruct {
-        val: vec![String::from("test")],
+        val1: vec![String::from("test")],
+        val2: vec![String::from("test")],
     };
 
     assert_eq!(
@@ -355,14 +396,19 @@ fn length_vec_err_message() {
         json!({
             "errors": [],
             "properties": {
-                "val": {
+                "val1": {
+                    "errors": [],
+                    "items": {
+                        "0": {
+                            "errors": ["this is min custom message."]
+                        }
+                    }
+                },
+                "val2": {
                     "errors": [],
                     "items": {
                         "0": {
-                            "errors": [
-                                "this is min custom message.",
-                                "this is max custom message."
-                            ]
+                            "errors": ["this is max custom message."]
                         }
                     }
                 }
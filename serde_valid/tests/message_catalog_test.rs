@@ -0,0 +1,105 @@
+use std::sync::Mutex;
+
+use serde_json::json;
+use serde_valid::{
+    BuiltinMessage, MaxLengthError, MaximumError, MessageCatalog, MinItemsError, Validate,
+};
+
+/// The default messages are global, so the tests replacing them run one at a time.
+static DEFAULT_MESSAGES_LOCK: Mutex<()> = Mutex::new(());
+
+struct Catalog;
+
+impl MessageCatalog for Catalog {
+    fn maximum(&self, error: &MaximumError) -> String {
+        format!("must be at most {}", error.maximum)
+    }
+
+    fn max_length(&self, error: &MaxLengthError) -> String {
+        format!("must be at most {} characters", error.max_length)
+    }
+}
+
+/// Replaces itself with [`Catalog`] the first time it is used.
+struct OneShotCatalog;
+
+impl MessageCatalog for OneShotCatalog {
+    fn min_items(&self, error: &MinItemsError) -> String {
+        serde_valid::set_default_messages(Catalog);
+        format!("{} (once)", error.builtin_message())
+    }
+}
+
+#[test]
+fn default_messages_from_catalog() {
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(maximum = 10)]
+        val1: i32,
+        #[validate(max_length = 3)]
+        val2: String,
+        #[validate(max_length = 3, message = "too long.")]
+        val3: String,
+        #[validate(min_items = 2)]
+        val4: Vec<i32>,
+    }
+
+    let _guard = DEFAULT_MESSAGES_LOCK.lock().unwrap();
+    serde_valid::set_default_messages(Catalog);
+
+    let s = TestStruct {
+        val1: 11,
+        val2: String::from("test"),
+        val3: String::from("test"),
+        val4: vec![1],
+    };
+
+    assert_eq!(
+        s.validate().unwrap_err().to_string(),
+        json!({
+            "errors": [],
+            "properties": {
+                "val1": {"errors": ["must be at most 10"]},
+                "val2": {"errors": ["must be at most 3 characters"]},
+                "val3": {"errors": ["too long."]},
+                "val4": {"errors": ["The length of the items must be `>= 2`."]}
+            }
+        })
+        .to_string()
+    );
+}
+
+#[test]
+fn catalog_can_replace_default_messages() {
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(min_items = 2)]
+        val: Vec<i32>,
+    }
+
+    let _guard = DEFAULT_MESSAGES_LOCK.lock().unwrap();
+    serde_valid::set_default_messages(OneShotCatalog);
+
+    let s = TestStruct { val: vec![1] };
+
+    assert_eq!(
+        s.validate().unwrap_err().to_string(),
+        json!({
+            "errors": [],
+            "properties": {
+                "val": {"errors": ["The length of the items must be `>= 2`. (once)"]}
+            }
+        })
+        .to_string()
+    );
+    assert_eq!(
+        s.validate().unwrap_err().to_string(),
+        json!({
+            "errors": [],
+            "properties": {
+                "val": {"errors": ["The length of the items must be `>= 2`."]}
+            }
+        })
+        .to_string()
+    );
+}
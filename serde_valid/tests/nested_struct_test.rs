@@ -135,7 +135,7 @@ fn nested_validate_err_message() {
     };
 
     assert_eq!(
-        serde_json::to_value(&s.validate().unwrap_err()).unwrap(),
+        serde_json::to_value(s.validate().unwrap_err()).unwrap(),
         json!({
             "errors": [],
             "properties": {
@@ -166,26 +166,39 @@ fn nested_validate_err_message() {
                 "named_fields_enum": {
                     "errors": [],
                     "properties": {
-                        "val": {
-                            "errors": ["The number must be `<= 0`."]
+                        "Value": {
+                            "errors": [],
+                            "properties": {
+                                "val": {
+                                    "errors": ["The number must be `<= 0`."]
+                                }
+                            }
                         }
                     }
                 },
                 "unnamed_fields_enum": {
                     "errors": [],
-                    "items": {
-                        "0": {
-                            "errors": ["The number must be `<= 0`."]
-                        },
-                        "1": {
-                            "errors": ["The number must be `<= 0`."]
+                    "properties": {
+                        "Value": {
+                            "errors": [],
+                            "items": {
+                                "0": {
+                                    "errors": ["The number must be `<= 0`."]
+                                },
+                                "1": {
+                                    "errors": ["The number must be `<= 0`."]
+                                }
+                            }
                         }
                     }
                 },
                 "single_unnamed_fields_enum": {
-                    "errors": [
-                        "The number must be `<= 0`."
-                    ]
+                    "errors": [],
+                    "properties": {
+                        "Value": {
+                            "errors": ["The number must be `<= 0`."]
+                        }
+                    }
                 }
             }
         })
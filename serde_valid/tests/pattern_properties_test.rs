@@ -0,0 +1,114 @@
+use std::collections::BTreeMap;
+
+use serde::Deserialize;
+use serde_json::json;
+use serde_valid::{Validate, ValidateSchema};
+
+#[test]
+fn pattern_properties_is_ok() {
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(pattern_properties("^x-", max_length = 5))]
+        val: BTreeMap<String, String>,
+    }
+
+    let s = TestStruct {
+        val: BTreeMap::from([
+            ("x-id".to_owned(), "12345".to_owned()),
+            (
+                "description".to_owned(),
+                "not matched by the pattern".to_owned(),
+            ),
+        ]),
+    };
+    assert!(s.validate().is_ok());
+}
+
+#[test]
+fn pattern_properties_is_err() {
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(
+            pattern_properties("^count_", maximum = 10),
+            message = "must be <= {maximum}."
+        )]
+        val: BTreeMap<String, i32>,
+    }
+
+    let s = TestStruct {
+        val: BTreeMap::from([
+            ("count_a".to_owned(), 3),
+            ("count_b".to_owned(), 12),
+            ("total".to_owned(), 100),
+        ]),
+    };
+
+    assert_eq!(
+        serde_json::to_value(s.validate().unwrap_err()).unwrap(),
+        json!({
+            "errors": [],
+            "properties": {
+                "val": {
+                    "errors": [],
+                    "properties": {
+                        "count_b": {"errors": ["must be <= 10."]}
+                    }
+                }
+            }
+        })
+    );
+}
+
+#[test]
+fn pattern_properties_nested_is_err() {
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(pattern_properties("^item_", all_of(min_length = 1, max_length = 3)))]
+        names: BTreeMap<String, String>,
+    }
+
+    let s = TestStruct {
+        names: BTreeMap::from([
+            ("item_a".to_owned(), "abcd".to_owned()),
+            ("other".to_owned(), "abcd".to_owned()),
+        ]),
+    };
+
+    assert_eq!(
+        serde_json::to_value(s.validate().unwrap_err()).unwrap(),
+        json!({
+            "errors": [],
+            "properties": {
+                "names": {
+                    "errors": [],
+                    "properties": {
+                        "item_a": {
+                            "errors": [
+                                "The value must match all of the validations: The length of the value must be `<= 3`."
+                            ]
+                        }
+                    }
+                }
+            }
+        })
+    );
+}
+
+#[test]
+fn pattern_properties_schema() {
+    #[derive(Deserialize, Validate, ValidateSchema)]
+    struct TestStruct {
+        #[validate(pattern_properties("^x-", max_length = 100))]
+        val: BTreeMap<String, String>,
+    }
+
+    assert_eq!(
+        TestStruct::json_schema(),
+        json!({
+            "type": "object",
+            "properties": {
+                "val": {"patternProperties": {"^x-": {"maxLength": 100}}}
+            }
+        })
+    );
+}
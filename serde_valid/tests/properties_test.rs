@@ -183,12 +183,17 @@ fn properties_custom_err_message_fn() {
     #[derive(Deserialize, Validate)]
     struct TestStruct {
         #[validate(min_properties = 3, message_fn(min_custom_error_message))]
+        val1: serde_json::Map<String, serde_json::Value>,
         #[validate(max_properties = 1, message_fn(max_custom_error_message))]
-        val: serde_json::Map<String, serde_json::Value>,
+        val2: serde_json::Map<String, serde_json::Value>,
     }
 
     let s: TestStruct = serde_json::from_value(json!({
-        "val": {
+        "val1": {
+            "key1": "value1",
+            "key2": "value2",
+        },
+        "val2": {
             "key1": "value1",
             "key2": "value2",
         }
@@ -200,11 +205,11 @@ fn properties_custom_err_message_fn() {
         json!({
             "errors": [],
             "properties": {
-                "val": {
-                    "errors": [
-                        "this is min custom message.",
-                        "this is max custom message."
-                    ]
+                "val1": {
+                    "errors": ["this is min custom message."]
+                },
+                "val2": {
+                    "errors": ["this is max custom message."]
                 }
             }
         })
@@ -217,12 +222,17 @@ fn properties_custom_err_message() {
     #[derive(Deserialize, Validate)]
     struct TestStruct {
         #[validate(min_properties = 3, message = "this is min custom message.")]
+        val1: serde_json::Map<String, serde_json::Value>,
         #[validate(max_properties = 1, message = "this is max custom message.")]
-        val: serde_json::Map<String, serde_json::Value>,
+        val2: serde_json::Map<String, serde_json::Value>,
     }
 
     let s: TestStruct = serde_json::from_value(json!({
-        "val": {
+        "val1": {
+            "key1": "value1",
+            "key2": "value2",
+        },
+        "val2": {
             "key1": "value1",
             "key2": "value2",
         }
@@ -234,11 +244,11 @@ fn properties_custom_err_message() {
         json!({
             "errors": [],
             "properties": {
-                "val": {
-                    "errors": [
-                    "this is min custom message.",
-                    "this is max custom message."
-                    ]
+                "val1": {
+                    "errors": ["this is min custom message."]
+                },
+                "val2": {
+                    "errors": ["this is max custom message."]
                 }
             }
         })
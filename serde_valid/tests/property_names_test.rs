@@ -0,0 +1,135 @@
+use std::collections::{BTreeMap, HashMap};
+
+use serde::Deserialize;
+use serde_json::json;
+use serde_valid::{Validate, ValidateSchema};
+
+#[test]
+fn property_names_is_ok() {
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(property_names(pattern = "^[a-z_]+$"))]
+        val: HashMap<String, i32>,
+    }
+
+    let s = TestStruct {
+        val: HashMap::from([("timeout".to_owned(), 1), ("max_size".to_owned(), 2)]),
+    };
+    assert!(s.validate().is_ok());
+}
+
+#[test]
+fn property_names_is_err() {
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(property_names(pattern = "^[a-z_]+$"))]
+        #[validate(property_names(max_length = 8), message = "too long key.")]
+        val: BTreeMap<String, i32>,
+    }
+
+    let s = TestStruct {
+        val: BTreeMap::from([
+            ("Timeout".to_owned(), 1),
+            ("max_upload_size".to_owned(), 2),
+            ("size".to_owned(), 3),
+        ]),
+    };
+
+    assert_eq!(
+        serde_json::to_value(s.validate().unwrap_err()).unwrap(),
+        json!({
+            "errors": [],
+            "properties": {
+                "val": {
+                    "errors": [],
+                    "properties": {
+                        "Timeout": {
+                            "errors": ["The value must match the pattern of \"^[a-z_]+$\"."]
+                        },
+                        "max_upload_size": {
+                            "errors": ["too long key."]
+                        }
+                    }
+                }
+            }
+        })
+    );
+}
+
+#[test]
+fn property_names_json_map_type() {
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(property_names(enumerate("name", "age")))]
+        val: Option<serde_json::Map<String, serde_json::Value>>,
+    }
+
+    let json = json!({"name": "taro", "role": "admin"});
+    let s = TestStruct {
+        val: json.as_object().cloned(),
+    };
+
+    assert_eq!(
+        s.validate().unwrap_err().to_string(),
+        json!({
+            "errors": [],
+            "properties": {
+                "val": {
+                    "errors": [],
+                    "properties": {
+                        "role": {"errors": ["The value must be in [name, age]."]}
+                    }
+                }
+            }
+        })
+        .to_string()
+    );
+    assert!(TestStruct { val: None }.validate().is_ok());
+}
+
+#[cfg(feature = "flatten")]
+#[test]
+fn property_names_in_flat_path() {
+    use serde_valid::flatten::IntoFlat;
+
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(property_names(max_length = 4))]
+        headers: HashMap<String, String>,
+    }
+
+    let s = TestStruct {
+        headers: HashMap::from([("accept".to_owned(), "*/*".to_owned())]),
+    };
+
+    assert_eq!(
+        serde_json::to_value(s.validate().unwrap_err().into_flat()).unwrap(),
+        json!({
+            "errors": [{
+                "error": "The length of the value must be `<= 4`.",
+                "code": "max_length",
+                "params": {"max_length": 4},
+                "instance_location": "/headers/accept"
+            }]
+        })
+    );
+}
+
+#[test]
+fn property_names_schema() {
+    #[derive(Deserialize, Validate, ValidateSchema)]
+    struct TestStruct {
+        #[validate(property_names(pattern = "^[a-z_]+$"))]
+        val: HashMap<String, i32>,
+    }
+
+    assert_eq!(
+        TestStruct::json_schema(),
+        json!({
+            "type": "object",
+            "properties": {
+                "val": {"propertyNames": {"pattern": "^[a-z_]+$"}}
+            }
+        })
+    );
+}
@@ -295,22 +295,23 @@ fn range_custom_err_message_fn() {
     #[derive(Validate)]
     struct TestStruct {
         #[validate(minimum = 5, message_fn(custom_min_error_message))]
+        val1: i32,
         #[validate(maximum = 3, message_fn(custom_max_error_message))]
-        val: i32,
+        val2: i32,
     }
 
-    let s = TestStruct { val: 4 };
+    let s = TestStruct { val1: 4, val2: 4 };
 
     assert_eq!(
         s.validate().unwrap_err().to_string(),
         json!({
             "errors": [],
             "properties": {
-                "val": {
-                    "errors": [
-                        "this is min custom message.",
-                        "this is max custom message."
-                    ]
+                "val1": {
+                    "errors": ["this is min custom message."]
+                },
+                "val2": {
+                    "errors": ["this is max custom message."]
                 }
             }
         })
@@ -323,11 +324,12 @@ fn range_custom_err_message() {
     #[derive(Validate)]
     struct TestStruct {
         #[validate(minimum = 5, message = "this is min custom message.")]
+        val1: i32,
         #[validate(maximum = 3, message = "this is max custom message.")]
-        val: i32,
+        val2: i32,
     }
 
-    let s = TestStruct { val: 4 };
+    let s = TestStruct { val1: 4, val2: 4 };
     let result = s.validate().unwrap_err();
 
     assert_eq!(
@@ -335,11 +337,11 @@ fn range_custom_err_message() {
         json!({
             "errors": [],
             "properties": {
-                "val": {
-                    "errors": [
-                        "this is min custom message.",
-                        "this is max custom message."
-                    ]
+                "val1": {
+                    "errors": ["this is min custom message."]
+                },
+                "val2": {
+                    "errors": ["this is max custom message."]
                 }
             }
         })
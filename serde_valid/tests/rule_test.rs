@@ -1,4 +1,6 @@
+use serde::Deserialize;
 use serde_json::json;
+use serde_valid::validation::{Error, Errors, ObjectErrors, RuleError};
 use serde_valid::Validate;
 
 fn sample_ok_rule(_val: &i32) -> Result<(), serde_valid::validation::Error> {
@@ -112,3 +114,171 @@ fn rule_enum_is_ok() {
     let s4 = TestEnum::NoField;
     assert!(s4.validate().is_ok());
 }
+
+#[test]
+fn rule_error_at_named_field_is_err() {
+    fn date_rule(start_date: &u32, end_date: &u32) -> Result<(), RuleError> {
+        if start_date <= end_date {
+            Ok(())
+        } else {
+            Err(RuleError::at(
+                "end_date",
+                Error::Custom("The end date must be after the start date.".to_owned()),
+            ))
+        }
+    }
+
+    #[derive(Deserialize, Validate)]
+    #[serde(rename_all = "camelCase")]
+    #[rule(date_rule(start_date, end_date))]
+    struct TestStruct {
+        start_date: u32,
+        #[validate(maximum = 10)]
+        end_date: u32,
+    }
+
+    let s = TestStruct {
+        start_date: 12,
+        end_date: 11,
+    };
+
+    assert_eq!(
+        serde_json::to_value(s.validate().unwrap_err()).unwrap(),
+        json!({
+            "errors": [],
+            "properties": {
+                "endDate": {
+                    "errors": [
+                        "The number must be `<= 10`.",
+                        "The end date must be after the start date."
+                    ]
+                }
+            }
+        })
+    );
+}
+
+#[test]
+fn rule_errors_tree_is_err() {
+    fn pair_rule(val1: &i32, val2: &i32) -> Result<(), Vec<RuleError>> {
+        if val1 == val2 {
+            Err(vec![
+                RuleError::at("val1", Error::Custom("Duplicated.".to_owned())),
+                RuleError::at("val2", Error::Custom("Duplicated.".to_owned())),
+                Error::Custom("The values must be different.".to_owned()).into(),
+            ])
+        } else {
+            Ok(())
+        }
+    }
+
+    #[derive(Validate)]
+    #[rule(pair_rule(val1, val2))]
+    struct TestStruct {
+        val1: i32,
+        val2: i32,
+    }
+
+    let s = TestStruct { val1: 1, val2: 1 };
+
+    assert_eq!(
+        s.validate().unwrap_err().to_string(),
+        json!({
+            "errors": ["The values must be different."],
+            "properties": {
+                "val1": {"errors": ["Duplicated."]},
+                "val2": {"errors": ["Duplicated."]}
+            }
+        })
+        .to_string()
+    );
+}
+
+#[test]
+fn rule_error_at_index_is_err() {
+    fn range_rule(min: &i32, max: &i32) -> Result<(), RuleError> {
+        if min <= max {
+            Ok(())
+        } else {
+            Err(RuleError::at_index(
+                1,
+                Error::Custom("The max must be `>= min`.".to_owned()),
+            ))
+        }
+    }
+
+    #[derive(Validate)]
+    #[rule(range_rule(0, 1))]
+    struct TestStruct(i32, i32);
+
+    assert_eq!(
+        TestStruct(2, 1).validate().unwrap_err().to_string(),
+        json!({
+            "errors": [],
+            "items": {
+                "1": {"errors": ["The max must be `>= min`."]}
+            }
+        })
+        .to_string()
+    );
+}
+
+#[test]
+fn rule_error_enum_variant_is_err() {
+    fn nested_rule(val: &i32) -> Result<(), Errors> {
+        if *val < 0 {
+            Err(Errors::Object(ObjectErrors::new(
+                vec![],
+                [(
+                    "val".into(),
+                    Errors::NewType(vec![Error::Custom("Negative.".to_owned())]),
+                )]
+                .into_iter()
+                .collect(),
+            )))
+        } else {
+            Ok(())
+        }
+    }
+
+    #[derive(Validate)]
+    enum TestEnum {
+        #[rule(nested_rule(val))]
+        Named { val: i32 },
+    }
+
+    assert_eq!(
+        TestEnum::Named { val: -1 }
+            .validate()
+            .unwrap_err()
+            .to_string(),
+        json!({
+            "errors": [],
+            "properties": {
+                "Named": {
+                    "errors": [],
+                    "properties": {
+                        "val": {"errors": ["Negative."]}
+                    }
+                }
+            }
+        })
+        .to_string()
+    );
+}
+
+#[test]
+fn rule_new_type_is_err() {
+    #[derive(Validate)]
+    #[rule(new_type_err_rule(0))]
+    struct TestNewType(i32);
+
+    fn new_type_err_rule(_val: &i32) -> Result<(), Error> {
+        Err(Error::Custom("Rule error.".to_owned()))
+    }
+
+    assert_eq!(
+        TestNewType(5).validate().unwrap_err().to_string(),
+        json!({"errors": ["Rule error."]}).to_string()
+    );
+}
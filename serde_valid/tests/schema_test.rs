@@ -0,0 +1,421 @@
+use serde::Deserialize;
+use serde_json::json;
+use serde_valid::{Validate, ValidateSchema};
+
+fn memo_validation(_: &str) -> Result<(), serde_valid::validation::Error> {
+    Ok(())
+}
+
+#[test]
+fn schema_named_struct() {
+    #[derive(Deserialize, Validate, ValidateSchema)]
+    struct TestStruct {
+        #[validate(minimum = 0)]
+        #[validate(maximum = 10, message = "too large.")]
+        #[validate(multiple_of = 2)]
+        int_value: i32,
+        #[validate(exclusive_maximum = 1.5)]
+        float_value: f32,
+        #[serde(rename = "userName")]
+        #[validate(min_length = 1)]
+        #[validate(max_length = 8)]
+        #[validate(pattern = r"^[a-z]+$")]
+        name: String,
+        #[validate(format = "email")]
+        email: String,
+        #[validate(enumerate("red", "green"))]
+        color: String,
+        #[validate(min_items = 1)]
+        #[validate(unique_items)]
+        tags: Vec<String>,
+        #[validate(custom(memo_validation))]
+        memo: String,
+        #[validate(max_length = 3, groups(create))]
+        code: String,
+    }
+
+    assert_eq!(
+        TestStruct::json_schema(),
+        json!({
+            "type": "object",
+            "properties": {
+                "int_value": {"minimum": 0, "maximum": 10, "multipleOf": 2},
+                "float_value": {"exclusiveMaximum": 1.5},
+                "userName": {"minLength": 1, "maxLength": 8, "pattern": "^[a-z]+$"},
+                "email": {"format": "email"},
+                "color": {"enum": ["red", "green"]},
+                "tags": {"minItems": 1, "uniqueItems": true}
+            }
+        })
+    );
+}
+
+#[test]
+fn schema_nested_struct() {
+    #[derive(Validate, ValidateSchema)]
+    struct Parent {
+        #[validate]
+        #[validate(max_items = 3)]
+        children: Vec<Child>,
+        #[validate]
+        child: Option<Child>,
+    }
+
+    #[derive(Validate, ValidateSchema)]
+    struct Child {
+        #[validate(max_length = 5)]
+        name: String,
+    }
+
+    let child_schema = json!({
+        "type": "object",
+        "properties": {"name": {"maxLength": 5}}
+    });
+
+    assert_eq!(
+        Parent::json_schema(),
+        json!({
+            "type": "object",
+            "properties": {
+                "children": {"type": "array", "items": child_schema, "maxItems": 3},
+                "child": {"anyOf": [child_schema, {"type": "null"}]}
+            }
+        })
+    );
+}
+
+#[test]
+fn schema_serde_flatten_and_rename_all() {
+    #[derive(Deserialize, Validate, ValidateSchema)]
+    #[serde(rename_all = "camelCase")]
+    struct Parent {
+        #[validate(maximum = 100)]
+        max_age: i32,
+        #[serde(flatten)]
+        #[validate]
+        common: Common,
+    }
+
+    #[derive(Deserialize, Validate, ValidateSchema)]
+    struct Common {
+        #[validate(max_length = 5)]
+        name: String,
+    }
+
+    assert_eq!(
+        Parent::json_schema(),
+        json!({
+            "type": "object",
+            "properties": {
+                "maxAge": {"maximum": 100},
+                "name": {"maxLength": 5}
+            }
+        })
+    );
+}
+
+#[test]
+fn schema_unnamed_struct_and_enum() {
+    #[derive(Validate, ValidateSchema)]
+    struct TestNewType(#[validate(maximum = 10)] u32);
+
+    #[derive(Validate, ValidateSchema)]
+    struct TestTuple(
+        #[validate(maximum = 10)] u32,
+        #[validate(min_length = 1)] String,
+    );
+
+    #[allow(dead_code)]
+    #[derive(Validate, ValidateSchema)]
+    enum TestEnum {
+        Named {
+            #[validate(max_length = 5)]
+            name: String,
+        },
+        Unnamed(#[validate(minimum = 1)] u32),
+        Unit,
+    }
+
+    assert_eq!(TestNewType::json_schema(), json!({"maximum": 10}));
+    assert_eq!(
+        TestTuple::json_schema(),
+        json!({
+            "type": "array",
+            "items": [{"maximum": 10}, {"minLength": 1}]
+        })
+    );
+    assert_eq!(
+        TestEnum::json_schema(),
+        json!({
+            "anyOf": [
+                {
+                    "type": "object",
+                    "properties": {
+                        "Named": {
+                            "type": "object",
+                            "properties": {"name": {"maxLength": 5}}
+                        }
+                    },
+                    "required": ["Named"]
+                },
+                {
+                    "type": "object",
+                    "properties": {"Unnamed": {"minimum": 1}},
+                    "required": ["Unnamed"]
+                },
+                {"enum": ["Unit"]}
+            ]
+        })
+    );
+}
+
+#[test]
+fn schema_externally_tagged_enum_renames() {
+    #[allow(dead_code)]
+    #[derive(Deserialize, Validate, ValidateSchema)]
+    #[serde(rename_all = "snake_case")]
+    enum TestEnum {
+        NamedVariant {
+            #[validate(max_length = 5)]
+            name: String,
+        },
+        #[serde(rename = "unit")]
+        UnitVariant,
+    }
+
+    assert_eq!(
+        TestEnum::json_schema(),
+        json!({
+            "anyOf": [
+                {
+                    "type": "object",
+                    "properties": {
+                        "named_variant": {
+                            "type": "object",
+                            "properties": {"name": {"maxLength": 5}}
+                        }
+                    },
+                    "required": ["named_variant"]
+                },
+                {"enum": ["unit"]}
+            ]
+        })
+    );
+}
+
+#[test]
+fn schema_internally_tagged_enum() {
+    #[derive(Deserialize, Validate, ValidateSchema)]
+    struct Inner {
+        #[validate(minimum = 1)]
+        val: u32,
+    }
+
+    #[allow(dead_code)]
+    #[derive(Deserialize, Validate, ValidateSchema)]
+    #[serde(tag = "type", rename_all = "lowercase")]
+    enum TestEnum {
+        Named {
+            #[validate(max_length = 5)]
+            name: String,
+        },
+        NewType(#[validate] Inner),
+        Unit,
+    }
+
+    assert_eq!(
+        TestEnum::json_schema(),
+        json!({
+            "anyOf": [
+                {
+                    "type": "object",
+                    "properties": {
+                        "type": {"const": "named"},
+                        "name": {"maxLength": 5}
+                    },
+                    "required": ["type"]
+                },
+                {
+                    "allOf": [
+                        {
+                            "type": "object",
+                            "properties": {"type": {"const": "newtype"}},
+                            "required": ["type"]
+                        },
+                        {
+                            "type": "object",
+                            "properties": {"val": {"minimum": 1}}
+                        }
+                    ]
+                },
+                {
+                    "type": "object",
+                    "properties": {"type": {"const": "unit"}},
+                    "required": ["type"]
+                }
+            ]
+        })
+    );
+}
+
+#[test]
+fn schema_adjacently_tagged_enum() {
+    #[allow(dead_code)]
+    #[derive(Deserialize, Validate, ValidateSchema)]
+    #[serde(tag = "t", content = "c")]
+    enum TestEnum {
+        Named {
+            #[validate(max_length = 5)]
+            name: String,
+        },
+        Unnamed(#[validate(minimum = 1)] u32),
+        Unit,
+    }
+
+    assert_eq!(
+        TestEnum::json_schema(),
+        json!({
+            "anyOf": [
+                {
+                    "type": "object",
+                    "properties": {
+                        "t": {"const": "Named"},
+                        "c": {
+                            "type": "object",
+                            "properties": {"name": {"maxLength": 5}}
+                        }
+                    },
+                    "required": ["t", "c"]
+                },
+                {
+                    "type": "object",
+                    "properties": {
+                        "t": {"const": "Unnamed"},
+                        "c": {"minimum": 1}
+                    },
+                    "required": ["t", "c"]
+                },
+                {
+                    "type": "object",
+                    "properties": {"t": {"const": "Unit"}},
+                    "required": ["t"]
+                }
+            ]
+        })
+    );
+}
+
+#[test]
+fn schema_untagged_enum() {
+    #[allow(dead_code)]
+    #[derive(Deserialize, Validate, ValidateSchema)]
+    #[serde(untagged)]
+    enum TestEnum {
+        Named {
+            #[validate(max_length = 5)]
+            name: String,
+        },
+        Unnamed(#[validate(minimum = 1)] u32),
+        Unit,
+    }
+
+    assert_eq!(
+        TestEnum::json_schema(),
+        json!({
+            "anyOf": [
+                {
+                    "type": "object",
+                    "properties": {"name": {"maxLength": 5}}
+                },
+                {"minimum": 1},
+                {"type": "null"}
+            ]
+        })
+    );
+}
+
+#[test]
+fn schema_round_trip_runtime_validation() {
+    use std::collections::BTreeMap;
+
+    use serde_valid::schema::Schema;
+
+    #[derive(Deserialize, Validate, ValidateSchema)]
+    struct TestStruct {
+        #[validate(any_of(pattern = r"^\d+$", enumerate("auto", "none")))]
+        size: String,
+        #[validate(one_of(maximum = 10, minimum = 5))]
+        level: u32,
+        #[validate(not(enumerate("admin", "root")))]
+        user: String,
+        #[validate(min_contains(2, "x"))]
+        #[validate(max_contains(3, "x"))]
+        tags: Vec<String>,
+        #[validate(property_names(pattern = "^[a-z_-]+$"))]
+        #[validate(pattern_properties("^x-", max_length = 3))]
+        labels: BTreeMap<String, String>,
+        #[validate]
+        mode: TestEnum,
+    }
+
+    #[allow(dead_code)]
+    #[derive(Deserialize, Validate, ValidateSchema)]
+    #[serde(tag = "type", rename_all = "snake_case")]
+    enum TestEnum {
+        Named {
+            #[validate(max_length = 5)]
+            name: String,
+        },
+        Unit,
+    }
+
+    let schema = Schema::new(&TestStruct::json_schema()).unwrap();
+
+    assert!(schema
+        .validate(&json!({
+            "size": "auto",
+            "level": 3,
+            "user": "alice",
+            "tags": ["x", "y", "x"],
+            "labels": {"x-a": "abc", "name": "long value"},
+            "mode": {"type": "named", "name": "short"}
+        }))
+        .is_ok());
+    assert_eq!(
+        schema
+            .validate(&json!({
+                "size": "big",
+                "level": 7,
+                "user": "root",
+                "tags": ["x", "y"],
+                "labels": {"X-a": "abc", "x-b": "abcd"},
+                "mode": {"type": "named", "name": "too long"}
+            }))
+            .unwrap_err()
+            .to_string(),
+        json!({
+            "errors": [],
+            "properties": {
+                "size": {"errors": [
+                    "The value must match any of the validations: The value must match the pattern of \"^\\d+$\". / The value must be in [auto, none]."
+                ]},
+                "level": {"errors": [
+                    "The value must match exactly one of the validations, but 2 matched."
+                ]},
+                "user": {"errors": ["The value must not match the validation."]},
+                "tags": {"errors": ["The number of matching items must be `>= 2`."], "items": {}},
+                "labels": {
+                    "errors": [],
+                    "properties": {
+                        "X-a": {"errors": ["The value must match the pattern of \"^[a-z_-]+$\"."]},
+                        "x-b": {"errors": ["The length of the value must be `<= 3`."]}
+                    }
+                },
+                "mode": {"errors": [
+                    "The value must match any of the validations: {\"errors\":[],\"properties\":{\"name\":{\"errors\":[\"The length of the value must be `<= 5`.\"]}}} / {\"errors\":[],\"properties\":{\"type\":{\"errors\":[\"The value must be in [unit].\"]}}}"
+                ], "properties": {}}
+            }
+        })
+        .to_string()
+    );
+}
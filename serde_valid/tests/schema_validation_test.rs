@@ -0,0 +1,239 @@
+use serde_json::json;
+use serde_valid::schema::Schema;
+
+#[test]
+fn schema_validation_is_ok() {
+    let schema = Schema::new(&json!({
+        "type": "object",
+        "properties": {
+            "name": {"type": "string", "maxLength": 8, "pattern": "^[a-z]+$"},
+            "port": {"type": "integer", "minimum": 1, "maximum": 65535},
+            "tags": {"type": "array", "items": {"enum": ["a", "b"]}, "uniqueItems": true}
+        },
+        "required": ["name"]
+    }))
+    .unwrap();
+
+    assert!(schema
+        .validate(&json!({"name": "plugin", "port": 8080, "tags": ["a", "b"]}))
+        .is_ok());
+}
+
+#[test]
+fn schema_validation_object_errors() {
+    let schema = Schema::new(&json!({
+        "type": "object",
+        "properties": {
+            "name": {"type": "string", "maxLength": 4, "pattern": "^[a-z]+$"},
+            "port": {"type": "integer", "exclusiveMaximum": 1024},
+            "mode": {"enum": ["fast", "safe"]}
+        },
+        "required": ["mode", "name"],
+        "maxProperties": 2
+    }))
+    .unwrap();
+
+    assert_eq!(
+        schema
+            .validate(&json!({"name": "Plugin", "port": 8080}))
+            .unwrap_err()
+            .to_string(),
+        json!({
+            "errors": [],
+            "properties": {
+                "mode": {"errors": ["The property is required."]},
+                "name": {
+                    "errors": [
+                        "The length of the value must be `<= 4`.",
+                        "The value must match the pattern of \"^[a-z]+$\"."
+                    ]
+                },
+                "port": {"errors": ["The number must be `< 1024`."]}
+            }
+        })
+        .to_string()
+    );
+}
+
+#[test]
+fn schema_validation_array_errors() {
+    let schema = Schema::new(&json!({
+        "type": "array",
+        "items": {"type": "number", "multipleOf": 5},
+        "maxItems": 2,
+        "uniqueItems": true
+    }))
+    .unwrap();
+
+    assert_eq!(
+        schema
+            .validate(&json!([5, 5, "10"]))
+            .unwrap_err()
+            .to_string(),
+        json!({
+            "errors": [
+                "The length of the items must be `<= 2`.",
+                "The items must be unique."
+            ],
+            "items": {
+                "2": {"errors": ["The value must be of type `number`."]}
+            }
+        })
+        .to_string()
+    );
+}
+
+#[test]
+fn schema_validation_new_type_errors() {
+    let schema =
+        Schema::new(&json!({"type": "string", "format": "email", "minLength": 6})).unwrap();
+
+    assert_eq!(
+        schema.validate(&json!("taro")).unwrap_err().to_string(),
+        json!({
+            "errors": [
+                "The length of the value must be `>= 6`.",
+                "The value must match the format of \"email\"."
+            ]
+        })
+        .to_string()
+    );
+}
+
+#[test]
+fn schema_validation_additional_properties() {
+    let schema = Schema::new(&json!({
+        "properties": {"name": {"type": "string"}},
+        "additionalProperties": false
+    }))
+    .unwrap();
+
+    assert_eq!(
+        schema
+            .validate(&json!({"name": "taro", "age": 20}))
+            .unwrap_err()
+            .to_string(),
+        json!({
+            "errors": ["The property `age` is not allowed."],
+            "properties": {}
+        })
+        .to_string()
+    );
+}
+
+#[test]
+fn schema_validation_additional_properties_schema() {
+    let schema = Schema::new(&json!({
+        "properties": {"name": {"type": "string"}},
+        "additionalProperties": {"type": "integer", "maximum": 60}
+    }))
+    .unwrap();
+
+    assert!(schema
+        .validate(&json!({"name": "taro", "timeout": 30}))
+        .is_ok());
+    assert_eq!(
+        schema
+            .validate(&json!({"name": "taro", "retry": "3", "timeout": 90}))
+            .unwrap_err()
+            .to_string(),
+        json!({
+            "errors": [],
+            "properties": {
+                "retry": {"errors": ["The value must be of type `integer`."]},
+                "timeout": {"errors": ["The number must be `<= 60`."]}
+            }
+        })
+        .to_string()
+    );
+}
+
+#[cfg(feature = "flatten")]
+#[test]
+fn schema_validation_into_flat() {
+    use serde_valid::flatten::IntoFlat;
+
+    let schema = Schema::new(&json!({
+        "properties": {
+            "servers": {
+                "items": {
+                    "properties": {"port": {"maximum": 65535}}
+                }
+            }
+        }
+    }))
+    .unwrap();
+
+    assert_eq!(
+        serde_json::to_value(
+            schema
+                .validate(&json!({"servers": [{"port": 80}, {"port": 70000}]}))
+                .unwrap_err()
+                .into_flat()
+        )
+        .unwrap(),
+        json!({
+            "errors": [{
+                "error": "The number must be `<= 65535`.",
+                "code": "maximum",
+                "params": {"maximum": 65535},
+                "instance_location": "/servers/1/port"
+            }]
+        })
+    );
+}
+
+#[cfg(feature = "flatten")]
+#[test]
+fn schema_validation_error_codes() {
+    use serde_valid::flatten::IntoFlat;
+
+    let schema = Schema::new(&json!({
+        "type": "object",
+        "properties": {
+            "name": {"type": ["string", "null"]},
+            "legacy": false
+        },
+        "required": ["id"],
+        "additionalProperties": false
+    }))
+    .unwrap();
+
+    assert_eq!(
+        serde_json::to_value(
+            schema
+                .validate(&json!({"name": 1, "legacy": true, "extra": 0}))
+                .unwrap_err()
+                .into_flat()
+        )
+        .unwrap(),
+        json!({
+            "errors": [
+                {
+                    "error": "The property `extra` is not allowed.",
+                    "code": "additional_properties",
+                    "params": {"additional_properties": "extra"},
+                    "instance_location": ""
+                },
+                {
+                    "error": "The value is not allowed.",
+                    "code": "false_schema",
+                    "params": {},
+                    "instance_location": "/legacy"
+                },
+                {
+                    "error": "The value must be of type `string` or `null`.",
+                    "code": "type",
+                    "params": {"types": ["string", "null"]},
+                    "instance_location": "/name"
+                },
+                {
+                    "error": "The property is required.",
+                    "code": "required",
+                    "params": {},
+                    "instance_location": "/id"
+                }
+            ]
+        })
+    );
+}
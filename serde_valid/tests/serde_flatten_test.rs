@@ -0,0 +1,146 @@
+use std::collections::HashMap;
+
+use serde::Deserialize;
+use serde_json::json;
+use serde_valid::json::FromJsonValue;
+use serde_valid::Validate;
+
+#[derive(Debug, Validate, Deserialize)]
+#[rule(name_rule(first_name, last_name))]
+struct CommonFields {
+    #[validate(max_length = 4)]
+    first_name: String,
+    #[validate(max_length = 8)]
+    last_name: String,
+}
+
+fn name_rule(first_name: &str, last_name: &str) -> Result<(), serde_valid::validation::Error> {
+    if first_name == last_name {
+        Err(serde_valid::validation::Error::Custom(
+            "The names must be different.".to_owned(),
+        ))
+    } else {
+        Ok(())
+    }
+}
+
+#[test]
+fn serde_flatten_is_ok() {
+    #[derive(Debug, Validate, Deserialize)]
+    struct TestStruct {
+        #[validate(maximum = 100)]
+        age: i32,
+        #[serde(flatten)]
+        #[validate]
+        common: CommonFields,
+    }
+
+    let s = TestStruct::from_json_value(json!({
+        "age": 20,
+        "first_name": "taro",
+        "last_name": "yamada"
+    }));
+
+    assert!(s.is_ok());
+}
+
+#[test]
+fn serde_flatten_is_err() {
+    #[derive(Debug, Validate, Deserialize)]
+    struct TestStruct {
+        #[validate(maximum = 100)]
+        age: i32,
+        #[serde(flatten)]
+        #[validate]
+        common: CommonFields,
+    }
+
+    let err = TestStruct::from_json_value(json!({
+        "age": 123,
+        "first_name": "yamada",
+        "last_name": "yamada"
+    }))
+    .unwrap_err();
+
+    assert_eq!(
+        serde_json::from_str::<serde_json::Value>(&err.to_string()).unwrap(),
+        json!({
+            "errors": ["The names must be different."],
+            "properties": {
+                "age": {"errors": ["The number must be `<= 100`."]},
+                "first_name": {"errors": ["The length of the value must be `<= 4`."]}
+            }
+        })
+    );
+}
+
+#[test]
+fn serde_flatten_map_is_err() {
+    #[derive(Debug, Validate, Deserialize)]
+    struct Limit {
+        #[validate(maximum = 60)]
+        timeout: u32,
+    }
+
+    #[derive(Debug, Validate, Deserialize)]
+    struct TestStruct {
+        #[validate(min_length = 1)]
+        name: String,
+        #[serde(flatten)]
+        #[validate]
+        limits: HashMap<String, Limit>,
+    }
+
+    let err = TestStruct::from_json_value(json!({
+        "name": "plugin",
+        "read": {"timeout": 30},
+        "write": {"timeout": 90}
+    }))
+    .unwrap_err();
+
+    assert_eq!(
+        serde_json::from_str::<serde_json::Value>(&err.to_string()).unwrap(),
+        json!({
+            "errors": [],
+            "properties": {
+                "write": {
+                    "errors": [],
+                    "properties": {
+                        "timeout": {"errors": ["The number must be `<= 60`."]}
+                    }
+                }
+            }
+        })
+    );
+}
+
+#[cfg(feature = "flatten")]
+#[test]
+fn serde_flatten_into_flat() {
+    use serde_valid::flatten::IntoFlat;
+
+    #[derive(Debug, Validate, Deserialize)]
+    struct TestStruct {
+        #[serde(flatten)]
+        #[validate]
+        common: CommonFields,
+    }
+
+    let err = TestStruct::from_json_value(json!({
+        "first_name": "taro",
+        "last_name": "yamada taro"
+    }))
+    .unwrap_err();
+
+    assert_eq!(
+        serde_json::to_value(err.as_validation_errors().unwrap().clone().into_flat()).unwrap(),
+        json!({
+            "errors": [{
+                "error": "The length of the value must be `<= 8`.",
+                "code": "max_length",
+                "params": {"max_length": 8},
+                "instance_location": "/last_name"
+            }]
+        })
+    );
+}
@@ -115,10 +115,156 @@ fn serde_rename_enume_is_err() {
         json!({
             "errors": [],
             "properties": {
-                "value": {
+                "Struct": {
+                    "errors": [],
+                    "properties": {
+                        "value": {
+                            "errors": ["The number must be `<= 100`."]
+                        }
+                    }
+                }
+            }
+        })
+    );
+}
+
+#[test]
+fn serde_rename_all_is_err() {
+    #[derive(Debug, Validate, Deserialize)]
+    #[serde(rename_all = "camelCase")]
+    struct TestStruct {
+        #[validate(max_length = 4)]
+        user_name: String,
+        #[validate(maximum = 100)]
+        #[serde(rename = "age")]
+        user_age: i32,
+    }
+
+    let err =
+        TestStruct::from_json_value(json!({ "userName": "taro yamada", "age": 123 })).unwrap_err();
+
+    assert_eq!(
+        serde_json::from_str::<serde_json::Value>(&err.to_string()).unwrap(),
+        json!({
+            "errors": [],
+            "properties": {
+                "userName": {
+                    "errors": ["The length of the value must be `<= 4`."]
+                },
+                "age": {
                     "errors": ["The number must be `<= 100`."]
                 }
             }
         })
     );
 }
+
+#[test]
+fn serde_rename_all_deserialize_is_err() {
+    #[derive(Debug, Validate, Deserialize)]
+    #[serde(rename_all(serialize = "snake_case", deserialize = "kebab-case"))]
+    struct TestStruct {
+        #[validate(maximum = 100)]
+        max_retry_count: i32,
+    }
+
+    let err = TestStruct::from_json_value(json!({ "max-retry-count": 123 })).unwrap_err();
+
+    assert_eq!(
+        serde_json::from_str::<serde_json::Value>(&err.to_string()).unwrap(),
+        json!({
+            "errors": [],
+            "properties": {
+                "max-retry-count": {
+                    "errors": ["The number must be `<= 100`."]
+                }
+            }
+        })
+    );
+}
+
+#[test]
+fn serde_rename_all_casings() {
+    macro_rules! assert_rename_all {
+        ($rule:literal, $key:literal) => {{
+            #[derive(Debug, Validate, Deserialize)]
+            #[serde(rename_all = $rule)]
+            struct TestStruct {
+                #[validate(maximum = 100)]
+                max_retry_count: i32,
+            }
+
+            let err = TestStruct::from_json_value(json!({ $key: 123 })).unwrap_err();
+
+            assert_eq!(
+                serde_json::from_str::<serde_json::Value>(&err.to_string()).unwrap(),
+                json!({
+                    "errors": [],
+                    "properties": {
+                        $key: {"errors": ["The number must be `<= 100`."]}
+                    }
+                })
+            );
+        }};
+    }
+
+    assert_rename_all!("lowercase", "max_retry_count");
+    assert_rename_all!("UPPERCASE", "MAX_RETRY_COUNT");
+    assert_rename_all!("PascalCase", "MaxRetryCount");
+    assert_rename_all!("camelCase", "maxRetryCount");
+    assert_rename_all!("snake_case", "max_retry_count");
+    assert_rename_all!("SCREAMING_SNAKE_CASE", "MAX_RETRY_COUNT");
+    assert_rename_all!("kebab-case", "max-retry-count");
+    assert_rename_all!("SCREAMING-KEBAB-CASE", "MAX-RETRY-COUNT");
+}
+
+#[test]
+fn serde_rename_all_enum_variant_is_err() {
+    #[derive(Debug, Validate, Deserialize)]
+    #[serde(rename_all_fields = "kebab-case")]
+    enum TestEnum {
+        #[serde(rename_all = "camelCase")]
+        Camel {
+            #[validate(maximum = 100)]
+            retry_count: i32,
+        },
+        Kebab {
+            #[validate(maximum = 100)]
+            retry_count: i32,
+        },
+    }
+
+    let err = TestEnum::from_json_value(json!({ "Camel": { "retryCount": 123 } })).unwrap_err();
+
+    assert_eq!(
+        serde_json::from_str::<serde_json::Value>(&err.to_string()).unwrap(),
+        json!({
+            "errors": [],
+            "properties": {
+                "Camel": {
+                    "errors": [],
+                    "properties": {
+                        "retryCount": {"errors": ["The number must be `<= 100`."]}
+                    }
+                }
+            }
+        })
+    );
+
+    let err = TestEnum::from_json_value(json!({ "Kebab": { "retry-count": 123 } })).unwrap_err();
+
+    assert_eq!(
+        serde_json::from_str::<serde_json::Value>(&err.to_string()).unwrap(),
+        json!({
+            "errors": [],
+            "properties": {
+                "Kebab": {
+                    "errors": [],
+                    "properties": {
+                        "retry-count": {"errors": ["The number must be `<= 100`."]}
+                    }
+                }
+            }
+        })
+    );
+}
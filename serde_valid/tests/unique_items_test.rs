@@ -126,3 +126,174 @@ fn unique_items_custom_err_message() {
         .to_string()
     );
 }
+
+#[test]
+fn unique_items_partial_eq_type() {
+    #[derive(PartialEq)]
+    struct Point {
+        x: f64,
+        y: f64,
+    }
+
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(unique_items)]
+        floats: Vec<f64>,
+        #[validate(unique_items)]
+        points: Vec<Point>,
+    }
+
+    let s = TestStruct {
+        floats: vec![0.5, 1.5],
+        points: vec![Point { x: 0.0, y: 1.0 }, Point { x: 1.0, y: 0.0 }],
+    };
+    assert!(s.validate().is_ok());
+
+    let s = TestStruct {
+        floats: vec![0.5, 0.5],
+        points: vec![Point { x: 0.0, y: 1.0 }, Point { x: 0.0, y: 1.0 }],
+    };
+    assert!(s.validate().is_err());
+}
+
+#[test]
+fn unique_items_hashes_hashable_type() {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    static HASHES: AtomicUsize = AtomicUsize::new(0);
+
+    #[derive(PartialEq, Eq)]
+    struct Tag(u32);
+
+    impl std::hash::Hash for Tag {
+        fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+            HASHES.fetch_add(1, Ordering::Relaxed);
+            self.0.hash(state);
+        }
+    }
+
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(unique_items)]
+        tags: Option<Vec<Tag>>,
+    }
+
+    let s = TestStruct {
+        tags: Some(vec![Tag(1), Tag(2), Tag(1)]),
+    };
+    assert!(s.validate().is_err());
+    assert_eq!(HASHES.load(Ordering::Relaxed), 3);
+}
+
+#[test]
+fn unique_items_generic_partial_eq_type() {
+    #[derive(Validate)]
+    struct TestStruct<T: PartialEq> {
+        #[validate(unique_items)]
+        val: Vec<T>,
+    }
+
+    assert!(TestStruct {
+        val: vec![0.5, 1.5]
+    }
+    .validate()
+    .is_ok());
+    assert!(TestStruct {
+        val: vec![0.5, 0.5]
+    }
+    .validate()
+    .is_err());
+}
+
+#[test]
+fn unique_items_by_is_ok() {
+    struct User {
+        id: u32,
+    }
+
+    fn user_id(user: &User) -> u32 {
+        user.id
+    }
+
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(unique_items_by = user_id)]
+        val: Option<Vec<User>>,
+    }
+
+    let s = TestStruct {
+        val: Some(vec![User { id: 1 }, User { id: 2 }]),
+    };
+    assert!(s.validate().is_ok());
+    assert!(TestStruct { val: None }.validate().is_ok());
+}
+
+#[test]
+fn unique_items_by_err_message() {
+    struct User {
+        id: u32,
+    }
+
+    fn user_id(user: &User) -> u32 {
+        user.id
+    }
+
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(
+            unique_items_by = user_id,
+            message = "duplicated users at [{duplicates}]."
+        )]
+        val: Vec<User>,
+    }
+
+    let s = TestStruct {
+        val: vec![
+            User { id: 1 },
+            User { id: 2 },
+            User { id: 1 },
+            User { id: 2 },
+        ],
+    };
+
+    assert_eq!(
+        s.validate().unwrap_err().to_string(),
+        json!({
+            "errors": [],
+            "properties": {
+                "val": {
+                    "errors": ["duplicated users at [2, 3]."]
+                }
+            }
+        })
+        .to_string()
+    );
+}
+
+#[cfg(feature = "flatten")]
+#[test]
+fn unique_items_in_flat_path() {
+    use serde_valid::flatten::IntoFlat;
+
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(unique_items)]
+        items: Vec<i32>,
+    }
+
+    let s = TestStruct {
+        items: vec![1, 2, 3, 2],
+    };
+
+    assert_eq!(
+        serde_json::to_value(s.validate().unwrap_err().into_flat()).unwrap(),
+        json!({
+            "errors": [{
+                "error": "The items must be unique.",
+                "code": "unique_items",
+                "params": {"duplicates": [3]},
+                "instance_location": "/items/3"
+            }]
+        })
+    );
+}
@@ -0,0 +1,83 @@
+use syn::ext::IdentExt;
+use syn::parse::{Parse, ParseStream};
+use syn::parse_quote;
+
+use crate::types::SingleIdentPath;
+
+/// Container attribute `#[validate(context = Type)]`.
+struct ContextAttribute {
+    path: syn::Path,
+    ty: syn::Type,
+}
+
+impl Parse for ContextAttribute {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let path = input.parse()?;
+        input.parse::<syn::Token![=]>()?;
+        let ty = input.parse()?;
+
+        Ok(Self { path, ty })
+    }
+}
+
+/// Collect the context type declared on the container.
+pub fn collect_context_type(
+    attributes: &[syn::Attribute],
+) -> Result<Option<syn::Type>, crate::Errors> {
+    let mut errors = vec![];
+    let mut context = None;
+
+    for attribute in attributes.iter().filter(|attribute| {
+        attribute.path == parse_quote!(validate) || attribute.path == parse_quote!(serde_valid)
+    }) {
+        match attribute.parse_args_with(ContextAttribute::parse) {
+            Ok(ContextAttribute { path, ty }) => {
+                if !path.is_ident("context") {
+                    errors.push(crate::Error::validate_unknown_type(
+                        &path,
+                        &SingleIdentPath::new(&path).ident().to_string(),
+                        &["context"],
+                    ));
+                } else if context.is_some() {
+                    errors.push(crate::Error::validate_context_duplicated(attribute));
+                } else {
+                    context = Some(ty);
+                }
+            }
+            Err(error) => errors.push(crate::Error::validate_attribute_parse_error(
+                attribute, &error,
+            )),
+        }
+    }
+
+    if errors.is_empty() {
+        Ok(context)
+    } else {
+        Err(errors)
+    }
+}
+
+/// Reject the fields named `ctx`, which would hide the context in the validation values.
+pub fn check_context_field_names(input: &syn::DeriveInput) -> Result<(), crate::Errors> {
+    let fields: Vec<&syn::Field> = match &input.data {
+        syn::Data::Struct(syn::DataStruct { fields, .. }) => fields.iter().collect(),
+        syn::Data::Enum(syn::DataEnum { variants, .. }) => variants
+            .iter()
+            .flat_map(|variant| variant.fields.iter())
+            .collect(),
+        syn::Data::Union(_) => vec![],
+    };
+
+    let errors = fields
+        .into_iter()
+        .filter_map(|field| field.ident.as_ref())
+        .filter(|ident| ident.unraw() == "ctx")
+        .map(crate::Error::validate_context_shadowed_by_field)
+        .collect::<Vec<_>>();
+
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(errors)
+    }
+}
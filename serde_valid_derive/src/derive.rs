@@ -1,21 +1,119 @@
+mod async_derive;
 mod enum_derive;
 mod named_struct_derive;
+mod schema_derive;
 mod unnamed_struct_derive;
 
+use crate::context::{check_context_field_names, collect_context_type};
+pub use async_derive::expand_async_derive;
 use enum_derive::expand_enum_validate_derive;
 use named_struct_derive::expand_named_struct_derive;
 use proc_macro2::TokenStream;
+use quote::quote;
+pub use schema_derive::expand_schema_derive;
 use unnamed_struct_derive::expand_unnamed_struct_derive;
 
+/// Implement [`Validate`](::serde_valid::Validate) sharing the body
+/// between `validate` and `validate_group`.
+///
+/// The body sees the active group as `__group: Option<&str>`.
+///
+/// With `#[validate(context = Type)]`, [`ValidateWith<Type>`](::serde_valid::ValidateWith)
+/// is implemented instead and the body also sees the context as `ctx: &Type`.
+/// Otherwise `ValidateWith` is implemented for any context by ignoring it.
+fn validate_impl_tokens(
+    input: &syn::DeriveInput,
+    body: TokenStream,
+    context: Option<&syn::Type>,
+) -> TokenStream {
+    let ident = &input.ident;
+    let (impl_generics, type_generics, where_clause) = input.generics.split_for_impl();
+
+    if let Some(context) = context {
+        return quote!(
+            impl #impl_generics ::serde_valid::ValidateWith<#context> for #ident #type_generics #where_clause {
+                fn validate_with(
+                    &self,
+                    ctx: &#context,
+                ) -> std::result::Result<(), ::serde_valid::validation::Errors> {
+                    let _ = ctx;
+                    let __group: Option<&str> = None;
+
+                    #body
+                }
+
+                fn validate_group_with(
+                    &self,
+                    group: &str,
+                    ctx: &#context,
+                ) -> std::result::Result<(), ::serde_valid::validation::Errors> {
+                    let _ = ctx;
+                    let __group: Option<&str> = Some(group);
+
+                    #body
+                }
+            }
+        );
+    }
+
+    let mut with_generics = input.generics.clone();
+    with_generics
+        .params
+        .push(syn::parse_quote!(__Ctx: ?::std::marker::Sized));
+    let (with_impl_generics, _, _) = with_generics.split_for_impl();
+
+    quote!(
+        impl #impl_generics ::serde_valid::Validate for #ident #type_generics #where_clause {
+            fn validate(&self) -> std::result::Result<(), ::serde_valid::validation::Errors> {
+                let __group: Option<&str> = None;
+
+                #body
+            }
+
+            fn validate_group(
+                &self,
+                group: &str,
+            ) -> std::result::Result<(), ::serde_valid::validation::Errors> {
+                let __group: Option<&str> = Some(group);
+
+                #body
+            }
+        }
+
+        impl #with_impl_generics ::serde_valid::ValidateWith<__Ctx> for #ident #type_generics #where_clause {
+            fn validate_with(
+                &self,
+                _: &__Ctx,
+            ) -> std::result::Result<(), ::serde_valid::validation::Errors> {
+                ::serde_valid::Validate::validate(self)
+            }
+
+            fn validate_group_with(
+                &self,
+                group: &str,
+                _: &__Ctx,
+            ) -> std::result::Result<(), ::serde_valid::validation::Errors> {
+                ::serde_valid::Validate::validate_group(self, group)
+            }
+        }
+    )
+}
+
 pub fn expand_derive(input: &syn::DeriveInput) -> Result<TokenStream, crate::Errors> {
+    let context = collect_context_type(&input.attrs)?;
+    let context = context.as_ref();
+    if context.is_some() {
+        check_context_field_names(input)?;
+    }
+
     match &input.data {
         syn::Data::Struct(syn::DataStruct { ref fields, .. }) => match fields {
-            syn::Fields::Named(fields) => expand_named_struct_derive(input, fields),
-            syn::Fields::Unnamed(fields) => expand_unnamed_struct_derive(input, fields),
+            syn::Fields::Named(fields) => expand_named_struct_derive(input, fields, context),
+            syn::Fields::Unnamed(fields) => expand_unnamed_struct_derive(input, fields, context),
             syn::Fields::Unit => Err(vec![crate::Error::unit_struct_not_support(input)]),
         },
         syn::Data::Enum(syn::DataEnum { variants, .. }) => {
-            expand_enum_validate_derive(input, variants)
+            expand_enum_validate_derive(input, variants, context)
         }
         syn::Data::Union(_) => Err(vec![crate::Error::union_not_support(input)]),
     }
@@ -0,0 +1,272 @@
+use crate::context::collect_context_type;
+use crate::error::{
+    array_errors_tokens, nested_errors_tokens, new_type_errors_tokens, object_errors_tokens,
+};
+use crate::rule::{collect_async_rules_from_named_struct, collect_async_rules_from_unnamed_struct};
+use crate::serde::rename::{
+    collect_serde_rename_map, find_serde_rename_all, find_serde_variant_rename_all, RenameMap,
+    RenameRule,
+};
+use crate::serde::representation::EnumRepresentation;
+use crate::types::{Field, NamedField, UnnamedField};
+use crate::validate::{
+    extract_async_meta_validator, extract_skip_if, is_skip_if, wrap_validators_with_skip_if,
+};
+use proc_macro2::TokenStream;
+use quote::quote;
+use std::collections::HashSet;
+use syn::parse_quote;
+
+pub fn expand_async_derive(input: &syn::DeriveInput) -> Result<TokenStream, crate::Errors> {
+    if collect_context_type(&input.attrs)?.is_some() {
+        return Err(vec![crate::Error::async_validate_context_not_support(
+            input,
+        )]);
+    }
+
+    let body = match &input.data {
+        syn::Data::Struct(syn::DataStruct { ref fields, .. }) => match fields {
+            syn::Fields::Named(fields) => {
+                let (pattern, body) = expand_async_named_fields(
+                    &input.attrs,
+                    fields,
+                    find_serde_rename_all(&input.attrs),
+                    None,
+                )?;
+                quote!(
+                    let Self { #pattern .. } = self;
+                    #body
+                )
+            }
+            syn::Fields::Unnamed(fields) => {
+                let (pattern, body) = expand_async_unnamed_fields(&input.attrs, fields, None)?;
+                quote!(
+                    let Self(#pattern) = self;
+                    #body
+                )
+            }
+            syn::Fields::Unit => Err(vec![crate::Error::unit_struct_not_support(input)])?,
+        },
+        syn::Data::Enum(syn::DataEnum { variants, .. }) => {
+            let representation = EnumRepresentation::from_attributes(&input.attrs);
+            let mut errors = vec![];
+            let arms = TokenStream::from_iter(variants.iter().map(|variant| {
+                let variant_ident = &variant.ident;
+                let variant_errors_key = representation.variant_errors_key(&input.attrs, variant);
+                let arm = match &variant.fields {
+                    syn::Fields::Named(fields) => expand_async_named_fields(
+                        &variant.attrs,
+                        fields,
+                        find_serde_variant_rename_all(&input.attrs, &variant.attrs),
+                        variant_errors_key.as_deref(),
+                    )
+                    .map(
+                        |(pattern, body)| quote!(Self::#variant_ident { #pattern .. } => { #body }),
+                    ),
+                    syn::Fields::Unnamed(fields) => expand_async_unnamed_fields(
+                        &variant.attrs,
+                        fields,
+                        variant_errors_key.as_deref(),
+                    )
+                    .map(|(pattern, body)| quote!(Self::#variant_ident(#pattern) => { #body })),
+                    syn::Fields::Unit => Ok(quote!(Self::#variant_ident => Ok(()),)),
+                };
+                arm.unwrap_or_else(|variant_errors| {
+                    errors.extend(variant_errors);
+                    quote!()
+                })
+            }));
+            if !errors.is_empty() {
+                return Err(errors);
+            }
+            quote!(
+                match self {
+                    #arms
+                }
+            )
+        }
+        syn::Data::Union(_) => Err(vec![crate::Error::union_not_support(input)])?,
+    };
+
+    let ident = &input.ident;
+    let (impl_generics, type_generics, where_clause) = input.generics.split_for_impl();
+
+    Ok(quote!(
+        impl #impl_generics ::serde_valid::AsyncValidate for #ident #type_generics #where_clause {
+            fn validate_async_only(
+                &self,
+            ) -> impl ::std::future::Future<
+                Output = std::result::Result<(), ::serde_valid::validation::Errors>,
+            > + ::std::marker::Send {
+                async move {
+                    #body
+                }
+            }
+        }
+    ))
+}
+
+/// Binding pattern of the used fields and the validation body.
+///
+/// The errors are nested under `errors_key` when given, as for enum variants.
+fn expand_async_named_fields(
+    attributes: &[syn::Attribute],
+    fields: &syn::FieldsNamed,
+    rename_all: Option<RenameRule>,
+    errors_key: Option<&str>,
+) -> Result<(TokenStream, TokenStream), crate::Errors> {
+    let mut errors = vec![];
+    let rename_map = collect_serde_rename_map(fields, rename_all);
+
+    let (rule_fields, rules) = match collect_async_rules_from_named_struct(attributes, &rename_map)
+    {
+        Ok(field_rules) => field_rules,
+        Err(rule_errors) => {
+            errors.extend(rule_errors);
+            (HashSet::new(), quote!())
+        }
+    };
+
+    let mut pattern = TokenStream::new();
+    let mut validates = TokenStream::new();
+    for field in fields.named.iter() {
+        let named_field = NamedField::new(field);
+        match collect_async_field_validators(&named_field, &rename_map) {
+            Ok(validators) => {
+                let field_ident = named_field.ident();
+                if !validators.is_empty() || rule_fields.contains(field_ident) {
+                    pattern.extend(quote!(#field_ident,));
+                }
+                validates.extend(validators);
+            }
+            Err(field_errors) => errors.extend(field_errors),
+        }
+    }
+
+    if !errors.is_empty() {
+        return Err(errors);
+    }
+
+    let fields_errors = nested_errors_tokens(errors_key, object_errors_tokens());
+
+    Ok((
+        pattern,
+        quote!(
+            let mut __rule_vec_errors = ::serde_valid::validation::VecErrors::new();
+            let mut __property_vec_errors_map = ::serde_valid::validation::PropertyVecErrorsMap::new();
+
+            #validates
+            #rules
+
+            if __rule_vec_errors.is_empty() && __property_vec_errors_map.is_empty() {
+                Ok(())
+            } else {
+                Err(#fields_errors)
+            }
+        ),
+    ))
+}
+
+/// Binding pattern of the used fields and the validation body.
+///
+/// The errors are nested under `errors_key` when given, as for enum variants.
+fn expand_async_unnamed_fields(
+    attributes: &[syn::Attribute],
+    fields: &syn::FieldsUnnamed,
+    errors_key: Option<&str>,
+) -> Result<(TokenStream, TokenStream), crate::Errors> {
+    let mut errors = vec![];
+
+    let (rule_fields, rules) = match collect_async_rules_from_unnamed_struct(attributes) {
+        Ok(field_rules) => field_rules,
+        Err(rule_errors) => {
+            errors.extend(rule_errors);
+            (HashSet::new(), quote!())
+        }
+    };
+
+    let mut pattern = TokenStream::new();
+    let mut validates = TokenStream::new();
+    for (index, field) in fields.unnamed.iter().enumerate() {
+        let unnamed_field = UnnamedField::new(index, field);
+        match collect_async_field_validators(&unnamed_field, &RenameMap::new()) {
+            Ok(validators) => {
+                let field_ident = unnamed_field.ident();
+                if !validators.is_empty() || rule_fields.contains(field_ident) {
+                    pattern.extend(quote!(#field_ident,));
+                } else {
+                    pattern.extend(quote!(_,));
+                }
+                validates.extend(validators);
+            }
+            Err(field_errors) => errors.extend(field_errors),
+        }
+    }
+
+    if !errors.is_empty() {
+        return Err(errors);
+    }
+
+    let fields_errors = nested_errors_tokens(
+        errors_key,
+        if fields.unnamed.len() != 1 {
+            array_errors_tokens()
+        } else {
+            new_type_errors_tokens()
+        },
+    );
+
+    Ok((
+        pattern,
+        quote!(
+            let mut __rule_vec_errors = ::serde_valid::validation::VecErrors::new();
+            let mut __item_vec_errors_map = ::serde_valid::validation::ItemVecErrorsMap::new();
+
+            #validates
+            #rules
+
+            if __rule_vec_errors.is_empty() && __item_vec_errors_map.is_empty() {
+                Ok(())
+            } else {
+                Err(#fields_errors)
+            }
+        ),
+    ))
+}
+
+fn collect_async_field_validators(
+    field: &impl Field,
+    rename_map: &RenameMap,
+) -> Result<TokenStream, crate::Errors> {
+    let mut errors = vec![];
+    let skip_if = extract_skip_if(field.attrs()).unwrap_or_else(|skip_if_errors| {
+        errors.extend(skip_if_errors);
+        None
+    });
+
+    let validators = field.attrs().iter().filter_map(|attribute| {
+        if (attribute.path == parse_quote!(validate) || attribute.path == parse_quote!(serde_valid))
+            && !is_skip_if(attribute)
+        {
+            match extract_async_meta_validator(field, attribute, rename_map) {
+                Ok(validator) => validator,
+                Err(validator_errors) => {
+                    errors.extend(validator_errors);
+                    None
+                }
+            }
+        } else {
+            None
+        }
+    });
+    let validators = validators.collect::<Vec<_>>();
+
+    if errors.is_empty() {
+        Ok(TokenStream::from_iter(wrap_validators_with_skip_if(
+            validators,
+            skip_if.as_ref(),
+        )))
+    } else {
+        Err(errors)
+    }
+}
@@ -1,9 +1,14 @@
 use super::named_struct_derive::collect_named_fields_validators_list;
 use super::unnamed_struct_derive::collect_unnamed_fields_validators_list;
-use crate::error::{array_errors_tokens, new_type_errors_tokens, object_errors_tokens};
+use super::validate_impl_tokens;
+use crate::error::{
+    array_errors_tokens, nested_errors_tokens, new_type_errors_tokens, object_errors_tokens,
+};
 use crate::rule::{collect_rules_from_named_struct, collect_rules_from_unnamed_struct};
-use crate::serde::rename::collect_serde_rename_map;
+use crate::serde::rename::{collect_serde_rename_map, find_serde_variant_rename_all};
+use crate::serde::representation::EnumRepresentation;
 use crate::types::CommaSeparatedTokenStreams;
+use crate::validate::collect_compared_fields;
 use proc_macro2::TokenStream;
 use quote::quote;
 use std::collections::HashSet;
@@ -14,9 +19,9 @@ pub type Variants = syn::punctuated::Punctuated<syn::Variant, syn::token::Comma>
 pub fn expand_enum_validate_derive(
     input: &syn::DeriveInput,
     variants: &Variants,
+    context: Option<&syn::Type>,
 ) -> Result<TokenStream, crate::Errors> {
-    let ident = &input.ident;
-    let (impl_generics, type_generics, where_clause) = input.generics.split_for_impl();
+    let representation = EnumRepresentation::from_attributes(&input.attrs);
 
     let mut errors = vec![];
 
@@ -24,7 +29,14 @@ pub fn expand_enum_validate_derive(
         TokenStream::from_iter(variants.iter().enumerate().map(|(index, variant)| {
             match &variant.fields {
                 syn::Fields::Named(named_fields) => {
-                    match expand_enum_variant_named_fields(index, ident, variant, named_fields) {
+                    match expand_enum_variant_named_fields(
+                        index,
+                        input,
+                        &representation,
+                        variant,
+                        named_fields,
+                        context,
+                    ) {
                         Ok(variant_varidates_and_rules) => variant_varidates_and_rules,
                         Err(variant_errors) => {
                             errors.extend(variant_errors);
@@ -35,9 +47,11 @@ pub fn expand_enum_validate_derive(
                 syn::Fields::Unnamed(unnamed_fields) => {
                     match expand_enum_variant_unnamed_fields_varidation(
                         index,
-                        ident,
+                        input,
+                        &representation,
                         variant,
                         unnamed_fields,
+                        context,
                     ) {
                         Ok(variant_varidates_and_rules) => variant_varidates_and_rules,
                         Err(variant_errors) => {
@@ -50,16 +64,14 @@ pub fn expand_enum_validate_derive(
             }
         }));
 
-    if errors.is_empty() {
-        Ok(quote!(
-            impl #impl_generics ::serde_valid::Validate for #ident #type_generics #where_clause {
-                fn validate(&self) -> std::result::Result<(), ::serde_valid::validation::Errors> {
-                    #validations_and_rules
+    let body = quote!(
+        #validations_and_rules
 
-                    Ok(())
-                }
-            }
-        ))
+        Ok(())
+    );
+
+    if errors.is_empty() {
+        Ok(validate_impl_tokens(input, body, context))
     } else {
         Err(errors)
     }
@@ -67,26 +79,35 @@ pub fn expand_enum_validate_derive(
 
 fn expand_enum_variant_named_fields(
     index: usize,
-    ident: &syn::Ident,
+    input: &syn::DeriveInput,
+    representation: &EnumRepresentation,
     variant: &syn::Variant,
     named_fields: &syn::FieldsNamed,
+    context: Option<&syn::Type>,
 ) -> Result<TokenStream, crate::Errors> {
     let mut errors = vec![];
 
     let variant_ident = &variant.ident;
     let mut fields_idents = CommaSeparatedTokenStreams::new();
     let else_token = make_else_token(index);
-    let rename_map = collect_serde_rename_map(named_fields);
+    let ident = &input.ident;
+    let rename_map = collect_serde_rename_map(
+        named_fields,
+        find_serde_variant_rename_all(&input.attrs, &variant.attrs),
+    );
+
+    let (rule_fields, rules) =
+        match collect_rules_from_named_struct(&variant.attrs, &rename_map, context) {
+            Ok(field_rules) => field_rules,
+            Err(variant_errors) => {
+                errors.extend(variant_errors);
+                (HashSet::new(), quote!())
+            }
+        };
 
-    let (rule_fields, rules) = match collect_rules_from_named_struct(&variant.attrs) {
-        Ok(field_rules) => field_rules,
-        Err(variant_errors) => {
-            errors.extend(variant_errors.into_iter());
-            (HashSet::new(), quote!())
-        }
-    };
+    let compared_fields = collect_compared_fields(named_fields);
 
-    let validates = match collect_named_fields_validators_list(named_fields, &rename_map) {
+    let validates = match collect_named_fields_validators_list(named_fields, &rename_map, context) {
         Ok(field_validators_list) => {
             TokenStream::from_iter(field_validators_list.iter().map(|validators| {
                 let field_ident = validators.ident();
@@ -95,7 +116,7 @@ fn expand_enum_variant_named_fields(
                     fields_idents.push(quote!(#field_ident));
                     quote!(#token)
                 } else {
-                    if rule_fields.contains(field_ident) {
+                    if rule_fields.contains(field_ident) || compared_fields.contains(field_ident) {
                         fields_idents.push(quote!(#field_ident));
                     } else {
                         fields_idents.push(quote!(#field_ident: _));
@@ -105,12 +126,17 @@ fn expand_enum_variant_named_fields(
             }))
         }
         Err(fields_errors) => {
-            errors.extend(fields_errors.into_iter());
+            errors.extend(fields_errors);
             quote!()
         }
     };
 
-    let variant_errors = object_errors_tokens();
+    let variant_errors = nested_errors_tokens(
+        representation
+            .variant_errors_key(&input.attrs, variant)
+            .as_deref(),
+        object_errors_tokens(),
+    );
 
     if errors.is_empty() {
         Ok(quote!(
@@ -119,7 +145,9 @@ fn expand_enum_variant_named_fields(
                 let mut __property_vec_errors_map = ::serde_valid::validation::PropertyVecErrorsMap::new();
 
                 #validates
-                #rules
+                if __group.is_none() {
+                    #rules
+                }
 
                 if !(__rule_vec_errors.is_empty() && __property_vec_errors_map.is_empty()) {
                     Err(#variant_errors)?
@@ -133,25 +161,28 @@ fn expand_enum_variant_named_fields(
 
 fn expand_enum_variant_unnamed_fields_varidation(
     index: usize,
-    ident: &syn::Ident,
+    input: &syn::DeriveInput,
+    representation: &EnumRepresentation,
     variant: &syn::Variant,
     unnamed_fields: &syn::FieldsUnnamed,
+    context: Option<&syn::Type>,
 ) -> Result<TokenStream, crate::Errors> {
     let mut errors = vec![];
 
+    let ident = &input.ident;
     let variant_ident = &variant.ident;
     let mut fields_idents = CommaSeparatedTokenStreams::new();
     let else_token = make_else_token(index);
 
-    let (rule_fields, rules) = match collect_rules_from_unnamed_struct(&variant.attrs) {
+    let (rule_fields, rules) = match collect_rules_from_unnamed_struct(&variant.attrs, context) {
         Ok(field_rules) => field_rules,
         Err(variant_errors) => {
-            errors.extend(variant_errors.into_iter());
+            errors.extend(variant_errors);
             (HashSet::new(), quote!())
         }
     };
 
-    let validates = match collect_unnamed_fields_validators_list(unnamed_fields) {
+    let validates = match collect_unnamed_fields_validators_list(unnamed_fields, context) {
         Ok(field_validators_list) => {
             TokenStream::from_iter(field_validators_list.iter().map(|validators| {
                 let field_ident = validators.ident();
@@ -170,16 +201,21 @@ fn expand_enum_variant_unnamed_fields_varidation(
             }))
         }
         Err(fields_errors) => {
-            errors.extend(fields_errors.into_iter());
+            errors.extend(fields_errors);
             quote!()
         }
     };
 
-    let variant_errors = if unnamed_fields.unnamed.len() != 1 {
-        array_errors_tokens()
-    } else {
-        new_type_errors_tokens()
-    };
+    let variant_errors = nested_errors_tokens(
+        representation
+            .variant_errors_key(&input.attrs, variant)
+            .as_deref(),
+        if unnamed_fields.unnamed.len() != 1 {
+            array_errors_tokens()
+        } else {
+            new_type_errors_tokens()
+        },
+    );
 
     if errors.is_empty() {
         Ok(quote!(
@@ -188,7 +224,9 @@ fn expand_enum_variant_unnamed_fields_varidation(
                 let mut __item_vec_errors_map = ::serde_valid::validation::ItemVecErrorsMap::new();
 
                 #validates
-                #rules
+                if __group.is_none() {
+                    #rules
+                }
 
                 if !(__rule_vec_errors.is_empty() && __item_vec_errors_map.is_empty()) {
                     Err(#variant_errors)?
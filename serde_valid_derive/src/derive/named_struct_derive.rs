@@ -1,8 +1,12 @@
+use super::validate_impl_tokens;
 use crate::error::object_errors_tokens;
 use crate::rule::collect_rules_from_named_struct;
-use crate::serde::rename::{collect_serde_rename_map, RenameMap};
+use crate::serde::rename::{collect_serde_rename_map, find_serde_rename_all, RenameMap};
 use crate::types::{Field, NamedField};
-use crate::validate::{extract_meta_validator, FieldValidators};
+use crate::validate::{
+    check_field_limits, collect_compared_fields, extract_meta_validator, extract_skip_if,
+    is_async_meta_validator, is_skip_if, wrap_validators_with_skip_if, FieldValidators,
+};
 use proc_macro2::TokenStream;
 use quote::quote;
 use std::borrow::Cow;
@@ -13,55 +17,72 @@ use syn::parse_quote;
 pub fn expand_named_struct_derive(
     input: &syn::DeriveInput,
     fields: &syn::FieldsNamed,
+    context: Option<&syn::Type>,
 ) -> Result<TokenStream, crate::Errors> {
-    let ident = &input.ident;
-    let (impl_generics, type_generics, where_clause) = input.generics.split_for_impl();
-    let rename_map = collect_serde_rename_map(fields);
+    let rename_map = collect_serde_rename_map(fields, find_serde_rename_all(&input.attrs));
 
     let mut errors = vec![];
 
-    let (rule_fields, rules) = match collect_rules_from_named_struct(&input.attrs) {
-        Ok((rule_fields, rules)) => (rule_fields, TokenStream::from_iter(rules)),
-        Err(rule_errors) => {
-            errors.extend(rule_errors);
-            (HashSet::new(), quote!())
-        }
-    };
-
-    let validates = match collect_named_fields_validators_list(fields, &rename_map) {
-        Ok(field_validators) => TokenStream::from_iter(field_validators.iter().map(|validator| {
-            if validator.is_empty() && rule_fields.contains(validator.ident()) {
-                validator.get_field_variable_token()
-            } else {
-                validator.generate_tokens()
+    let (rule_fields, rules) =
+        match collect_rules_from_named_struct(&input.attrs, &rename_map, context) {
+            Ok((rule_fields, rules)) => (rule_fields, TokenStream::from_iter(rules)),
+            Err(rule_errors) => {
+                errors.extend(rule_errors);
+                (HashSet::new(), quote!())
             }
-        })),
+        };
+
+    let compared_fields = collect_compared_fields(fields);
+
+    let validates = match collect_named_fields_validators_list(fields, &rename_map, context) {
+        Ok(field_validators) => {
+            // Compared fields are bound first, as other fields refer to them.
+            let compared_field_variables = TokenStream::from_iter(
+                field_validators
+                    .iter()
+                    .filter(|validator| compared_fields.contains(validator.ident()))
+                    .map(|validator| validator.get_field_variable_token()),
+            );
+            let validates = TokenStream::from_iter(field_validators.iter().map(|validator| {
+                if compared_fields.contains(validator.ident()) {
+                    validator.get_tokens().unwrap_or_default()
+                } else if validator.is_empty() && rule_fields.contains(validator.ident()) {
+                    validator.get_field_variable_token()
+                } else {
+                    validator.generate_tokens()
+                }
+            }));
+            quote!(
+                #compared_field_variables
+                #validates
+            )
+        }
         Err(validation_errors) => {
-            errors.extend(validation_errors.into_iter());
+            errors.extend(validation_errors);
             quote!()
         }
     };
 
     let fields_errors = object_errors_tokens();
 
+    let body = quote!(
+        let mut __rule_vec_errors = ::serde_valid::validation::VecErrors::new();
+        let mut __property_vec_errors_map = ::serde_valid::validation::PropertyVecErrorsMap::new();
+
+        #validates
+        if __group.is_none() {
+            #rules
+        }
+
+        if __rule_vec_errors.is_empty() && __property_vec_errors_map.is_empty() {
+            Ok(())
+        } else {
+            Err(#fields_errors)
+        }
+    );
+
     if errors.is_empty() {
-        Ok(quote!(
-            impl #impl_generics ::serde_valid::Validate for #ident #type_generics #where_clause {
-                fn validate(&self) -> std::result::Result<(), ::serde_valid::validation::Errors> {
-                    let mut __rule_vec_errors = ::serde_valid::validation::VecErrors::new();
-                    let mut __property_vec_errors_map = ::serde_valid::validation::PropertyVecErrorsMap::new();
-
-                    #validates
-                    #rules
-
-                    if __rule_vec_errors.is_empty() && __property_vec_errors_map.is_empty() {
-                        Ok(())
-                    } else {
-                        Err(#fields_errors)
-                    }
-                }
-            }
-        ))
+        Ok(validate_impl_tokens(input, body, context))
     } else {
         Err(errors)
     }
@@ -70,6 +91,7 @@ pub fn expand_named_struct_derive(
 pub fn collect_named_fields_validators_list<'a>(
     fields: &'a syn::FieldsNamed,
     rename_map: &RenameMap,
+    context: Option<&syn::Type>,
 ) -> Result<Vec<FieldValidators<'a, NamedField<'a>>>, crate::Errors> {
     let mut errors = vec![];
 
@@ -77,7 +99,7 @@ pub fn collect_named_fields_validators_list<'a>(
         .named
         .iter()
         .filter_map(
-            |field| match collect_named_field_validators(field, rename_map) {
+            |field| match collect_named_field_validators(field, rename_map, context) {
                 Ok(validators) => Some(validators),
                 Err(ref mut error) => {
                     errors.append(error);
@@ -97,18 +119,25 @@ pub fn collect_named_fields_validators_list<'a>(
 fn collect_named_field_validators<'a>(
     field: &'a syn::Field,
     rename_map: &RenameMap,
+    context: Option<&syn::Type>,
 ) -> Result<FieldValidators<'a, NamedField<'a>>, crate::Errors> {
     let mut errors = vec![];
 
     let named_field = NamedField::new(field);
+    let skip_if = extract_skip_if(named_field.attrs()).unwrap_or_else(|skip_if_errors| {
+        errors.extend(skip_if_errors);
+        None
+    });
     let validators = named_field
         .attrs()
         .iter()
         .filter_map(|attribute| {
-            if attribute.path == parse_quote!(validate)
-                || attribute.path == parse_quote!(serde_valid)
+            if (attribute.path == parse_quote!(validate)
+                || attribute.path == parse_quote!(serde_valid))
+                && !is_skip_if(attribute)
+                && !is_async_meta_validator(attribute)
             {
-                match extract_meta_validator(&named_field, attribute, rename_map) {
+                match extract_meta_validator(&named_field, attribute, rename_map, context) {
                     Ok(validator) => Some(validator),
                     Err(validator_error) => {
                         errors.extend(validator_error);
@@ -121,12 +150,16 @@ fn collect_named_field_validators<'a>(
         })
         .collect::<Vec<_>>();
 
+    if let Err(limit_errors) = check_field_limits(&named_field) {
+        errors.extend(limit_errors);
+    }
+
     if !errors.is_empty() {
         return Err(errors);
     }
 
     Ok(FieldValidators::new(
         Cow::Owned(named_field.clone()),
-        validators,
+        wrap_validators_with_skip_if(validators, skip_if.as_ref()),
     ))
 }
@@ -0,0 +1,196 @@
+use crate::serde::flatten::is_serde_flatten;
+use crate::serde::rename::{
+    collect_serde_rename_map, find_serde_rename_all, find_serde_variant_rename_all, RenameRule,
+};
+use crate::serde::representation::{find_serde_variant_name, EnumRepresentation};
+use crate::types::{Field, NamedField, UnnamedField};
+use crate::validate::extract_field_schema;
+use proc_macro2::TokenStream;
+use quote::quote;
+
+pub fn expand_schema_derive(input: &syn::DeriveInput) -> Result<TokenStream, crate::Errors> {
+    let schema = match &input.data {
+        syn::Data::Struct(syn::DataStruct { ref fields, .. }) => match fields {
+            syn::Fields::Named(fields) => {
+                named_fields_schema_tokens(fields, find_serde_rename_all(&input.attrs))
+            }
+            syn::Fields::Unnamed(fields) => unnamed_fields_schema_tokens(fields),
+            syn::Fields::Unit => Err(vec![crate::Error::unit_struct_not_support(input)])?,
+        },
+        syn::Data::Enum(syn::DataEnum { variants, .. }) => {
+            let representation = EnumRepresentation::from_attributes(&input.attrs);
+            let variant_schemas = variants.iter().map(|variant| {
+                let variant_name = find_serde_variant_name(&input.attrs, variant);
+                let rename_all = find_serde_variant_rename_all(&input.attrs, &variant.attrs);
+                variant_schema_tokens(&representation, &variant_name, &variant.fields, rename_all)
+            });
+            quote!(::serde_valid::export::serde_json::json!({
+                "anyOf": ::serde_valid::export::serde_json::Value::Array(vec![#(#variant_schemas),*])
+            }))
+        }
+        syn::Data::Union(_) => Err(vec![crate::Error::union_not_support(input)])?,
+    };
+
+    let ident = &input.ident;
+    let (impl_generics, type_generics, where_clause) = input.generics.split_for_impl();
+
+    Ok(quote!(
+        impl #impl_generics ::serde_valid::ValidateSchema for #ident #type_generics #where_clause {
+            fn json_schema() -> ::serde_valid::export::serde_json::Value {
+                ::serde_valid::export::json_schema_of::<Self>(|| #schema)
+            }
+        }
+    ))
+}
+
+fn variant_schema_tokens(
+    representation: &EnumRepresentation,
+    variant_name: &str,
+    fields: &syn::Fields,
+    rename_all: Option<RenameRule>,
+) -> TokenStream {
+    let fields_schema = || match fields {
+        syn::Fields::Named(fields) => named_fields_schema_tokens(fields, rename_all),
+        syn::Fields::Unnamed(fields) => unnamed_fields_schema_tokens(fields),
+        syn::Fields::Unit => unreachable!("unit variants have no fields schema"),
+    };
+    let tag_schema = |tag: &str| {
+        quote!(::serde_valid::export::serde_json::json!({
+            "type": "object",
+            "properties": { #tag: {"const": #variant_name} },
+            "required": [#tag]
+        }))
+    };
+
+    match (representation, fields) {
+        (EnumRepresentation::External, syn::Fields::Unit) => {
+            quote!(::serde_valid::export::serde_json::json!({"enum": [#variant_name]}))
+        }
+        (EnumRepresentation::External, _) => {
+            let fields_schema = fields_schema();
+            quote!(::serde_valid::export::serde_json::json!({
+                "type": "object",
+                "properties": { #variant_name: (#fields_schema) },
+                "required": [#variant_name]
+            }))
+        }
+        (EnumRepresentation::Internal { tag }, syn::Fields::Named(fields)) => {
+            let fields_schema = named_fields_schema_tokens_with(
+                fields,
+                rename_all,
+                quote!(
+                    __properties.insert(
+                        (#tag).to_owned(),
+                        ::serde_valid::export::serde_json::json!({"const": #variant_name}),
+                    );
+                ),
+            );
+            quote!({
+                let mut __variant_schema = #fields_schema;
+                __variant_schema["required"] = ::serde_valid::export::serde_json::json!([#tag]);
+                __variant_schema
+            })
+        }
+        (EnumRepresentation::Internal { tag }, syn::Fields::Unnamed(_)) => {
+            let tag_schema = tag_schema(tag);
+            let fields_schema = fields_schema();
+            quote!(::serde_valid::export::serde_json::json!({
+                "allOf": [(#tag_schema), (#fields_schema)]
+            }))
+        }
+        (EnumRepresentation::Internal { tag }, syn::Fields::Unit)
+        | (EnumRepresentation::Adjacent { tag, .. }, syn::Fields::Unit) => tag_schema(tag),
+        (EnumRepresentation::Adjacent { tag, content }, _) => {
+            let fields_schema = fields_schema();
+            quote!(::serde_valid::export::serde_json::json!({
+                "type": "object",
+                "properties": {
+                    #tag: {"const": #variant_name},
+                    #content: (#fields_schema)
+                },
+                "required": [#tag, #content]
+            }))
+        }
+        (EnumRepresentation::Untagged, syn::Fields::Unit) => {
+            quote!(::serde_valid::export::serde_json::json!({"type": "null"}))
+        }
+        (EnumRepresentation::Untagged, _) => fields_schema(),
+    }
+}
+
+fn named_fields_schema_tokens(
+    fields: &syn::FieldsNamed,
+    rename_all: Option<RenameRule>,
+) -> TokenStream {
+    named_fields_schema_tokens_with(fields, rename_all, quote!())
+}
+
+/// Schema of named fields, with `extra_properties` run first to insert properties such as an enum tag.
+fn named_fields_schema_tokens_with(
+    fields: &syn::FieldsNamed,
+    rename_all: Option<RenameRule>,
+    extra_properties: TokenStream,
+) -> TokenStream {
+    let rename_map = collect_serde_rename_map(fields, rename_all);
+
+    let properties = TokenStream::from_iter(fields.named.iter().map(|field| {
+        let named_field = NamedField::new(field);
+        let field_key = named_field.key();
+        let rename = rename_map.get(named_field.name()).unwrap_or(&field_key);
+        let field_schema = extract_field_schema(&named_field);
+
+        if is_serde_flatten(named_field.attrs()) {
+            return quote!(
+                let mut __schema = ::serde_valid::export::serde_json::Map::new();
+                #field_schema
+                if let Some(::serde_valid::export::serde_json::Value::Object(__nested_properties)) =
+                    __schema.remove("properties")
+                {
+                    __properties.extend(__nested_properties);
+                }
+            );
+        }
+
+        quote!(
+            let mut __schema = ::serde_valid::export::serde_json::Map::new();
+            #field_schema
+            if !__schema.is_empty() {
+                __properties.insert(
+                    (#rename).to_owned(),
+                    ::serde_valid::export::serde_json::Value::Object(__schema),
+                );
+            }
+        )
+    }));
+
+    quote!({
+        let mut __properties = ::serde_valid::export::serde_json::Map::new();
+        #extra_properties
+        #properties
+        ::serde_valid::export::serde_json::json!({
+            "type": "object",
+            "properties": __properties
+        })
+    })
+}
+
+fn unnamed_fields_schema_tokens(fields: &syn::FieldsUnnamed) -> TokenStream {
+    let items = fields.unnamed.iter().enumerate().map(|(index, field)| {
+        let field_schema = extract_field_schema(&UnnamedField::new(index, field));
+
+        quote!({
+            let mut __schema = ::serde_valid::export::serde_json::Map::new();
+            #field_schema
+            ::serde_valid::export::serde_json::Value::Object(__schema)
+        })
+    });
+
+    if fields.unnamed.len() == 1 {
+        quote!(#(#items)*)
+    } else {
+        quote!(::serde_valid::export::serde_json::json!({
+            "type": "array",
+            "items": ::serde_valid::export::serde_json::Value::Array(vec![#(#items),*])
+        }))
+    }
+}
@@ -1,7 +1,11 @@
+use super::validate_impl_tokens;
 use crate::error::{array_errors_tokens, new_type_errors_tokens};
 use crate::rule::collect_rules_from_unnamed_struct;
 use crate::types::{Field, UnnamedField};
-use crate::validate::{extract_meta_validator, FieldValidators};
+use crate::validate::{
+    check_field_limits, extract_meta_validator, extract_skip_if, is_async_meta_validator,
+    is_skip_if, wrap_validators_with_skip_if, FieldValidators,
+};
 use proc_macro2::TokenStream;
 use quote::quote;
 use std::borrow::Cow;
@@ -12,13 +16,11 @@ use syn::parse_quote;
 pub fn expand_unnamed_struct_derive(
     input: &syn::DeriveInput,
     fields: &syn::FieldsUnnamed,
+    context: Option<&syn::Type>,
 ) -> Result<TokenStream, crate::Errors> {
-    let ident = &input.ident;
-    let (impl_generics, type_generics, where_clause) = input.generics.split_for_impl();
-
     let mut errors = vec![];
 
-    let (rule_fields, rules) = match collect_rules_from_unnamed_struct(&input.attrs) {
+    let (rule_fields, rules) = match collect_rules_from_unnamed_struct(&input.attrs, context) {
         Ok((rule_fields, rules)) => (rule_fields, TokenStream::from_iter(rules)),
         Err(rule_errors) => {
             errors.extend(rule_errors);
@@ -26,7 +28,7 @@ pub fn expand_unnamed_struct_derive(
         }
     };
 
-    let validates = match collect_unnamed_fields_validators_list(fields) {
+    let validates = match collect_unnamed_fields_validators_list(fields, context) {
         Ok(field_validators) => TokenStream::from_iter(field_validators.iter().map(|validator| {
             if validator.is_empty() && rule_fields.contains(validator.ident()) {
                 validator.get_field_variable_token()
@@ -35,7 +37,7 @@ pub fn expand_unnamed_struct_derive(
             }
         })),
         Err(validation_errors) => {
-            errors.extend(validation_errors.into_iter());
+            errors.extend(validation_errors);
             quote!()
         }
     };
@@ -46,45 +48,48 @@ pub fn expand_unnamed_struct_derive(
         new_type_errors_tokens()
     };
 
+    let body = quote!(
+        let mut __rule_vec_errors = ::serde_valid::validation::VecErrors::new();
+        let mut __item_vec_errors_map = ::serde_valid::validation::ItemVecErrorsMap::new();
+
+        #validates
+        if __group.is_none() {
+            #rules
+        }
+
+        if __rule_vec_errors.is_empty() && __item_vec_errors_map.is_empty() {
+            Ok(())
+        } else {
+            Err(#fields_errors)
+        }
+    );
+
     if errors.is_empty() {
-        Ok(quote!(
-            impl #impl_generics ::serde_valid::Validate for #ident #type_generics #where_clause {
-                fn validate(&self) -> std::result::Result<(), ::serde_valid::validation::Errors> {
-                    let mut __rule_vec_errors = ::serde_valid::validation::VecErrors::new();
-                    let mut __item_vec_errors_map = ::serde_valid::validation::ItemVecErrorsMap::new();
-
-                    #validates
-                    #rules
-
-                    if __rule_vec_errors.is_empty() && __item_vec_errors_map.is_empty() {
-                        Ok(())
-                    } else {
-                        Err(#fields_errors)
-                    }
-                }
-            }
-        ))
+        Ok(validate_impl_tokens(input, body, context))
     } else {
         Err(errors)
     }
 }
 
-pub fn collect_unnamed_fields_validators_list(
-    fields: &syn::FieldsUnnamed,
-) -> Result<Vec<FieldValidators<UnnamedField>>, crate::Errors> {
+pub fn collect_unnamed_fields_validators_list<'a>(
+    fields: &'a syn::FieldsUnnamed,
+    context: Option<&syn::Type>,
+) -> Result<Vec<FieldValidators<'a, UnnamedField<'a>>>, crate::Errors> {
     let mut errors = vec![];
 
     let validators = fields
         .unnamed
         .iter()
         .enumerate()
-        .filter_map(|field| match collect_unnamed_field_validators(field) {
-            Ok(validators) => Some(validators),
-            Err(ref mut error) => {
-                errors.append(error);
-                None
-            }
-        })
+        .filter_map(
+            |field| match collect_unnamed_field_validators(field, context) {
+                Ok(validators) => Some(validators),
+                Err(ref mut error) => {
+                    errors.append(error);
+                    None
+                }
+            },
+        )
         .collect();
 
     if !errors.is_empty() {
@@ -94,21 +99,28 @@ pub fn collect_unnamed_fields_validators_list(
     Ok(validators)
 }
 
-fn collect_unnamed_field_validators(
-    (index, field): (usize, &syn::Field),
-) -> Result<FieldValidators<UnnamedField>, crate::Errors> {
+fn collect_unnamed_field_validators<'a>(
+    (index, field): (usize, &'a syn::Field),
+    context: Option<&syn::Type>,
+) -> Result<FieldValidators<'a, UnnamedField<'a>>, crate::Errors> {
     let mut errors = vec![];
 
     let unnamed_field = UnnamedField::new(index, field);
+    let skip_if = extract_skip_if(unnamed_field.attrs()).unwrap_or_else(|skip_if_errors| {
+        errors.extend(skip_if_errors);
+        None
+    });
 
     let validators = unnamed_field
         .attrs()
         .iter()
         .filter_map(|attribute| {
-            if attribute.path == parse_quote!(validate)
-                || attribute.path == parse_quote!(serde_valid)
+            if (attribute.path == parse_quote!(validate)
+                || attribute.path == parse_quote!(serde_valid))
+                && !is_skip_if(attribute)
+                && !is_async_meta_validator(attribute)
             {
-                match extract_meta_validator(&unnamed_field, attribute, &HashMap::new()) {
+                match extract_meta_validator(&unnamed_field, attribute, &HashMap::new(), context) {
                     Ok(validator) => Some(validator),
                     Err(validator_errors) => {
                         errors.extend(validator_errors);
@@ -121,9 +133,16 @@ fn collect_unnamed_field_validators(
         })
         .collect::<Vec<_>>();
 
+    if let Err(limit_errors) = check_field_limits(&unnamed_field) {
+        errors.extend(limit_errors);
+    }
+
     if !errors.is_empty() {
         return Err(errors);
     }
 
-    Ok(FieldValidators::new(Cow::Owned(unnamed_field), validators))
+    Ok(FieldValidators::new(
+        Cow::Owned(unnamed_field),
+        wrap_validators_with_skip_if(validators, skip_if.as_ref()),
+    ))
 }
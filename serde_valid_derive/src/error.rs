@@ -14,7 +14,7 @@ pub fn object_errors_tokens() -> TokenStream {
                 .into_iter()
                 .map(|(field, errors)| {
                     let mut __field_items_errors = vec![];
-                    let mut __field_properties_errors = None;
+                    let mut __field_properties_errors = vec![];
                     let mut __field_errors: ::serde_valid::validation::VecErrors = errors
                         .into_iter()
                         .filter_map(|error| match error {
@@ -23,14 +23,23 @@ pub fn object_errors_tokens() -> TokenStream {
                                 None
                             }
                             ::serde_valid::validation::Error::Properties(__object_errors) => {
-                                __field_properties_errors = Some(__object_errors);
+                                __field_properties_errors.push(__object_errors);
                                 None
                             }
                             _ => Some(error),
                         })
                         .collect();
 
-                    if let Some(__object_errors) = __field_properties_errors {
+                    if !__field_properties_errors.is_empty() {
+                        let mut __field_properties_errors = __field_properties_errors.into_iter();
+                        let mut __object_errors = __field_properties_errors.next().unwrap();
+                        for __properties_errors in __field_properties_errors {
+                            if let Err(__conflict) = __object_errors.merge(__properties_errors) {
+                                __field_errors.push(::serde_valid::validation::Error::Properties(
+                                    __conflict.into_inner(),
+                                ));
+                            }
+                        }
                         __field_errors.extend(__object_errors.errors);
 
                         (
@@ -43,10 +52,15 @@ pub fn object_errors_tokens() -> TokenStream {
                             ),
                         )
                     } else if !__field_items_errors.is_empty() {
-                        let __array_errors = __field_items_errors
-                            .into_iter()
-                            .reduce(|a, b| a.merge(b))
-                            .unwrap();
+                        let mut __field_items_errors = __field_items_errors.into_iter();
+                        let mut __array_errors = __field_items_errors.next().unwrap();
+                        for __items_errors in __field_items_errors {
+                            if let Err(__conflict) = __array_errors.merge(__items_errors) {
+                                __field_errors.push(::serde_valid::validation::Error::Items(
+                                    __conflict.into_inner(),
+                                ));
+                            }
+                        }
                         __field_errors.extend(__array_errors.errors);
 
                         (
@@ -78,7 +92,7 @@ pub fn array_errors_tokens() -> TokenStream {
                 .into_iter()
                 .map(|(index, errors)| {
                     let mut __field_items_errors = vec![];
-                    let mut __field_properties_errors = None;
+                    let mut __field_properties_errors = vec![];
                     let mut __field_errors: ::serde_valid::validation::VecErrors = errors
                         .into_iter()
                         .filter_map(|error| match error {
@@ -87,14 +101,23 @@ pub fn array_errors_tokens() -> TokenStream {
                                 None
                             }
                             ::serde_valid::validation::Error::Properties(__object_errors) => {
-                                __field_properties_errors = Some(__object_errors);
+                                __field_properties_errors.push(__object_errors);
                                 None
                             }
                             _ => Some(error),
                         })
                         .collect();
 
-                    if let Some(__object_errors) = __field_properties_errors {
+                    if !__field_properties_errors.is_empty() {
+                        let mut __field_properties_errors = __field_properties_errors.into_iter();
+                        let mut __object_errors = __field_properties_errors.next().unwrap();
+                        for __properties_errors in __field_properties_errors {
+                            if let Err(__conflict) = __object_errors.merge(__properties_errors) {
+                                __field_errors.push(::serde_valid::validation::Error::Properties(
+                                    __conflict.into_inner(),
+                                ));
+                            }
+                        }
                         __field_errors.extend(__object_errors.errors);
 
                         (
@@ -107,10 +130,15 @@ pub fn array_errors_tokens() -> TokenStream {
                             ),
                         )
                     } else if !__field_items_errors.is_empty() {
-                        let __array_errors = __field_items_errors
-                            .into_iter()
-                            .reduce(|a, b| a.merge(b))
-                            .unwrap();
+                        let mut __field_items_errors = __field_items_errors.into_iter();
+                        let mut __array_errors = __field_items_errors.next().unwrap();
+                        for __items_errors in __field_items_errors {
+                            if let Err(__conflict) = __array_errors.merge(__items_errors) {
+                                __field_errors.push(::serde_valid::validation::Error::Items(
+                                    __conflict.into_inner(),
+                                ));
+                            }
+                        }
                         __field_errors.extend(__array_errors.errors);
 
                         (
@@ -138,11 +166,24 @@ pub fn new_type_errors_tokens() -> TokenStream {
     quote!(::serde_valid::validation::Errors::NewType(
         __rule_vec_errors
             .into_iter()
-            .chain(__item_vec_errors_map.remove(&0).unwrap().into_iter())
+            .chain(__item_vec_errors_map.remove(&0).unwrap_or_default())
             .collect()
     ))
 }
 
+/// Nest `errors` under the property `key`, such as the name of an externally tagged variant.
+pub fn nested_errors_tokens(key: Option<&str>, errors: TokenStream) -> TokenStream {
+    match key {
+        Some(key) => quote!(::serde_valid::validation::Errors::Object(
+            ::serde_valid::validation::ObjectErrors::new(
+                vec![],
+                ::std::iter::FromIterator::from_iter([(#key.into(), #errors)]),
+            )
+        )),
+        None => errors,
+    }
+}
+
 #[derive(Debug)]
 pub struct Error(syn::Error);
 
@@ -256,6 +297,13 @@ impl Error {
         )
     }
 
+    pub fn validate_context_duplicated(attribute: &syn::Attribute) -> Self {
+        Self::new(
+            attribute.span(),
+            "#[validate(context = ???)] allow only one context.",
+        )
+    }
+
     pub fn validate_enumerate_need_item(path: &syn::Path) -> Self {
         Self::new(path.span(), "`enumerate` need items.")
     }
@@ -268,6 +316,203 @@ impl Error {
         Self::new(nested.span(), "`custom` support only 1 item.")
     }
 
+    pub fn validate_custom_not_support_message(path: &syn::Path) -> Self {
+        Self::new(
+            path.span(),
+            "`custom` does not support a custom message. Return the message from the custom function.",
+        )
+    }
+
+    pub fn validate_custom_async_need_async_derive(path: &syn::Path) -> Self {
+        Self::new(
+            path.span(),
+            "`custom_async` is run by #[derive(AsyncValidate)].",
+        )
+    }
+
+    pub fn validate_context_shadowed_by_field(field: &syn::Ident) -> Self {
+        Self::new(
+            field.span(),
+            "A field named `ctx` hides the context of #[validate(context = ???)]. \
+             Rename the field, and keep its key with #[serde(rename = \"ctx\")].",
+        )
+    }
+
+    pub fn async_validate_context_not_support(input: &syn::DeriveInput) -> Self {
+        Self::new(
+            input.span(),
+            "#[derive(AsyncValidate)] does not support #[validate(context = ???)].",
+        )
+    }
+
+    pub fn validate_pattern_invalid(pattern: &syn::LitStr, error: &regex::Error) -> Self {
+        Self::new(pattern.span(), format!("Invalid pattern: {error}"))
+    }
+
+    pub fn validate_format_unknown(format: &syn::LitStr, candidates: &[&str]) -> Self {
+        let value = format.value();
+        let filterd_candidates =
+            did_you_mean(&value, candidates).unwrap_or_else(|| candidates.to_vec());
+
+        Self::new(
+            format.span(),
+            format!(
+                "Unknown format: `{}`. Is it one of the following?\n{filterd_candidates:#?}\n\
+                 For a custom format, register it with `serde_valid::format::register_format` \
+                 and give its name by a constant, such as `format = POSTAL_CODE`.",
+                value
+            ),
+        )
+    }
+
+    pub fn validate_multiple_of_zero(multiple_of: &syn::Expr) -> Self {
+        Self::new(multiple_of.span(), "`multiple_of` must not be 0.")
+    }
+
+    pub fn validate_limits_conflict(
+        lower: &syn::Expr,
+        lower_name: &str,
+        upper: &syn::Expr,
+        upper_name: &str,
+        allow_equal: bool,
+    ) -> Self {
+        Self::new(
+            lower.span(),
+            format!(
+                "`{lower_name} = {}` must be {} `{upper_name} = {}`.",
+                quote!(#lower),
+                if allow_equal { "<=" } else { "<" },
+                quote!(#upper),
+            ),
+        )
+    }
+
+    pub fn validate_compare_allow_field(value: &syn::Expr, validation_type: &str) -> Self {
+        Self::new(
+            value.span(),
+            format!("#[validate({validation_type} = ???)] allow sibling field name only."),
+        )
+    }
+
+    pub fn validate_groups_need_item(path: &syn::Path) -> Self {
+        Self::new(path.span(), "`groups` need items.")
+    }
+
+    pub fn validate_groups_allow_ident(nested_meta: &syn::NestedMeta) -> Self {
+        Self::new(
+            nested_meta.span(),
+            "#[validate(..., groups(???, ...))] allow only group name ident.",
+        )
+    }
+
+    pub fn validate_contains_need_item(path: &syn::Path) -> Self {
+        Self::new(path.span(), "`contains` need an item.")
+    }
+
+    pub fn validate_count_contains_need_items(path: &syn::Path) -> Self {
+        let validation = quote!(#path);
+        Self::new(
+            path.span(),
+            format!("#[validate({validation}(???, ...))] need a count literal and an item."),
+        )
+    }
+
+    pub fn validate_contains_allow_matcher(nested_meta: &syn::NestedMeta) -> Self {
+        Self::new(
+            nested_meta.span(),
+            "#[validate(contains(???))] allow only `custom(function)` or a literal.",
+        )
+    }
+
+    pub fn validate_unique_items_by_allow_function(value: &syn::Expr) -> Self {
+        Self::new(
+            value.span(),
+            "#[validate(unique_items_by = ???)] allow function path only.",
+        )
+    }
+
+    pub fn validate_property_names_need_validation(list: &syn::MetaList) -> Self {
+        Self::new(
+            list.span(),
+            "#[validate(property_names(???))] need a single validation.",
+        )
+    }
+
+    pub fn validate_pattern_properties_need_validation(list: &syn::MetaList) -> Self {
+        Self::new(
+            list.span(),
+            "#[validate(pattern_properties(\"pattern\", ???))] need a pattern and a single validation.",
+        )
+    }
+
+    pub fn validate_composition_need_item(path: &syn::Path) -> Self {
+        let composition = quote!(#path);
+        Self::new(path.span(), format!("`{composition}` need items."))
+    }
+
+    pub fn validate_not_allow_single_validation(list: &syn::MetaList) -> Self {
+        Self::new(
+            list.span(),
+            "#[validate(not(???))] allow a single validation.",
+        )
+    }
+
+    pub fn validate_condition_need_function(ident: &proc_macro2::Ident) -> Self {
+        Self::new(
+            ident.span(),
+            format!("#[validate({ident} = ???)] need a predicate function path."),
+        )
+    }
+
+    pub fn validate_condition_duplicated(predicate: &syn::Path, name: &str) -> Self {
+        Self::new(predicate.span(), format!("Too many `{name}` options."))
+    }
+
+    pub fn validate_skip_if_allow_single_option(tokens: &TokenStream) -> Self {
+        Self::new(
+            tokens.span(),
+            "#[validate(skip_if = ???)] allow no validation, use another #[validate(...)].",
+        )
+    }
+
+    pub fn message_template_invalid(template: &syn::LitStr) -> Self {
+        Self::new(
+            template.span(),
+            "Invalid message template. Use `{name}` for the error params, and `{{` or `}}` for braces.",
+        )
+    }
+
+    pub fn message_template_unresolved(template: &syn::LitStr) -> Self {
+        Self::new(
+            template.span(),
+            "This validation does not support `message`. Use `message_fn` instead.",
+        )
+    }
+
+    pub fn message_template_unknown_placeholder(
+        template: &syn::LitStr,
+        unknown: &str,
+        candidates: &[&str],
+    ) -> Self {
+        if candidates.is_empty() {
+            return Self::new(
+                template.span(),
+                format!(
+                    "Unknown placeholder: `{{{unknown}}}`. The validation has no error params."
+                ),
+            );
+        }
+        let filterd_candidates =
+            did_you_mean(unknown, candidates).unwrap_or_else(|| candidates.to_vec());
+
+        Self::new(
+            template.span(),
+            format!(
+                "Unknown placeholder: `{{{unknown}}}`. Is it one of the following?\n{filterd_candidates:#?}"
+            ),
+        )
+    }
+
     pub fn message_fn_need_item(path: &syn::Path) -> Self {
         Self::new(path.span(), "`message_fn` need items.")
     }
@@ -324,8 +569,8 @@ impl Error {
         Self::new(lit.span(), "Allow numeric literal only.")
     }
 
-    pub fn str_literal_only(lit: &syn::Lit) -> Self {
-        Self::new(lit.span(), "Allow str literal only.")
+    pub fn str_literal_only(value: &impl Spanned) -> Self {
+        Self::new(value.span(), "Allow str literal only.")
     }
 
     pub fn literal_not_support(lit: &syn::Lit) -> Self {
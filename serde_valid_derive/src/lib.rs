@@ -1,4 +1,5 @@
 #[warn(clippy::needless_collect)]
+mod context;
 mod derive;
 mod error;
 mod rule;
@@ -6,7 +7,7 @@ mod serde;
 mod types;
 mod validate;
 
-use derive::expand_derive;
+use derive::{expand_async_derive, expand_derive, expand_schema_derive};
 use error::to_compile_errors;
 use error::{Error, Errors};
 use proc_macro::TokenStream;
@@ -22,3 +23,23 @@ pub fn derive_validate(tokens: TokenStream) -> TokenStream {
         .unwrap_or_else(to_compile_errors)
         .into()
 }
+
+#[proc_macro_derive(ValidateSchema, attributes(validate, serde_valid))]
+#[proc_macro_error]
+pub fn derive_validate_schema(tokens: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(tokens as DeriveInput);
+
+    expand_schema_derive(&input)
+        .unwrap_or_else(to_compile_errors)
+        .into()
+}
+
+#[proc_macro_derive(AsyncValidate, attributes(rule_async, validate, serde_valid))]
+#[proc_macro_error]
+pub fn derive_async_validate(tokens: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(tokens as DeriveInput);
+
+    expand_async_derive(&input)
+        .unwrap_or_else(to_compile_errors)
+        .into()
+}
@@ -1,5 +1,36 @@
 mod named_struct_rule;
 mod unnamed_struct_rule;
 
-pub use named_struct_rule::collect_rules_from_named_struct;
-pub use unnamed_struct_rule::collect_rules_from_unnamed_struct;
+pub use named_struct_rule::{
+    collect_async_rules_from_named_struct, collect_rules_from_named_struct,
+};
+pub use unnamed_struct_rule::{
+    collect_async_rules_from_unnamed_struct, collect_rules_from_unnamed_struct,
+};
+
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::parse_quote;
+
+/// `#[rule(...)]` run by `Validate`, or `#[rule_async(...)]` run by `AsyncValidate`.
+#[derive(Debug, Clone, Copy)]
+enum RuleKind {
+    Sync,
+    Async,
+}
+
+impl RuleKind {
+    fn attribute_path(&self) -> syn::Path {
+        match self {
+            Self::Sync => parse_quote!(rule),
+            Self::Async => parse_quote!(rule_async),
+        }
+    }
+
+    fn await_token(&self) -> Option<TokenStream> {
+        match self {
+            Self::Sync => None,
+            Self::Async => Some(quote!(.await)),
+        }
+    }
+}
@@ -2,22 +2,43 @@ use std::collections::HashSet;
 
 use proc_macro2::TokenStream;
 use quote::{quote, ToTokens};
-use syn::parse_quote;
 use syn::spanned::Spanned;
 
+use super::RuleKind;
+use crate::serde::rename::RenameMap;
 use crate::types::CommaSeparatedTokenStreams;
 
 pub fn collect_rules_from_named_struct(
     attributes: &[syn::Attribute],
+    rename_map: &RenameMap,
+    context: Option<&syn::Type>,
+) -> Result<(HashSet<syn::Ident>, TokenStream), crate::Errors> {
+    collect_rules(attributes, rename_map, RuleKind::Sync, context)
+}
+
+pub fn collect_async_rules_from_named_struct(
+    attributes: &[syn::Attribute],
+    rename_map: &RenameMap,
+) -> Result<(HashSet<syn::Ident>, TokenStream), crate::Errors> {
+    collect_rules(attributes, rename_map, RuleKind::Async, None)
+}
+
+fn collect_rules(
+    attributes: &[syn::Attribute],
+    rename_map: &RenameMap,
+    kind: RuleKind,
+    context: Option<&syn::Type>,
 ) -> Result<(HashSet<syn::Ident>, TokenStream), crate::Errors> {
     let mut errors = vec![];
+    let rule_path = kind.attribute_path();
+    let rename = rename_fn_tokens(rename_map);
 
     let mut rule_fields = HashSet::new();
     let rules = attributes
         .iter()
-        .filter(|attribute| attribute.path == parse_quote!(rule))
+        .filter(|attribute| attribute.path == rule_path)
         .filter_map(|attribute| match attribute.parse_meta() {
-            Ok(syn::Meta::List(list)) => match collect_rule(&list) {
+            Ok(syn::Meta::List(list)) => match collect_rule(&list, &rename, kind, context) {
                 Ok((field_ident, stream)) => {
                     rule_fields.extend(field_ident);
                     Some(stream)
@@ -51,6 +72,9 @@ fn collect_rule(
     syn::MetaList {
         path, ref nested, ..
     }: &syn::MetaList,
+    rename: &TokenStream,
+    kind: RuleKind,
+    context: Option<&syn::Type>,
 ) -> Result<(HashSet<syn::Ident>, TokenStream), crate::Errors> {
     let mut errors = vec![];
 
@@ -64,7 +88,7 @@ fn collect_rule(
 
     let rule = match &nested[0] {
         syn::NestedMeta::Meta(meta) => match meta {
-            syn::Meta::List(list) => extract_rule_from_meta_list(list),
+            syn::Meta::List(list) => extract_rule_from_meta_list(list, rename, kind, context),
             syn::Meta::NameValue(name_value) => {
                 Err(vec![crate::Error::meta_name_value_not_support(name_value)])
             }
@@ -81,7 +105,7 @@ fn collect_rule(
                 Err(errors)
             }
         }
-        Err(rule_errors) => Err(errors.into_iter().chain(rule_errors.into_iter()).collect()),
+        Err(rule_errors) => Err(errors.into_iter().chain(rule_errors).collect()),
     }
 }
 
@@ -91,6 +115,9 @@ fn extract_rule_from_meta_list(
         ref nested,
         ..
     }: &syn::MetaList,
+    rename: &TokenStream,
+    kind: RuleKind,
+    context: Option<&syn::Type>,
 ) -> Result<(HashSet<syn::Ident>, TokenStream), crate::Errors> {
     let mut errors = vec![];
 
@@ -120,7 +147,9 @@ fn extract_rule_from_meta_list(
             }
             arg
         })
+        .chain(context.map(|_| quote!(ctx)))
         .collect::<CommaSeparatedTokenStreams>();
+    let await_token = kind.await_token();
 
     if !errors.is_empty() {
         return Err(errors);
@@ -129,9 +158,30 @@ fn extract_rule_from_meta_list(
     Ok((
         arg_idents,
         quote!(
-            if let Err(__error) = #rule_fn_name(#rule_fn_args) {
-                __rule_vec_errors.push(__error);
+            if let Err(__error) = #rule_fn_name(#rule_fn_args) #await_token {
+                use ::serde_valid::validation::IntoRuleErrors;
+
+                for __rule_error in __error.into_rule_errors() {
+                    __rule_error.push_to_properties(
+                        &mut __rule_vec_errors,
+                        &mut __property_vec_errors_map,
+                        #rename,
+                    );
+                }
             };
         ),
     ))
 }
+
+/// Maps the Rust field names in rule errors to their serde names.
+fn rename_fn_tokens(rename_map: &RenameMap) -> TokenStream {
+    let arms = TokenStream::from_iter(rename_map.iter().map(|(field_name, rename)| {
+        let field_name = field_name.strip_prefix("r#").unwrap_or(field_name);
+        quote!(#field_name => Some(#rename),)
+    }));
+
+    quote!(|__property: &str| match __property {
+        #arms
+        _ => None,
+    })
+}
@@ -2,21 +2,37 @@ use std::collections::HashSet;
 
 use proc_macro2::TokenStream;
 use quote::quote;
-use syn::parse_quote;
 
+use super::RuleKind;
 use crate::types::CommaSeparatedTokenStreams;
 
 pub fn collect_rules_from_unnamed_struct(
     attributes: &[syn::Attribute],
+    context: Option<&syn::Type>,
+) -> Result<(HashSet<syn::Ident>, TokenStream), crate::Errors> {
+    collect_rules(attributes, RuleKind::Sync, context)
+}
+
+pub fn collect_async_rules_from_unnamed_struct(
+    attributes: &[syn::Attribute],
+) -> Result<(HashSet<syn::Ident>, TokenStream), crate::Errors> {
+    collect_rules(attributes, RuleKind::Async, None)
+}
+
+fn collect_rules(
+    attributes: &[syn::Attribute],
+    kind: RuleKind,
+    context: Option<&syn::Type>,
 ) -> Result<(HashSet<syn::Ident>, TokenStream), crate::Errors> {
     let mut errors = vec![];
+    let rule_path = kind.attribute_path();
 
     let mut rule_fields = HashSet::new();
     let rules = attributes
         .iter()
-        .filter(|attribute| attribute.path == parse_quote!(rule))
+        .filter(|attribute| attribute.path == rule_path)
         .filter_map(|attribute| match attribute.parse_meta() {
-            Ok(syn::Meta::List(list)) => match collect_rule(&list) {
+            Ok(syn::Meta::List(list)) => match collect_rule(&list, kind, context) {
                 Ok((field_ident, stream)) => {
                     rule_fields.extend(field_ident);
                     Some(stream)
@@ -50,6 +66,8 @@ fn collect_rule(
     syn::MetaList {
         path, ref nested, ..
     }: &syn::MetaList,
+    kind: RuleKind,
+    context: Option<&syn::Type>,
 ) -> Result<(HashSet<syn::Ident>, TokenStream), crate::Errors> {
     let mut errors = vec![];
 
@@ -63,7 +81,7 @@ fn collect_rule(
 
     let rule = match &nested[0] {
         syn::NestedMeta::Meta(meta) => match meta {
-            syn::Meta::List(list) => extract_rule_from_meta_list(list),
+            syn::Meta::List(list) => extract_rule_from_meta_list(list, kind, context),
             syn::Meta::NameValue(name_value) => {
                 Err(vec![crate::Error::meta_name_value_not_support(name_value)])
             }
@@ -80,7 +98,7 @@ fn collect_rule(
                 Err(errors)
             }
         }
-        Err(rule_errors) => Err(errors.into_iter().chain(rule_errors.into_iter()).collect()),
+        Err(rule_errors) => Err(errors.into_iter().chain(rule_errors).collect()),
     }
 }
 
@@ -90,6 +108,8 @@ fn extract_rule_from_meta_list(
         ref nested,
         ..
     }: &syn::MetaList,
+    kind: RuleKind,
+    context: Option<&syn::Type>,
 ) -> Result<(HashSet<syn::Ident>, TokenStream), crate::Errors> {
     let mut errors = vec![];
 
@@ -120,7 +140,9 @@ fn extract_rule_from_meta_list(
             }
             arg
         })
+        .chain(context.map(|_| quote!(ctx)))
         .collect::<CommaSeparatedTokenStreams>();
+    let await_token = kind.await_token();
 
     if !errors.is_empty() {
         return Err(errors);
@@ -129,9 +151,12 @@ fn extract_rule_from_meta_list(
     Ok((
         arg_idents,
         quote!(
-            if let Err(__error) = #rule_fn_name(#rule_fn_args) {
-                __rule_vec_errors
-                    .push(__error);
+            if let Err(__error) = #rule_fn_name(#rule_fn_args) #await_token {
+                use ::serde_valid::validation::IntoRuleErrors;
+
+                for __rule_error in __error.into_rule_errors() {
+                    __rule_error.push_to_items(&mut __rule_vec_errors, &mut __item_vec_errors_map);
+                }
             };
         ),
     ))
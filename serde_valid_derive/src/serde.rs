@@ -1 +1,19 @@
+pub mod flatten;
 pub mod rename;
+pub mod representation;
+
+/// Whether a `#[serde(...)]` attribute has the flag `name`, such as `flatten` or `untagged`.
+pub fn has_serde_flag(attributes: &[syn::Attribute], name: &str) -> bool {
+    attributes
+        .iter()
+        .filter(|attribute| attribute.path.is_ident("serde"))
+        .any(|attribute| match attribute.parse_meta() {
+            Ok(syn::Meta::List(serde_list)) => serde_list.nested.iter().any(|serde_nested_meta| {
+                matches!(
+                    serde_nested_meta,
+                    syn::NestedMeta::Meta(syn::Meta::Path(path)) if path.is_ident(name)
+                )
+            }),
+            _ => false,
+        })
+}
@@ -0,0 +1,6 @@
+use super::has_serde_flag;
+
+/// Whether the field has `#[serde(flatten)]`.
+pub fn is_serde_flatten(attributes: &[syn::Attribute]) -> bool {
+    has_serde_flag(attributes, "flatten")
+}
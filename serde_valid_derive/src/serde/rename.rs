@@ -2,31 +2,164 @@ use std::collections::HashMap;
 
 use crate::types::{Field, NamedField};
 use proc_macro2::TokenStream;
-use quote::ToTokens;
+use quote::{quote, ToTokens};
 use syn::parse_quote;
 
 pub type RenameMap = HashMap<String, TokenStream>;
 
-pub fn collect_serde_rename_map(fields: &syn::FieldsNamed) -> RenameMap {
+/// Casing convention of `#[serde(rename_all = "...")]`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RenameRule {
+    Lower,
+    Upper,
+    Pascal,
+    Camel,
+    Snake,
+    ScreamingSnake,
+    Kebab,
+    ScreamingKebab,
+}
+
+impl RenameRule {
+    fn from_str(rule: &str) -> Option<Self> {
+        match rule {
+            "lowercase" => Some(Self::Lower),
+            "UPPERCASE" => Some(Self::Upper),
+            "PascalCase" => Some(Self::Pascal),
+            "camelCase" => Some(Self::Camel),
+            "snake_case" => Some(Self::Snake),
+            "SCREAMING_SNAKE_CASE" => Some(Self::ScreamingSnake),
+            "kebab-case" => Some(Self::Kebab),
+            "SCREAMING-KEBAB-CASE" => Some(Self::ScreamingKebab),
+            _ => None,
+        }
+    }
+
+    /// Rename a `PascalCase` variant name the way serde does.
+    pub fn apply_to_variant(self, variant: &str) -> String {
+        match self {
+            Self::Pascal => variant.to_owned(),
+            Self::Lower => variant.to_ascii_lowercase(),
+            Self::Upper => variant.to_ascii_uppercase(),
+            Self::Camel => {
+                let mut chars = variant.chars();
+                chars
+                    .next()
+                    .map(|first| first.to_ascii_lowercase().to_string() + chars.as_str())
+                    .unwrap_or_default()
+            }
+            Self::Snake => {
+                let mut snake = String::new();
+                for (index, ch) in variant.char_indices() {
+                    if index > 0 && ch.is_uppercase() {
+                        snake.push('_');
+                    }
+                    snake.push(ch.to_ascii_lowercase());
+                }
+                snake
+            }
+            Self::ScreamingSnake => Self::Snake.apply_to_variant(variant).to_ascii_uppercase(),
+            Self::Kebab => Self::Snake.apply_to_variant(variant).replace('_', "-"),
+            Self::ScreamingKebab => Self::ScreamingSnake
+                .apply_to_variant(variant)
+                .replace('_', "-"),
+        }
+    }
+
+    /// Rename a `snake_case` field name the way serde does.
+    pub fn apply_to_field(self, field: &str) -> String {
+        match self {
+            Self::Lower | Self::Snake => field.to_owned(),
+            Self::Upper | Self::ScreamingSnake => field.to_ascii_uppercase(),
+            Self::Pascal => {
+                let mut pascal = String::new();
+                let mut capitalize = true;
+                for ch in field.chars() {
+                    if ch == '_' {
+                        capitalize = true;
+                    } else if capitalize {
+                        pascal.push(ch.to_ascii_uppercase());
+                        capitalize = false;
+                    } else {
+                        pascal.push(ch);
+                    }
+                }
+                pascal
+            }
+            Self::Camel => {
+                let pascal = Self::Pascal.apply_to_field(field);
+                let mut chars = pascal.chars();
+                chars
+                    .next()
+                    .map(|first| first.to_ascii_lowercase().to_string() + chars.as_str())
+                    .unwrap_or_default()
+            }
+            Self::Kebab => field.replace('_', "-"),
+            Self::ScreamingKebab => Self::ScreamingSnake.apply_to_field(field).replace('_', "-"),
+        }
+    }
+}
+
+/// `#[serde(rename_all = "...")]` of a struct or an enum variant.
+pub fn find_serde_rename_all(attributes: &[syn::Attribute]) -> Option<RenameRule> {
+    find_serde_rename_rule(attributes, "rename_all")
+}
+
+/// Fields of an enum variant follow the variant `rename_all`,
+/// then the enum `rename_all_fields`.
+pub fn find_serde_variant_rename_all(
+    enum_attributes: &[syn::Attribute],
+    variant_attributes: &[syn::Attribute],
+) -> Option<RenameRule> {
+    find_serde_rename_all(variant_attributes)
+        .or_else(|| find_serde_rename_rule(enum_attributes, "rename_all_fields"))
+}
+
+fn find_serde_rename_rule(attributes: &[syn::Attribute], name: &str) -> Option<RenameRule> {
+    find_serde_name(attributes, name).and_then(|rule| RenameRule::from_str(&rule.value()))
+}
+
+/// `name = "..."` or `name(deserialize = "...")` in the `#[serde(...)]` attributes.
+pub fn find_serde_name(attributes: &[syn::Attribute], name: &str) -> Option<syn::LitStr> {
+    attributes
+        .iter()
+        .find_map(|attribute| find_name_from_serde_attribute(attribute, name))
+}
+
+pub fn collect_serde_rename_map(
+    fields: &syn::FieldsNamed,
+    rename_all: Option<RenameRule>,
+) -> RenameMap {
     let mut renames = RenameMap::new();
     for field in fields.named.iter() {
         let named_field = NamedField::new(field);
-        for attribute in named_field.attrs() {
-            if attribute.path == parse_quote!(serde) {
-                if let Some(rename) = find_rename_from_serde_attributes(attribute) {
-                    renames.insert(field.ident.to_token_stream().to_string(), rename);
-                }
-            }
+        let rename = find_serde_name(named_field.attrs(), "rename")
+            .map(|rename| rename.to_token_stream())
+            .or_else(|| {
+                rename_all.map(|rule| {
+                    let name = named_field.name();
+                    let rename = rule.apply_to_field(name.strip_prefix("r#").unwrap_or(name));
+                    quote!(#rename)
+                })
+            });
+        if let Some(rename) = rename {
+            renames.insert(field.ident.to_token_stream().to_string(), rename);
         }
     }
     renames
 }
 
-fn find_rename_from_serde_attributes(attribute: &syn::Attribute) -> Option<TokenStream> {
+fn find_name_from_serde_attribute(attribute: &syn::Attribute, name: &str) -> Option<syn::LitStr> {
+    if attribute.path != parse_quote!(serde) {
+        return None;
+    }
     if let Ok(syn::Meta::List(serde_list)) = attribute.parse_meta() {
         for serde_nested_meta in serde_list.nested {
             if let syn::NestedMeta::Meta(serde_meta) = &serde_nested_meta {
-                if let Some(rename) = find_rename_from_serde_rename_attributes(serde_meta) {
+                if !serde_meta.path().is_ident(name) {
+                    continue;
+                }
+                if let Some(rename) = find_name_from_serde_rename_attributes(serde_meta) {
                     return Some(rename);
                 }
             }
@@ -35,11 +168,11 @@ fn find_rename_from_serde_attributes(attribute: &syn::Attribute) -> Option<Token
     None
 }
 
-fn find_rename_from_serde_rename_attributes(serde_meta: &syn::Meta) -> Option<TokenStream> {
+fn find_name_from_serde_rename_attributes(serde_meta: &syn::Meta) -> Option<syn::LitStr> {
     match serde_meta {
         syn::Meta::NameValue(rename_name_value) => {
             if let syn::Lit::Str(lit_str) = &rename_name_value.lit {
-                Some(lit_str.to_token_stream())
+                Some(lit_str.clone())
             } else {
                 None
             }
@@ -52,7 +185,7 @@ fn find_rename_from_serde_rename_attributes(serde_meta: &syn::Meta) -> Option<To
                     }
                     if let syn::Meta::NameValue(deserialize_name_value) = rename_meta {
                         if let syn::Lit::Str(lit_str) = &deserialize_name_value.lit {
-                            return Some(lit_str.to_token_stream());
+                            return Some(lit_str.clone());
                         }
                     }
                 }
@@ -0,0 +1,64 @@
+use super::has_serde_flag;
+use super::rename::{find_serde_name, find_serde_rename_all};
+
+/// Serde representation of an enum.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum EnumRepresentation {
+    /// `{"Variant": {...}}`
+    External,
+    /// `#[serde(tag = "type")]`: `{"type": "Variant", ...}`
+    Internal { tag: String },
+    /// `#[serde(tag = "type", content = "data")]`: `{"type": "Variant", "data": {...}}`
+    Adjacent { tag: String, content: String },
+    /// `#[serde(untagged)]`: `{...}`
+    Untagged,
+}
+
+impl EnumRepresentation {
+    pub fn from_attributes(attributes: &[syn::Attribute]) -> Self {
+        if has_serde_flag(attributes, "untagged") {
+            return Self::Untagged;
+        }
+        match (
+            find_serde_name(attributes, "tag"),
+            find_serde_name(attributes, "content"),
+        ) {
+            (Some(tag), Some(content)) => Self::Adjacent {
+                tag: tag.value(),
+                content: content.value(),
+            },
+            (Some(tag), None) => Self::Internal { tag: tag.value() },
+            (None, _) => Self::External,
+        }
+    }
+
+    /// Property under which the fields of `variant` are serialized,
+    /// or `None` when they sit in the enum object itself.
+    pub fn variant_errors_key(
+        &self,
+        enum_attributes: &[syn::Attribute],
+        variant: &syn::Variant,
+    ) -> Option<String> {
+        match self {
+            Self::External => Some(find_serde_variant_name(enum_attributes, variant)),
+            Self::Adjacent { content, .. } => Some(content.to_owned()),
+            Self::Internal { .. } | Self::Untagged => None,
+        }
+    }
+}
+
+/// Serialized name of `variant`, from the variant `rename` or the enum `rename_all`.
+pub fn find_serde_variant_name(
+    enum_attributes: &[syn::Attribute],
+    variant: &syn::Variant,
+) -> String {
+    if let Some(rename) = find_serde_name(&variant.attrs, "rename") {
+        return rename.value();
+    }
+    let name = variant.ident.to_string();
+    let name = name.strip_prefix("r#").unwrap_or(&name);
+    match find_serde_rename_all(enum_attributes) {
+        Some(rule) => rule.apply_to_variant(name),
+        None => name.to_owned(),
+    }
+}
@@ -13,10 +13,13 @@ pub trait Field {
 
     fn errors_variable(&self) -> proc_macro2::TokenStream;
 
+    fn errors_map_type(&self) -> proc_macro2::TokenStream;
+
     fn getter_token(&self) -> proc_macro2::TokenStream;
 
     fn attrs(&self) -> &Vec<syn::Attribute>;
 
+    #[allow(dead_code)]
     fn vis(&self) -> &syn::Visibility;
 
     fn ty(&self) -> &syn::Type;
@@ -40,6 +40,10 @@ impl<'a> Field for NamedField<'a> {
         quote!(__property_vec_errors_map)
     }
 
+    fn errors_map_type(&self) -> proc_macro2::TokenStream {
+        quote!(::serde_valid::validation::PropertyVecErrorsMap)
+    }
+
     fn getter_token(&self) -> proc_macro2::TokenStream {
         let ident = self.ident();
         quote!(#ident)
@@ -45,6 +45,10 @@ impl<'a> Field for UnnamedField<'a> {
         quote!(__item_vec_errors_map)
     }
 
+    fn errors_map_type(&self) -> proc_macro2::TokenStream {
+        quote!(::serde_valid::validation::ItemVecErrorsMap)
+    }
+
     fn getter_token(&self) -> proc_macro2::TokenStream {
         let index = syn::Index::from(self.index);
         quote!(#index)
@@ -2,11 +2,18 @@ mod array;
 mod common;
 mod field;
 mod generic;
+mod limits;
 mod meta;
 mod numeric;
 mod object;
+mod schema;
 mod string;
 
+#[cfg(feature = "fluent")]
 pub use common::MetaListMessage;
+pub use common::{extract_skip_if, is_skip_if, wrap_validators_with_skip_if};
 pub use field::{FieldValidators, Validator};
-pub use meta::extract_meta_validator;
+pub use generic::collect_compared_fields;
+pub use limits::check_field_limits;
+pub use meta::{extract_async_meta_validator, extract_meta_validator, is_async_meta_validator};
+pub use schema::extract_field_schema;
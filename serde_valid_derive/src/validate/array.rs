@@ -1,4 +1,11 @@
+mod contains;
 mod length_items;
 mod unique_items;
+pub use contains::{
+    extract_array_contains_validator, extract_array_contains_value_validator,
+    extract_array_max_contains_validator, extract_array_min_contains_validator,
+};
 pub use length_items::{extract_array_max_items_validator, extract_array_min_items_validator};
-pub use unique_items::extract_array_unique_items_validator;
+pub use unique_items::{
+    extract_array_unique_items_by_validator, extract_array_unique_items_validator,
+};
@@ -0,0 +1,162 @@
+use crate::serde::rename::RenameMap;
+use crate::types::Field;
+use crate::validate::common::{get_numeric, CustomMessageToken};
+use crate::validate::Validator;
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// `contains = "x"`, which matches the items equal to the value.
+pub fn extract_array_contains_value_validator(
+    field: &impl Field,
+    validation_value: &syn::Expr,
+    custom_message: CustomMessageToken,
+    rename_map: &RenameMap,
+) -> Result<Validator, crate::Errors> {
+    Ok(inner_extract_array_contains_validator(
+        field,
+        quote!(|__item| *__item == #validation_value),
+        custom_message,
+        rename_map,
+    ))
+}
+
+/// `contains(custom(is_admin))` or `contains("x")`.
+pub fn extract_array_contains_validator(
+    field: &impl Field,
+    syn::MetaList { path, nested, .. }: &syn::MetaList,
+    custom_message: CustomMessageToken,
+    rename_map: &RenameMap,
+) -> Result<Validator, crate::Errors> {
+    let predicate = match nested.len() {
+        0 => Err(crate::Error::validate_contains_need_item(path)),
+        1 => extract_contains_predicate(&nested[0]),
+        _ => Err(crate::Error::too_many_list_items(&nested[1])),
+    }
+    .map_err(|error| vec![error])?;
+
+    Ok(inner_extract_array_contains_validator(
+        field,
+        predicate,
+        custom_message,
+        rename_map,
+    ))
+}
+
+fn inner_extract_array_contains_validator(
+    field: &impl Field,
+    predicate: TokenStream,
+    custom_message: CustomMessageToken,
+    rename_map: &RenameMap,
+) -> Validator {
+    let field_name = field.name();
+    let field_ident = field.ident();
+    let field_key = field.key();
+    let rename = rename_map.get(field_name).unwrap_or(&field_key);
+    let errors = field.errors_variable();
+    let message_fn =
+        custom_message.message_fn_or(quote!(::serde_valid::ContainsError::to_default_message));
+
+    quote!(
+        if let Err(error_params) = ::serde_valid::ValidateContains::validate_contains(
+            #field_ident,
+            #predicate,
+        ) {
+            use ::serde_valid::validation::ToDefaultMessage;
+
+            #errors
+                .entry((#rename).into())
+                .or_default()
+                .push(::serde_valid::validation::Error::Contains(
+                    ::serde_valid::validation::Message::new(
+                        error_params,
+                        #message_fn,
+                    )
+                ));
+        }
+    )
+}
+
+/// Count validation of the matching items.
+///
+/// See <https://json-schema.org/understanding-json-schema/reference/array.html#mincontains-maxcontains>
+macro_rules! extract_array_count_contains_validator {
+    ($ErrorType:ident) => {
+        paste::paste! {
+            /// `min_contains(2, custom(is_admin))` or `max_contains(1, "x")`.
+            pub fn [<extract_array_ $ErrorType:snake _validator>](
+                field: &impl Field,
+                syn::MetaList { path, nested, .. }: &syn::MetaList,
+                custom_message: CustomMessageToken,
+                rename_map: &RenameMap,
+            ) -> Result<Validator, crate::Errors> {
+                let field_name = field.name();
+                let field_ident = field.ident();
+                let field_key = field.key();
+                let rename = rename_map.get(field_name).unwrap_or(&field_key);
+                let errors = field.errors_variable();
+                let message_fn = custom_message
+                    .message_fn_or(quote!(::serde_valid::[<$ErrorType Error>]::to_default_message));
+
+                let (count, predicate) = match (nested.iter().next(), nested.iter().nth(1)) {
+                    (Some(syn::NestedMeta::Lit(count)), Some(item)) => {
+                        let count = syn::Expr::Lit(syn::ExprLit {
+                            attrs: vec![],
+                            lit: count.clone(),
+                        });
+                        let count = get_numeric(&count)?;
+                        (quote!(#count), extract_contains_predicate(item).map_err(|error| vec![error])?)
+                    }
+                    _ => return Err(vec![crate::Error::validate_count_contains_need_items(path)]),
+                };
+                if let Some(tail) = nested.iter().nth(2) {
+                    return Err(vec![crate::Error::too_many_list_items(tail)]);
+                }
+
+                Ok(quote!(
+                    if let Err(error_params) = ::serde_valid::[<Validate $ErrorType>]::[<validate_ $ErrorType:snake>](
+                        #field_ident,
+                        #predicate,
+                        #count,
+                    ) {
+                        use ::serde_valid::validation::ToDefaultMessage;
+
+                        #errors
+                            .entry((#rename).into())
+                            .or_default()
+                            .push(::serde_valid::validation::Error::$ErrorType(
+                                ::serde_valid::validation::Message::new(
+                                    error_params,
+                                    #message_fn,
+                                )
+                            ));
+                    }
+                ))
+            }
+        }
+    }
+}
+
+extract_array_count_contains_validator!(MinContains);
+extract_array_count_contains_validator!(MaxContains);
+
+/// Predicate of the matching items, from `custom(is_admin)` or a literal.
+fn extract_contains_predicate(nested_meta: &syn::NestedMeta) -> Result<TokenStream, crate::Error> {
+    match nested_meta {
+        syn::NestedMeta::Meta(syn::Meta::List(syn::MetaList { path, nested, .. }))
+            if path.is_ident("custom") =>
+        {
+            match nested.len() {
+                0 => Err(crate::Error::validate_custom_need_item(path)),
+                1 => match &nested[0] {
+                    syn::NestedMeta::Meta(syn::Meta::Path(fn_name)) => {
+                        Ok(quote!(|__item| #fn_name(__item)))
+                    }
+                    nested_meta => Err(crate::Error::validate_contains_allow_matcher(nested_meta)),
+                },
+                _ => Err(crate::Error::validate_custom_tail_error(nested)),
+            }
+        }
+        syn::NestedMeta::Lit(lit) => Ok(quote!(|__item| *__item == #lit)),
+        _ => Err(crate::Error::validate_contains_allow_matcher(nested_meta)),
+    }
+}
@@ -13,7 +13,7 @@ macro_rules! extract_array_length_validator{
         paste::paste! {
             pub fn [<extract_array_ $ErrorType:snake _validator>](
                 field: &impl Field,
-                validation_value: &syn::Lit,
+                validation_value: &syn::Expr,
                 custom_message: CustomMessageToken,
                 rename_map: &RenameMap,
             ) -> Result<Validator, crate::Errors> {
@@ -22,7 +22,7 @@ macro_rules! extract_array_length_validator{
 
             fn [<inner_extract_array_ $ErrorType:snake _validator>](
                 field: &impl Field,
-                validation_value: &syn::Lit,
+                validation_value: &syn::Expr,
                 custom_message: CustomMessageToken,
                 rename_map: &RenameMap,
             ) -> Result<TokenStream, crate::Errors> {
@@ -33,7 +33,7 @@ macro_rules! extract_array_length_validator{
                 let [<$ErrorType:snake>] = get_numeric(validation_value)?;
                 let errors = field.errors_variable();
                 let message_fn = custom_message
-                    .message_fn.unwrap_or(quote!(::serde_valid::[<$ErrorType Error>]::to_default_message));
+                    .message_fn_or(quote!(::serde_valid::[<$ErrorType Error>]::to_default_message));
 
                 Ok(quote!(
                     if let Err(error_params) = ::serde_valid::[<Validate $ErrorType>]::[<validate_ $ErrorType:snake>](
@@ -43,7 +43,7 @@ macro_rules! extract_array_length_validator{
                         use ::serde_valid::validation::ToDefaultMessage;
 
                         #errors
-                            .entry(#rename)
+                            .entry((#rename).into())
                             .or_default()
                             .push(::serde_valid::validation::Error::$ErrorType(
                                 ::serde_valid::validation::Message::new(
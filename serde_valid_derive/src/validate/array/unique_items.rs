@@ -11,31 +11,63 @@ pub fn extract_array_unique_items_validator(
     custom_message: CustomMessageToken,
     rename_map: &RenameMap,
 ) -> Validator {
-    inner_extract_array_unique_items_validator(field, custom_message, rename_map)
+    let field_ident = field.ident();
+
+    inner_extract_array_unique_items_validator(
+        field,
+        quote!({
+            use ::serde_valid::export::{ValidateUniqueItemsByEq as _, ValidateUniqueItemsByHash as _};
+            (&::serde_valid::export::UniqueItemsField(#field_ident)).validate()
+        }),
+        custom_message,
+        rename_map,
+    )
+}
+
+/// `unique_items_by = user_id`, which compares the items by the keys of the function.
+pub fn extract_array_unique_items_by_validator(
+    field: &impl Field,
+    validation_value: &syn::Expr,
+    custom_message: CustomMessageToken,
+    rename_map: &RenameMap,
+) -> Result<Validator, crate::Errors> {
+    let field_ident = field.ident();
+    if !matches!(validation_value, syn::Expr::Path(_)) {
+        return Err(vec![crate::Error::validate_unique_items_by_allow_function(
+            validation_value,
+        )]);
+    }
+
+    Ok(inner_extract_array_unique_items_validator(
+        field,
+        quote!(::serde_valid::ValidateUniqueItemsBy::validate_unique_items_by(
+            #field_ident,
+            #validation_value,
+        )),
+        custom_message,
+        rename_map,
+    ))
 }
 
 fn inner_extract_array_unique_items_validator(
     field: &impl Field,
+    validation: TokenStream,
     custom_message: CustomMessageToken,
     rename_map: &RenameMap,
 ) -> TokenStream {
     let field_name = field.name();
-    let field_ident = field.ident();
     let field_key = field.key();
     let rename = rename_map.get(field_name).unwrap_or(&field_key);
     let errors = field.errors_variable();
-    let message_fn = custom_message
-        .message_fn
-        .unwrap_or(quote!(::serde_valid::UniqueItemsError::to_default_message));
+    let message_fn =
+        custom_message.message_fn_or(quote!(::serde_valid::UniqueItemsError::to_default_message));
 
     quote!(
-        if let Err(error_params) = ::serde_valid::ValidateUniqueItems::validate_unique_items(
-            #field_ident
-        ) {
+        if let Err(error_params) = #validation {
             use ::serde_valid::validation::ToDefaultMessage;
 
             #errors
-                .entry(#rename)
+                .entry((#rename).into())
                 .or_default()
                 .push(::serde_valid::validation::Error::UniqueItems(
                     ::serde_valid::validation::Message::new(
@@ -1,8 +1,17 @@
+mod condition;
 mod custom_message;
+mod groups;
 mod lit;
+mod message_template;
 
+pub use condition::{
+    extract_skip_if, is_skip_if, split_when_option, wrap_validator_with_when,
+    wrap_validators_with_skip_if,
+};
 pub use custom_message::{extract_custom_message_tokens, CustomMessageToken};
-pub use lit::{get_numeric, get_str};
+pub use groups::{extract_groups, wrap_validator_with_groups, Groups};
+pub use lit::{get_numeric, get_numeric_literal_value, get_str};
+pub use message_template::ErrorParams;
 
 macro_rules! count {
     () => (0usize);
@@ -71,6 +80,16 @@ enum_str! {
     pub enum MetaListValidation {
         Enumerate = "enumerate",
         Custom = "custom",
+        CustomAsync = "custom_async",
+        AnyOf = "any_of",
+        AllOf = "all_of",
+        OneOf = "one_of",
+        Not = "not",
+        Contains = "contains",
+        MinContains = "min_contains",
+        MaxContains = "max_contains",
+        PropertyNames = "property_names",
+        PatternProperties = "pattern_properties",
     }
 }
 
@@ -88,6 +107,30 @@ enum_str! {
         MaxProperties = "max_properties",
         MultipleOf = "multiple_of",
         Pattern = "pattern",
+        Format = "format",
+        GreaterThan = "greater_than",
+        GreaterThanOrEqual = "greater_than_or_equal",
+        LessThan = "less_than",
+        LessThanOrEqual = "less_than_or_equal",
+        Equals = "equals",
+        NotEquals = "not_equals",
+        Contains = "contains",
+        UniqueItemsBy = "unique_items_by",
+    }
+}
+
+impl MetaNameValueValidation {
+    /// Whether the validation compares with a sibling field.
+    pub fn is_compare(&self) -> bool {
+        matches!(
+            self,
+            Self::GreaterThan
+                | Self::GreaterThanOrEqual
+                | Self::LessThan
+                | Self::LessThanOrEqual
+                | Self::Equals
+                | Self::NotEquals
+        )
     }
 }
 
@@ -113,6 +156,12 @@ enum_str! {
     }
 }
 
+enum_str! {
+    pub enum MetaListOption {
+        Groups = "groups",
+    }
+}
+
 enum_str! {
     pub enum MetaNameValueMessage {
         Message = "message",
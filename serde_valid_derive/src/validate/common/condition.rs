@@ -0,0 +1,159 @@
+use std::borrow::Cow;
+
+use proc_macro2::{Delimiter, Group, TokenStream, TokenTree};
+use quote::{quote, ToTokens};
+
+use crate::validate::Validator;
+
+/// `#[validate(..., when = predicate)]`, with the `when` option removed from the attribute.
+///
+/// The predicate is a function path receiving `&Self`.
+pub fn split_when_option(
+    attribute: &syn::Attribute,
+) -> Result<(Cow<'_, syn::Attribute>, Option<syn::Path>), crate::Errors> {
+    let Some(group) = attribute_args(attribute) else {
+        return Ok((Cow::Borrowed(attribute), None));
+    };
+
+    let mut errors = vec![];
+    let mut when = None;
+    let mut items = vec![];
+    for item in split_by_comma(group.stream()) {
+        match split_name_value(&item, "when") {
+            Some(Ok(predicate)) if when.is_none() => when = Some(predicate),
+            Some(Ok(predicate)) => errors.push(crate::Error::validate_condition_duplicated(
+                &predicate, "when",
+            )),
+            Some(Err(error)) => errors.push(error),
+            None => items.push(item),
+        }
+    }
+
+    if !errors.is_empty() {
+        return Err(errors);
+    }
+    if when.is_none() {
+        return Ok((Cow::Borrowed(attribute), None));
+    }
+
+    let mut args = Group::new(Delimiter::Parenthesis, join_by_comma(items));
+    args.set_span(group.span());
+
+    Ok((
+        Cow::Owned(syn::Attribute {
+            tokens: args.into_token_stream(),
+            ..attribute.clone()
+        }),
+        when,
+    ))
+}
+
+/// `#[validate(skip_if = predicate)]` of the field, which skips all its validators.
+///
+/// The predicate is a function path receiving `&Self`.
+pub fn extract_skip_if(attributes: &[syn::Attribute]) -> Result<Option<syn::Path>, crate::Errors> {
+    let mut errors = vec![];
+    let mut skip_if = None;
+
+    for attribute in attributes.iter().filter(|attribute| is_skip_if(attribute)) {
+        let Some(group) = attribute_args(attribute) else {
+            continue;
+        };
+        let mut items = split_by_comma(group.stream()).into_iter();
+        match items
+            .next()
+            .and_then(|item| split_name_value(&item, "skip_if"))
+        {
+            Some(Ok(predicate)) if skip_if.is_none() => skip_if = Some(predicate),
+            Some(Ok(predicate)) => errors.push(crate::Error::validate_condition_duplicated(
+                &predicate, "skip_if",
+            )),
+            Some(Err(error)) => errors.push(error),
+            None => {}
+        }
+        if let Some(tail) = items.next() {
+            errors.push(crate::Error::validate_skip_if_allow_single_option(&tail));
+        }
+    }
+
+    if errors.is_empty() {
+        Ok(skip_if)
+    } else {
+        Err(errors)
+    }
+}
+
+/// Whether the attribute is `#[validate(skip_if = ...)]`, which has no validator.
+pub fn is_skip_if(attribute: &syn::Attribute) -> bool {
+    attribute_args(attribute)
+        .and_then(|group| split_by_comma(group.stream()).into_iter().next())
+        .is_some_and(|item| split_name_value(&item, "skip_if").is_some())
+}
+
+/// Run the validator only when the predicate of `when` holds.
+pub fn wrap_validator_with_when(validator: Validator, when: Option<&syn::Path>) -> Validator {
+    match when {
+        Some(predicate) => quote!(
+            if #predicate(self) {
+                #validator
+            }
+        ),
+        None => validator,
+    }
+}
+
+/// Skip the validators of the field when the predicate of `skip_if` holds.
+pub fn wrap_validators_with_skip_if(
+    validators: Vec<Validator>,
+    skip_if: Option<&syn::Path>,
+) -> Vec<Validator> {
+    match skip_if {
+        Some(predicate) if !validators.is_empty() => vec![quote!(
+            if !#predicate(self) {
+                #(#validators)*
+            }
+        )],
+        _ => validators,
+    }
+}
+
+fn attribute_args(attribute: &syn::Attribute) -> Option<Group> {
+    match attribute.tokens.clone().into_iter().next() {
+        Some(TokenTree::Group(group)) if group.delimiter() == Delimiter::Parenthesis => Some(group),
+        _ => None,
+    }
+}
+
+/// `name = path`, or `None` when the item is not the `name` option.
+fn split_name_value(item: &TokenStream, name: &str) -> Option<Result<syn::Path, crate::Error>> {
+    let tokens = item.clone().into_iter().collect::<Vec<_>>();
+    match tokens.as_slice() {
+        [TokenTree::Ident(ident), TokenTree::Punct(eq), value @ ..]
+            if ident == name && eq.as_char() == '=' =>
+        {
+            let value = TokenStream::from_iter(value.iter().cloned());
+            Some(
+                syn::parse2::<syn::Path>(value)
+                    .map_err(|_| crate::Error::validate_condition_need_function(ident)),
+            )
+        }
+        _ => None,
+    }
+}
+
+fn split_by_comma(tokens: TokenStream) -> Vec<TokenStream> {
+    let mut items = vec![TokenStream::new()];
+    for token in tokens {
+        match &token {
+            TokenTree::Punct(punct) if punct.as_char() == ',' => items.push(TokenStream::new()),
+            _ => items.last_mut().unwrap().extend([token]),
+        }
+    }
+    items.retain(|item| !item.is_empty());
+    items
+}
+
+fn join_by_comma(items: Vec<TokenStream>) -> TokenStream {
+    let items = items.into_iter();
+    quote!(#(#items),*)
+}
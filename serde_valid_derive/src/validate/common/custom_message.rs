@@ -3,11 +3,12 @@ use proc_macro2::TokenStream;
 use quote::quote;
 use std::str::FromStr;
 
-use super::{get_str, MetaListMessage, MetaNameValueMessage, MetaPathMessage};
+use super::{ErrorParams, MetaListMessage, MetaListOption, MetaNameValueMessage, MetaPathMessage};
 
 #[derive(Debug, Default)]
 pub struct CustomMessageToken {
     pub message_fn: Option<TokenStream>,
+    pub message_template: Option<syn::LitStr>,
     #[cfg(feature = "fluent")]
     pub fluent_message: Option<TokenStream>,
 }
@@ -16,26 +17,74 @@ impl CustomMessageToken {
     pub fn new_message_fn(message_fn: TokenStream) -> Self {
         Self {
             message_fn: Some(message_fn),
+            message_template: None,
             #[cfg(feature = "fluent")]
             fluent_message: None,
         }
     }
 
+    pub fn new_message_template(message_template: syn::LitStr) -> Self {
+        Self {
+            message_fn: None,
+            message_template: Some(message_template),
+            #[cfg(feature = "fluent")]
+            fluent_message: None,
+        }
+    }
+
+    /// Resolve `message = "..."` into a `message_fn` filling the placeholders from `error_params`.
+    pub fn with_error_params(self, error_params: &ErrorParams) -> Result<Self, crate::Errors> {
+        match &self.message_template {
+            Some(template) => error_params
+                .message_fn(template)
+                .map(CustomMessageToken::new_message_fn),
+            None => Ok(self),
+        }
+    }
+
     #[cfg(feature = "fluent")]
     pub fn new_fluent_message(fluent_message: TokenStream) -> Self {
         Self {
             message_fn: None,
+            message_template: None,
             fluent_message: Some(fluent_message),
         }
     }
 
+    /// Whether `message`, `message_fn` or a fluent message is given.
+    pub fn is_specified(&self) -> bool {
+        #[cfg(feature = "fluent")]
+        let has_fluent_message = self.fluent_message.is_some();
+        #[cfg(not(feature = "fluent"))]
+        let has_fluent_message = false;
+
+        self.message_fn.is_some() || self.message_template.is_some() || has_fluent_message
+    }
+
+    /// The `message_fn`, or `default_message_fn` when none is given.
+    ///
+    /// A `message = "..."` template that `with_error_params` has not resolved
+    /// becomes a compile error instead of being dropped.
+    pub fn message_fn_or(&self, default_message_fn: TokenStream) -> TokenStream {
+        match (&self.message_fn, &self.message_template) {
+            (Some(message_fn), _) => message_fn.clone(),
+            (None, Some(template)) => {
+                crate::Error::message_template_unresolved(template).to_compile_error()
+            }
+            (None, None) => default_message_fn,
+        }
+    }
+
     pub fn into_token(self) -> TokenStream {
-        let message_fn = self.message_fn.unwrap_or(quote!(
+        let message_fn = self.message_fn_or(quote!(
             ::serde_valid::validation::ToDefaultMessage::to_default_message
         ));
 
         #[cfg(feature = "fluent")]
-        let fluent_message = quote!(fluent_message: None,);
+        let fluent_message = match self.fluent_message {
+            Some(fluent_message) => quote!(fluent_message: Some(#fluent_message),),
+            None => quote!(fluent_message: None,),
+        };
         #[cfg(not(feature = "fluent"))]
         let fluent_message = quote!();
 
@@ -115,8 +164,8 @@ fn extract_custom_message_tokens_from_meta_list(
                 crate::Error::validate_unknown_type(
                     path,
                     &unknown,
-                    &MetaListMessage::iter()
-                        .map(|x| x.name())
+                    &(MetaListMessage::iter().map(|x| x.name()))
+                        .chain(MetaListOption::iter().map(|x| x.name()))
                         .collect::<Vec<_>>(),
                 )
             };
@@ -133,7 +182,7 @@ fn extract_custom_message_tokens_from_name_value(
 
     match MetaNameValueMessage::from_str(&path_label) {
         Ok(MetaNameValueMessage::Message) => {
-            get_message_fn_from_lit(lit).map(CustomMessageToken::new_message_fn)
+            get_message_template_from_lit(lit).map(CustomMessageToken::new_message_template)
         }
         Err(unknown) => if MetaListMessage::from_str(&path_label).is_ok() {
             Err(crate::Error::validate_meta_list_need_value(
@@ -179,8 +228,11 @@ fn get_message_fn_from_nested_meta(
     }
 }
 
-fn get_message_fn_from_lit(lit: &syn::Lit) -> Result<TokenStream, crate::Errors> {
-    get_str(lit).map(|lit_str| quote!(|_| { #lit_str.to_string() }))
+fn get_message_template_from_lit(lit: &syn::Lit) -> Result<syn::LitStr, crate::Errors> {
+    match lit {
+        syn::Lit::Str(lit_str) => Ok(lit_str.to_owned()),
+        _ => Err(vec![crate::Error::str_literal_only(lit)]),
+    }
 }
 
 #[cfg(feature = "fluent")]
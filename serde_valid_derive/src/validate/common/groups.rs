@@ -0,0 +1,53 @@
+use crate::types::SingleIdentPath;
+use crate::validate::Validator;
+use quote::quote;
+
+pub type Groups = Vec<syn::LitStr>;
+
+pub fn extract_groups(
+    syn::MetaList { path, nested, .. }: &syn::MetaList,
+) -> Result<Groups, crate::Errors> {
+    if nested.is_empty() {
+        return Err(vec![crate::Error::validate_groups_need_item(path)]);
+    }
+
+    let mut errors = vec![];
+    let groups = nested
+        .iter()
+        .filter_map(|nested_meta| match nested_meta {
+            syn::NestedMeta::Meta(syn::Meta::Path(group)) if group.get_ident().is_some() => {
+                let group = SingleIdentPath::new(group).ident();
+                Some(syn::LitStr::new(&group.to_string(), group.span()))
+            }
+            _ => {
+                errors.push(crate::Error::validate_groups_allow_ident(nested_meta));
+                None
+            }
+        })
+        .collect();
+
+    if errors.is_empty() {
+        Ok(groups)
+    } else {
+        Err(errors)
+    }
+}
+
+/// Run the validator only when the active group matches.
+///
+/// Validators without groups belong to the default set,
+/// which runs when no group is active.
+pub fn wrap_validator_with_groups(validator: Validator, groups: Option<&Groups>) -> Validator {
+    match groups {
+        Some(groups) => quote!(
+            if matches!(__group, Some(#(#groups)|*)) {
+                #validator
+            }
+        ),
+        None => quote!(
+            if __group.is_none() {
+                #validator
+            }
+        ),
+    }
+}
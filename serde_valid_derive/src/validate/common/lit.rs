@@ -4,6 +4,8 @@ use quote::ToTokens;
 pub enum LitNumeric<'a> {
     Int(&'a syn::LitInt),
     Float(&'a syn::LitFloat),
+    /// Non-literal expression such as `ctx.max_upload`.
+    Expr(&'a syn::Expr),
 }
 
 impl<'a> ToTokens for LitNumeric<'a> {
@@ -11,21 +13,48 @@ impl<'a> ToTokens for LitNumeric<'a> {
         match self {
             LitNumeric::Int(lin) => lin.to_tokens(tokens),
             LitNumeric::Float(lin) => lin.to_tokens(tokens),
+            LitNumeric::Expr(expr) => expr.to_tokens(tokens),
         }
     }
 }
 
-pub fn get_numeric(lit: &syn::Lit) -> Result<LitNumeric, crate::Errors> {
-    match lit {
-        syn::Lit::Int(int) => Ok(LitNumeric::Int(int)),
-        syn::Lit::Float(float) => Ok(LitNumeric::Float(float)),
-        _ => Err(vec![crate::Error::numeric_literal_only(lit)]),
+pub fn get_numeric(expr: &syn::Expr) -> Result<LitNumeric<'_>, crate::Errors> {
+    match expr {
+        syn::Expr::Lit(syn::ExprLit { lit, .. }) => match lit {
+            syn::Lit::Int(int) => Ok(LitNumeric::Int(int)),
+            syn::Lit::Float(float) => Ok(LitNumeric::Float(float)),
+            _ => Err(vec![crate::Error::numeric_literal_only(lit)]),
+        },
+        _ => Ok(LitNumeric::Expr(expr)),
     }
 }
 
-pub fn get_str(lit: &syn::Lit) -> Result<&syn::LitStr, crate::Errors> {
-    match lit {
-        syn::Lit::Str(lit_str) => Ok(lit_str),
-        _ => Err(vec![crate::Error::str_literal_only(lit)]),
+pub fn get_str(expr: &syn::Expr) -> Result<&syn::LitStr, crate::Errors> {
+    match expr {
+        syn::Expr::Lit(syn::ExprLit {
+            lit: syn::Lit::Str(lit_str),
+            ..
+        }) => Ok(lit_str),
+        _ => Err(vec![crate::Error::str_literal_only(expr)]),
+    }
+}
+
+/// Value of a numeric literal such as `10`, `0.5` or `-5`.
+///
+/// Non-literal expressions have no value at compile time.
+pub fn get_numeric_literal_value(expr: &syn::Expr) -> Option<f64> {
+    match expr {
+        syn::Expr::Lit(syn::ExprLit { lit, .. }) => match lit {
+            syn::Lit::Int(int) => int.base10_parse().ok(),
+            syn::Lit::Float(float) => float.base10_parse().ok(),
+            _ => None,
+        },
+        syn::Expr::Unary(syn::ExprUnary {
+            op: syn::UnOp::Neg(_),
+            expr,
+            ..
+        }) => get_numeric_literal_value(expr).map(|value| -value),
+        syn::Expr::Paren(syn::ExprParen { expr, .. }) => get_numeric_literal_value(expr),
+        _ => None,
     }
 }
@@ -0,0 +1,152 @@
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+
+/// Error-params struct of a validation, whose fields fill the `{placeholder}`s
+/// of `message = "..."`.
+pub struct ErrorParams {
+    error_type: TokenStream,
+    fields: Vec<(&'static str, TokenStream)>,
+}
+
+impl ErrorParams {
+    /// Name value validations, whose error params have a single field named after the validation,
+    /// as `MaxLengthError::max_length` for `max_length`.
+    pub fn from_validation_name(validation_name: &'static str) -> Self {
+        let error_type = format_ident!(
+            "{}Error",
+            validation_name
+                .split('_')
+                .map(|word| word[..1].to_ascii_uppercase() + &word[1..])
+                .collect::<String>()
+        );
+        let field = format_ident!("{validation_name}");
+
+        Self {
+            error_type: quote!(::serde_valid::#error_type),
+            fields: vec![(validation_name, quote!(__error_params.#field))],
+        }
+    }
+
+    pub fn enumerate() -> Self {
+        Self {
+            error_type: quote!(::serde_valid::EnumerateError),
+            fields: vec![(
+                "enumerate",
+                quote!(__error_params
+                    .enumerate
+                    .iter()
+                    .map(ToString::to_string)
+                    .collect::<Vec<_>>()
+                    .join(", ")),
+            )],
+        }
+    }
+
+    pub fn unique_items() -> Self {
+        Self {
+            error_type: quote!(::serde_valid::UniqueItemsError),
+            fields: vec![(
+                "duplicates",
+                quote!(__error_params
+                    .duplicates
+                    .iter()
+                    .map(ToString::to_string)
+                    .collect::<Vec<_>>()
+                    .join(", ")),
+            )],
+        }
+    }
+
+    pub fn contains() -> Self {
+        Self {
+            error_type: quote!(::serde_valid::ContainsError),
+            fields: vec![],
+        }
+    }
+
+    pub fn not() -> Self {
+        Self {
+            error_type: quote!(::serde_valid::NotError),
+            fields: vec![],
+        }
+    }
+
+    /// `message_fn` formatting `template` with the fields of the error params.
+    pub fn message_fn(&self, template: &syn::LitStr) -> Result<TokenStream, crate::Errors> {
+        let placeholders = parse_placeholders(&template.value())
+            .ok_or_else(|| vec![crate::Error::message_template_invalid(template)])?;
+        let candidates = self
+            .fields
+            .iter()
+            .map(|(name, _)| *name)
+            .collect::<Vec<_>>();
+
+        let mut errors = vec![];
+        let mut args = vec![];
+        for placeholder in &placeholders {
+            match self.fields.iter().find(|(name, _)| name == placeholder) {
+                Some((name, value)) => {
+                    let name = format_ident!("{name}");
+                    args.push(quote!(#name = #value));
+                }
+                None => errors.push(crate::Error::message_template_unknown_placeholder(
+                    template,
+                    placeholder,
+                    &candidates,
+                )),
+            }
+        }
+        if !errors.is_empty() {
+            return Err(errors);
+        }
+
+        let error_type = &self.error_type;
+        Ok(quote!(
+            |__error_params: &#error_type| format!(#template, #(#args),*)
+        ))
+    }
+}
+
+/// Distinct placeholder names of a `format!` style template,
+/// or `None` if the template is malformed or has positional placeholders.
+fn parse_placeholders(template: &str) -> Option<Vec<String>> {
+    let mut placeholders = vec![];
+    let mut chars = template.chars().peekable();
+
+    while let Some(ch) = chars.next() {
+        match ch {
+            '{' if chars.peek() == Some(&'{') => {
+                chars.next();
+            }
+            '}' if chars.peek() == Some(&'}') => {
+                chars.next();
+            }
+            '{' => {
+                let mut placeholder = String::new();
+                loop {
+                    match chars.next()? {
+                        '}' => break,
+                        '{' => return None,
+                        ch => placeholder.push(ch),
+                    }
+                }
+                let name = placeholder
+                    .split(':')
+                    .next()
+                    .unwrap_or_default()
+                    .trim()
+                    .to_owned();
+                if !name.starts_with(|ch: char| ch.is_ascii_alphabetic() || ch == '_') {
+                    return None;
+                }
+                if !placeholders.contains(&name) {
+                    placeholders.push(name);
+                }
+            }
+            '}' => return None,
+            _ => {}
+        }
+    }
+
+    Some(placeholders)
+}
@@ -1,5 +1,14 @@
+mod compare;
+mod composition;
 mod custom;
 mod enumerate;
 
-pub use custom::extract_generic_custom_validator;
+pub use compare::{
+    collect_compared_fields, extract_generic_equals_validator,
+    extract_generic_greater_than_or_equal_validator, extract_generic_greater_than_validator,
+    extract_generic_less_than_or_equal_validator, extract_generic_less_than_validator,
+    extract_generic_not_equals_validator,
+};
+pub use composition::{extract_generic_composition_validator, Composition};
+pub use custom::{extract_generic_custom_async_validator, extract_generic_custom_validator};
 pub use enumerate::extract_generic_enumerate_validator;
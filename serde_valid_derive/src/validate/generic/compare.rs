@@ -0,0 +1,97 @@
+use crate::serde::rename::RenameMap;
+use crate::types::Field;
+use crate::validate::common::{CustomMessageToken, MetaNameValueValidation};
+use crate::validate::meta::MetaNameExprList;
+use crate::validate::Validator;
+use proc_macro2::TokenStream;
+use quote::quote;
+use std::collections::HashSet;
+use std::str::FromStr;
+use syn::parse::Parse;
+
+/// Comparison with a sibling field, such as `#[validate(greater_than = start)]`.
+///
+/// The error is reported under the annotated field.
+macro_rules! extract_generic_compare_validator {
+    ($ErrorType:ident, $op:tt) => {
+        paste::paste! {
+            pub fn [<extract_generic_ $ErrorType:snake _validator>](
+                field: &impl Field,
+                validation_value: &syn::Expr,
+                custom_message: CustomMessageToken,
+                rename_map: &RenameMap,
+            ) -> Result<Validator, crate::Errors> {
+                [<inner_extract_generic_ $ErrorType:snake _validator>](field, validation_value, custom_message, rename_map)
+            }
+
+            fn [<inner_extract_generic_ $ErrorType:snake _validator>](
+                field: &impl Field,
+                validation_value: &syn::Expr,
+                custom_message: CustomMessageToken,
+                rename_map: &RenameMap,
+            ) -> Result<TokenStream, crate::Errors> {
+                let field_name = field.name();
+                let field_ident = field.ident();
+                let field_key = field.key();
+                let rename = rename_map.get(field_name).unwrap_or(&field_key);
+                let errors = field.errors_variable();
+                let other_ident = get_compared_field(validation_value).ok_or_else(|| {
+                    vec![crate::Error::validate_compare_allow_field(
+                        validation_value,
+                        stringify!([<$ErrorType:snake>]),
+                    )]
+                })?;
+                let other_name = other_ident.to_string();
+                let other_key = quote!(#other_name);
+                let other_rename = rename_map.get(&other_name).unwrap_or(&other_key);
+                let custom_message = custom_message.into_token();
+
+                Ok(quote!(
+                    if !(#field_ident $op #other_ident) {
+                        #errors
+                            .entry((#rename).into())
+                            .or_default()
+                            .push(::serde_valid::validation::Error::$ErrorType(
+                                #custom_message.into_message(::serde_valid::[<$ErrorType Error>]::new(#other_rename)),
+                            ));
+                    }
+                ))
+            }
+        }
+    };
+}
+
+extract_generic_compare_validator!(GreaterThan, >);
+extract_generic_compare_validator!(GreaterThanOrEqual, >=);
+extract_generic_compare_validator!(LessThan, <);
+extract_generic_compare_validator!(LessThanOrEqual, <=);
+extract_generic_compare_validator!(Equals, ==);
+extract_generic_compare_validator!(NotEquals, !=);
+
+/// Sibling fields compared by the fields, which must be bound before the validations.
+pub fn collect_compared_fields(fields: &syn::FieldsNamed) -> HashSet<syn::Ident> {
+    fields
+        .named
+        .iter()
+        .flat_map(|field| field.attrs.iter())
+        .filter(|attribute| {
+            attribute.path.is_ident("validate") || attribute.path.is_ident("serde_valid")
+        })
+        .filter_map(|attribute| attribute.parse_args_with(MetaNameExprList::parse).ok())
+        .filter(|MetaNameExprList { path, .. }| {
+            path.get_ident()
+                .and_then(|ident| MetaNameValueValidation::from_str(&ident.to_string()).ok())
+                .is_some_and(|validation| validation.is_compare())
+        })
+        .filter_map(|MetaNameExprList { expr, .. }| get_compared_field(&expr).cloned())
+        .collect()
+}
+
+fn get_compared_field(expr: &syn::Expr) -> Option<&syn::Ident> {
+    match expr {
+        syn::Expr::Path(syn::ExprPath {
+            qself: None, path, ..
+        }) => path.get_ident(),
+        _ => None,
+    }
+}
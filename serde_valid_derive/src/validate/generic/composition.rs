@@ -0,0 +1,78 @@
+use crate::serde::rename::RenameMap;
+use crate::types::Field;
+use crate::validate::common::CustomMessageToken;
+use crate::validate::Validator;
+use proc_macro2::TokenStream;
+use quote::quote;
+
+#[derive(Debug, Clone, Copy)]
+pub enum Composition {
+    AnyOf,
+    AllOf,
+    OneOf,
+    Not,
+}
+
+/// Validator combining the validators of the branches.
+///
+/// Each branch pushes its errors into a map of its own,
+/// which the composition error keeps in branch order.
+pub fn extract_generic_composition_validator(
+    field: &impl Field,
+    composition: Composition,
+    branches: Vec<Validator>,
+    custom_message: CustomMessageToken,
+    rename_map: &RenameMap,
+) -> Validator {
+    let field_name = field.name();
+    let field_key = field.key();
+    let rename = rename_map.get(field_name).unwrap_or(&field_key);
+    let errors = field.errors_variable();
+    let errors_map_type = field.errors_map_type();
+    let custom_message = custom_message.into_token();
+
+    let (failed, error) = match composition {
+        Composition::AnyOf => (
+            quote!(__branch_errors.iter().all(|errors| !errors.is_empty())),
+            quote!(AnyOf(#custom_message.into_message(::serde_valid::AnyOfError::new(__branch_errors)))),
+        ),
+        Composition::AllOf => (
+            quote!(__branch_errors.iter().any(|errors| !errors.is_empty())),
+            quote!(AllOf(#custom_message.into_message(::serde_valid::AllOfError::new(__branch_errors)))),
+        ),
+        Composition::OneOf => (
+            quote!(
+                __branch_errors
+                    .iter()
+                    .filter(|errors| errors.is_empty())
+                    .count()
+                    != 1
+            ),
+            quote!(OneOf(#custom_message.into_message(::serde_valid::OneOfError::new(__branch_errors)))),
+        ),
+        Composition::Not => (
+            quote!(__branch_errors.iter().all(|errors| errors.is_empty())),
+            quote!(Not(#custom_message.into_message(::serde_valid::NotError {}))),
+        ),
+    };
+    let branches = branches.into_iter().map(|branch| -> TokenStream {
+        quote!({
+            let mut #errors = #errors_map_type::new();
+            #branch
+            #errors
+                .into_iter()
+                .flat_map(|(_, errors)| errors)
+                .collect::<::serde_valid::validation::VecErrors>()
+        })
+    });
+
+    quote!({
+        let __branch_errors = vec![#(#branches),*];
+        if #failed {
+            #errors
+                .entry((#rename).into())
+                .or_default()
+                .push(::serde_valid::validation::Error::#error);
+        }
+    })
+}
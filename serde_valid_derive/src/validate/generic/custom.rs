@@ -5,12 +5,37 @@ use proc_macro2::TokenStream;
 use quote::quote;
 
 pub fn extract_generic_custom_validator(
+    field: &impl Field,
+    validation_list: &syn::MetaList,
+    rename_map: &RenameMap,
+    context: Option<&syn::Type>,
+) -> Result<Validator, crate::Errors> {
+    let context_arg = context.map(|_| quote!(, ctx));
+
+    inner_extract_generic_custom_validator(field, validation_list, rename_map, |custom_fn_name| {
+        let field_ident = field.ident();
+        quote!(#custom_fn_name(#field_ident #context_arg))
+    })
+}
+
+pub fn extract_generic_custom_async_validator(
+    field: &impl Field,
+    validation_list: &syn::MetaList,
+    rename_map: &RenameMap,
+) -> Result<Validator, crate::Errors> {
+    inner_extract_generic_custom_validator(field, validation_list, rename_map, |custom_fn_name| {
+        let field_ident = field.ident();
+        quote!(#custom_fn_name(#field_ident).await)
+    })
+}
+
+fn inner_extract_generic_custom_validator(
     field: &impl Field,
     syn::MetaList { path, nested, .. }: &syn::MetaList,
     rename_map: &RenameMap,
+    custom_fn_call: impl FnOnce(TokenStream) -> TokenStream,
 ) -> Result<Validator, crate::Errors> {
     let field_name = field.name();
-    let field_ident = field.ident();
     let field_key = field.key();
     let rename = rename_map.get(field_name).unwrap_or(&field_key);
     let errors = field.errors_variable();
@@ -21,11 +46,12 @@ pub fn extract_generic_custom_validator(
         _ => Err(crate::Error::validate_custom_tail_error(nested)),
     }
     .map_err(|error| vec![error])?;
+    let custom_fn_call = custom_fn_call(custom_fn_name);
 
     Ok(quote!(
-        if let Err(__error) = #custom_fn_name(#field_ident) {
+        if let Err(__error) = #custom_fn_call {
             #errors
-                .entry(#rename)
+                .entry((#rename).into())
                 .or_default()
                 .push(__error);
         };
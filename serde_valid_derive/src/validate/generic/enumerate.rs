@@ -38,7 +38,7 @@ fn inner_extract_generic_enumerate_validator(
             use ::serde_valid::validation::{IntoError, ToDefaultMessage};
 
             #errors
-                .entry(#rename)
+                .entry((#rename).into())
                 .or_default()
                 .push(__composited_error_params.into_error_by(#custom_message));
         }
@@ -47,7 +47,7 @@ fn inner_extract_generic_enumerate_validator(
 
 fn get_enumerate(
     syn::MetaList { path, nested, .. }: &syn::MetaList,
-) -> Result<Lits, crate::Errors> {
+) -> Result<Lits<'_>, crate::Errors> {
     let mut errors = vec![];
     let mut enumerate = Lits::new();
 
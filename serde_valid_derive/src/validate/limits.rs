@@ -0,0 +1,122 @@
+use crate::types::{CommaSeparatedNestedMetas, Field};
+use crate::validate::common::{
+    get_numeric_literal_value, is_skip_if, split_when_option, MetaListOption,
+};
+use crate::validate::meta::MetaNameExprList;
+use std::str::FromStr;
+use syn::parse::Parse;
+
+/// Lower and upper limits, and whether they may be equal.
+const LIMIT_PAIRS: [(&str, &str, bool); 5] = [
+    ("minimum", "maximum", true),
+    ("exclusive_minimum", "exclusive_maximum", false),
+    ("min_length", "max_length", true),
+    ("min_items", "max_items", true),
+    ("min_properties", "max_properties", true),
+];
+
+struct LiteralLimit {
+    name: String,
+    expr: syn::Expr,
+    value: f64,
+    groups: Option<Vec<String>>,
+}
+
+/// Report literal limits of a field that no value can satisfy,
+/// such as `minimum` greater than `maximum`.
+///
+/// Only validators of the same groups run together, so only they are compared.
+/// Conditional validators, i.e. those with `when` or of a field with `skip_if`,
+/// and the branches of `any_of`, `all_of`, `one_of` and `not` are not compared.
+pub fn check_field_limits(field: &impl Field) -> Result<(), crate::Errors> {
+    if field.attrs().iter().any(is_skip_if) {
+        return Ok(());
+    }
+
+    let limits = field
+        .attrs()
+        .iter()
+        .filter(|attribute| {
+            (attribute.path.is_ident("validate") || attribute.path.is_ident("serde_valid"))
+                && matches!(split_when_option(attribute), Ok((_, None)))
+        })
+        .filter_map(extract_literal_limit)
+        .collect::<Vec<_>>();
+
+    let mut errors = vec![];
+    for (lower_name, upper_name, allow_equal) in LIMIT_PAIRS {
+        for lower in limits.iter().filter(|limit| limit.name == lower_name) {
+            for upper in limits
+                .iter()
+                .filter(|limit| limit.name == upper_name && limit.groups == lower.groups)
+            {
+                if lower.value > upper.value || (!allow_equal && lower.value == upper.value) {
+                    errors.push(crate::Error::validate_limits_conflict(
+                        &lower.expr,
+                        lower_name,
+                        &upper.expr,
+                        upper_name,
+                        allow_equal,
+                    ));
+                }
+            }
+        }
+    }
+
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(errors)
+    }
+}
+
+fn extract_literal_limit(attribute: &syn::Attribute) -> Option<LiteralLimit> {
+    let (path, expr, options) = match attribute.parse_meta() {
+        Ok(syn::Meta::List(syn::MetaList { nested, .. })) => match nested.first()? {
+            syn::NestedMeta::Meta(syn::Meta::NameValue(syn::MetaNameValue {
+                path, lit, ..
+            })) => (
+                path.clone(),
+                syn::Expr::Lit(syn::ExprLit {
+                    attrs: vec![],
+                    lit: lit.clone(),
+                }),
+                nested.iter().skip(1).cloned().collect(),
+            ),
+            _ => return None,
+        },
+        Ok(_) => return None,
+        Err(_) => {
+            let MetaNameExprList { path, expr, nested } =
+                attribute.parse_args_with(MetaNameExprList::parse).ok()?;
+            (path, expr, nested)
+        }
+    };
+
+    Some(LiteralLimit {
+        name: path.get_ident()?.to_string(),
+        value: get_numeric_literal_value(&expr)?,
+        expr,
+        groups: extract_group_names(&options),
+    })
+}
+
+fn extract_group_names(options: &CommaSeparatedNestedMetas) -> Option<Vec<String>> {
+    options.iter().find_map(|option| match option {
+        syn::NestedMeta::Meta(syn::Meta::List(syn::MetaList { path, nested, .. }))
+            if matches!(
+                path.get_ident()
+                    .map(|ident| MetaListOption::from_str(&ident.to_string())),
+                Some(Ok(MetaListOption::Groups))
+            ) =>
+        {
+            let mut groups = nested
+                .iter()
+                .map(|group| quote::quote!(#group).to_string())
+                .collect::<Vec<_>>();
+            groups.sort();
+            Some(groups)
+        }
+        _ => None,
+    })
+}
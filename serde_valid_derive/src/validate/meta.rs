@@ -1,4 +1,5 @@
 mod meta_list;
+mod meta_name_expr;
 mod meta_path;
 mod nested_meta_list;
 mod nested_meta_name_value;
@@ -6,8 +7,14 @@ mod nested_meta_path;
 
 use crate::serde::rename::RenameMap;
 use crate::types::Field;
+use crate::validate::common::{split_when_option, wrap_validator_with_when, MetaListValidation};
+use crate::validate::generic::extract_generic_custom_async_validator;
 use crate::validate::Validator;
-use meta_path::extract_validator_from_meta_path;
+use meta_name_expr::extract_validator_from_meta_name_expr_list;
+pub use meta_name_expr::MetaNameExprList;
+use meta_path::{extract_async_validator_from_meta_path, extract_validator_from_meta_path};
+use std::str::FromStr;
+use syn::parse::Parse;
 
 use self::meta_list::extract_validator_from_meta_list;
 
@@ -15,19 +22,81 @@ pub fn extract_meta_validator(
     field: &impl Field,
     attribute: &syn::Attribute,
     rename_map: &RenameMap,
+    context: Option<&syn::Type>,
 ) -> Result<Validator, crate::Errors> {
-    match attribute.parse_meta() {
+    let (attribute, when) = split_when_option(attribute)?;
+    let attribute = attribute.as_ref();
+
+    let validator = match attribute.parse_meta() {
         Ok(syn::Meta::List(list)) => {
-            extract_validator_from_meta_list(field, attribute, &list, rename_map)
+            extract_validator_from_meta_list(field, attribute, &list, rename_map, context)
         }
-        Ok(syn::Meta::Path(_)) => extract_validator_from_meta_path(field, rename_map),
+        Ok(syn::Meta::Path(_)) => extract_validator_from_meta_path(field, rename_map, context),
         Ok(syn::Meta::NameValue(name_value)) => {
             Err(vec![crate::Error::validate_meta_name_value_not_support(
                 name_value,
             )])
         }
-        Err(error) => Err(vec![crate::Error::validate_attribute_parse_error(
-            attribute, &error,
-        )]),
-    }
+        Err(error) => match attribute.parse_args_with(MetaNameExprList::parse) {
+            Ok(list) => {
+                extract_validator_from_meta_name_expr_list(field, attribute, &list, rename_map)
+            }
+            Err(_) => Err(vec![crate::Error::validate_attribute_parse_error(
+                attribute, &error,
+            )]),
+        },
+    }?;
+
+    Ok(wrap_validator_with_when(validator, when.as_ref()))
+}
+
+/// Extract the validator run by `AsyncValidate`.
+///
+/// `#[validate(custom_async(f))]` and nested `#[validate]` give a validator,
+/// and the synchronous validators are left to `Validate`.
+pub fn extract_async_meta_validator(
+    field: &impl Field,
+    attribute: &syn::Attribute,
+    rename_map: &RenameMap,
+) -> Result<Option<Validator>, crate::Errors> {
+    let (attribute, when) = split_when_option(attribute)?;
+
+    let validator = match attribute.parse_meta() {
+        Ok(syn::Meta::List(syn::MetaList { nested, .. })) => match nested.first() {
+            Some(syn::NestedMeta::Meta(syn::Meta::List(list))) if is_custom_async(list) => {
+                if let Some(tail) = nested.iter().nth(1) {
+                    return Err(vec![crate::Error::too_many_list_items(tail)]);
+                }
+                extract_generic_custom_async_validator(field, list, rename_map).map(Some)
+            }
+            _ => Ok(None),
+        },
+        Ok(syn::Meta::Path(_)) => {
+            extract_async_validator_from_meta_path(field, rename_map).map(Some)
+        }
+        _ => Ok(None),
+    }?;
+
+    Ok(validator.map(|validator| wrap_validator_with_when(validator, when.as_ref())))
+}
+
+/// Whether the attribute is `#[validate(custom_async(...))]`, which `Validate` skips.
+pub fn is_async_meta_validator(attribute: &syn::Attribute) -> bool {
+    let Ok((attribute, _)) = split_when_option(attribute) else {
+        return false;
+    };
+    matches!(
+        attribute.parse_meta(),
+        Ok(syn::Meta::List(syn::MetaList { nested, .. }))
+            if matches!(nested.first(), Some(syn::NestedMeta::Meta(syn::Meta::List(list))) if is_custom_async(list))
+    )
+}
+
+fn is_custom_async(list: &syn::MetaList) -> bool {
+    matches!(
+        list.path
+            .get_ident()
+            .map(|ident| MetaListValidation::from_str(&ident.to_string())),
+        Some(Ok(MetaListValidation::CustomAsync))
+    )
 }
@@ -3,81 +3,123 @@ use super::nested_meta_name_value::extract_validator_from_nested_meta_name_value
 use super::nested_meta_path::extract_validator_from_nested_meta_path;
 use crate::serde::rename::RenameMap;
 use crate::types::Field;
-use crate::validate::common::{extract_custom_message_tokens, CustomMessageToken};
+use crate::validate::common::{
+    extract_custom_message_tokens, extract_groups, wrap_validator_with_groups, CustomMessageToken,
+    Groups, MetaListOption,
+};
 use crate::validate::Validator;
+use std::str::FromStr;
 
 pub fn extract_validator_from_meta_list(
     field: &impl Field,
     attribute: &syn::Attribute,
     syn::MetaList { nested, .. }: &syn::MetaList,
     rename_map: &RenameMap,
+    context: Option<&syn::Type>,
 ) -> Result<Validator, crate::Errors> {
-    let mut errors = vec![];
-    let custom_message = match nested.len() {
-        0..=1 => CustomMessageToken::default(),
-        2 => match extract_custom_message_tokens(&nested[1]) {
-            Ok(custom_message) => custom_message,
-            Err(message_fn_errors) => {
-                errors.extend(message_fn_errors);
-                CustomMessageToken::default()
-            }
-        },
-        _ => {
-            for meta in nested.iter().skip(1) {
-                errors.push(crate::Error::too_many_list_items(meta));
-            }
-            CustomMessageToken::default()
-        }
-    };
+    let (custom_message, groups, errors) = extract_validator_options(nested.iter().skip(1));
 
     if !nested.is_empty() {
         let meta_item = &nested[0];
-        match meta_item {
-            syn::NestedMeta::Meta(meta) => {
-                let validator = match meta {
-                    syn::Meta::Path(path) => extract_validator_from_nested_meta_path(
-                        field,
-                        path,
-                        custom_message,
-                        rename_map,
-                    ),
-                    syn::Meta::List(list) => extract_validator_from_nested_meta_list(
-                        field,
-                        list,
-                        custom_message,
-                        rename_map,
-                    ),
-                    syn::Meta::NameValue(name_value) => {
-                        extract_validator_from_nested_meta_name_value(
-                            field,
-                            attribute,
-                            name_value,
-                            custom_message,
-                            rename_map,
-                        )
-                    }
-                };
-                match validator {
-                    Ok(validator) => {
-                        if errors.is_empty() {
-                            Ok(validator)
-                        } else {
-                            Err(errors)
-                        }
-                    }
-                    Err(validator_errors) => {
-                        errors.extend(validator_errors);
-                        Err(errors)
-                    }
+        let validator = match meta_item {
+            syn::NestedMeta::Meta(meta) => match meta {
+                syn::Meta::Path(path) => {
+                    extract_validator_from_nested_meta_path(field, path, custom_message, rename_map)
                 }
-            }
+                syn::Meta::List(list) => extract_validator_from_nested_meta_list(
+                    field,
+                    attribute,
+                    list,
+                    custom_message,
+                    rename_map,
+                    context,
+                ),
+                syn::Meta::NameValue(name_value) => extract_validator_from_nested_meta_name_value(
+                    field,
+                    attribute,
+                    &name_value.path,
+                    &syn::Expr::Lit(syn::ExprLit {
+                        attrs: vec![],
+                        lit: name_value.lit.clone(),
+                    }),
+                    custom_message,
+                    rename_map,
+                ),
+            },
             syn::NestedMeta::Lit(lit) => {
-                errors.push(crate::Error::validate_meta_literal_not_support(lit));
+                Err(vec![crate::Error::validate_meta_literal_not_support(lit)])
+            }
+        };
+        wrap_validator_with_options(validator, groups.as_ref(), errors)
+    } else {
+        Err(errors
+            .into_iter()
+            .chain([crate::Error::validate_type_required_error(attribute)])
+            .collect())
+    }
+}
+
+/// Extract the custom message and the groups following the validation.
+pub fn extract_validator_options<'a>(
+    options: impl IntoIterator<Item = &'a syn::NestedMeta>,
+) -> (CustomMessageToken, Option<Groups>, crate::Errors) {
+    let mut errors = vec![];
+    let mut custom_message = None;
+    let mut groups = None;
+
+    for meta in options {
+        match meta {
+            syn::NestedMeta::Meta(syn::Meta::List(list)) if is_groups(list) => {
+                if groups.is_some() {
+                    errors.push(crate::Error::too_many_list_items(meta));
+                    continue;
+                }
+                match extract_groups(list) {
+                    Ok(list_groups) => groups = Some(list_groups),
+                    Err(groups_errors) => errors.extend(groups_errors),
+                }
+            }
+            _ => {
+                if custom_message.is_some() {
+                    errors.push(crate::Error::too_many_list_items(meta));
+                    continue;
+                }
+                match extract_custom_message_tokens(meta) {
+                    Ok(message) => custom_message = Some(message),
+                    Err(message_fn_errors) => errors.extend(message_fn_errors),
+                }
+            }
+        }
+    }
+
+    (custom_message.unwrap_or_default(), groups, errors)
+}
+
+pub fn wrap_validator_with_options(
+    validator: Result<Validator, crate::Errors>,
+    groups: Option<&Groups>,
+    mut errors: crate::Errors,
+) -> Result<Validator, crate::Errors> {
+    match validator {
+        Ok(validator) => {
+            if errors.is_empty() {
+                Ok(wrap_validator_with_groups(validator, groups))
+            } else {
                 Err(errors)
             }
         }
-    } else {
-        errors.push(crate::Error::validate_type_required_error(attribute));
-        Err(errors)
+        Err(validator_errors) => {
+            errors.extend(validator_errors);
+            Err(errors)
+        }
     }
 }
+
+fn is_groups(list: &syn::MetaList) -> bool {
+    matches!(
+        list.path
+            .get_ident()
+            .map(|ident| MetaListOption::from_str(&ident.to_string())),
+        Some(Ok(MetaListOption::Groups))
+    )
+}
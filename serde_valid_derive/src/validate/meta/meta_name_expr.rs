@@ -0,0 +1,52 @@
+use super::meta_list::{extract_validator_options, wrap_validator_with_options};
+use super::nested_meta_name_value::extract_validator_from_nested_meta_name_value;
+use crate::serde::rename::RenameMap;
+use crate::types::{CommaSeparatedNestedMetas, Field};
+use crate::validate::Validator;
+use syn::parse::{Parse, ParseStream};
+
+/// `#[validate(maximum = ctx.max_upload, ...)]`.
+///
+/// Name value validation whose value is an expression rather than a literal,
+/// which [`syn::Attribute::parse_meta`] does not accept.
+pub struct MetaNameExprList {
+    pub path: syn::Path,
+    pub expr: syn::Expr,
+    pub nested: CommaSeparatedNestedMetas,
+}
+
+impl Parse for MetaNameExprList {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let path = input.parse()?;
+        input.parse::<syn::Token![=]>()?;
+        let expr = input.parse()?;
+        let nested = if input.is_empty() {
+            CommaSeparatedNestedMetas::new()
+        } else {
+            input.parse::<syn::Token![,]>()?;
+            CommaSeparatedNestedMetas::parse_terminated(input)?
+        };
+
+        Ok(Self { path, expr, nested })
+    }
+}
+
+pub fn extract_validator_from_meta_name_expr_list(
+    field: &impl Field,
+    attribute: &syn::Attribute,
+    MetaNameExprList { path, expr, nested }: &MetaNameExprList,
+    rename_map: &RenameMap,
+) -> Result<Validator, crate::Errors> {
+    let (custom_message, groups, errors) = extract_validator_options(nested);
+
+    let validator = extract_validator_from_nested_meta_name_value(
+        field,
+        attribute,
+        path,
+        expr,
+        custom_message,
+        rename_map,
+    );
+
+    wrap_validator_with_options(validator, groups.as_ref(), errors)
+}
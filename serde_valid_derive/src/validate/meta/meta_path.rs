@@ -1,3 +1,4 @@
+use crate::serde::flatten::is_serde_flatten;
 use crate::serde::rename::RenameMap;
 use crate::types::Field;
 use crate::validate::Validator;
@@ -7,35 +8,120 @@ use quote::quote;
 pub fn extract_validator_from_meta_path(
     field: &impl Field,
     rename_map: &RenameMap,
+    context: Option<&syn::Type>,
 ) -> Result<Validator, crate::Errors> {
-    Ok(inner_extract_validator_from_meta_path(field, rename_map))
+    Ok(inner_extract_validator_from_meta_path(
+        field, rename_map, context,
+    ))
+}
+
+pub fn extract_async_validator_from_meta_path(
+    field: &impl Field,
+    rename_map: &RenameMap,
+) -> Result<Validator, crate::Errors> {
+    let field_ident = field.ident();
+
+    Ok(nested_validator_tokens(
+        field,
+        rename_map,
+        quote!(::serde_valid::AsyncValidate::validate_async_only(#field_ident).await),
+    ))
 }
 
 fn inner_extract_validator_from_meta_path(
     field: &impl Field,
     rename_map: &RenameMap,
+    context: Option<&syn::Type>,
 ) -> TokenStream {
     let field_ident = field.ident();
+    let inner_result = match context {
+        Some(_) => quote!(
+            match __group {
+                Some(__group) => ::serde_valid::ValidateWith::validate_group_with(#field_ident, __group, ctx),
+                None => ::serde_valid::ValidateWith::validate_with(#field_ident, ctx),
+            }
+        ),
+        None => quote!(
+            match __group {
+                Some(__group) => #field_ident.validate_group(__group),
+                None => #field_ident.validate(),
+            }
+        ),
+    };
+
+    nested_validator_tokens(field, rename_map, inner_result)
+}
+
+/// Push the errors of the nested validation `inner_result` to the field.
+fn nested_validator_tokens(
+    field: &impl Field,
+    rename_map: &RenameMap,
+    inner_result: TokenStream,
+) -> TokenStream {
     let field_name = field.name();
     let field_key = field.key();
     let rename = rename_map.get(field_name).unwrap_or(&field_key);
     let errors = field.errors_variable();
 
+    if is_serde_flatten(field.attrs()) {
+        return flatten_validator_tokens(&errors, inner_result);
+    }
+
     quote!(
-        if let Err(__inner_errors) = #field_ident.validate() {
+        let __inner_result = #inner_result;
+        if let Err(__inner_errors) = __inner_result {
             match __inner_errors {
                 ::serde_valid::validation::Errors::Object(__object_errors) => {
-                    #errors.entry(#rename).or_default().push(
+                    #errors.entry((#rename).into()).or_default().push(
                         ::serde_valid::validation::Error::Properties(__object_errors)
                     );
                 }
                 ::serde_valid::validation::Errors::Array(__array_errors) => {
-                    #errors.entry(#rename).or_default().push(
+                    #errors.entry((#rename).into()).or_default().push(
                         ::serde_valid::validation::Error::Items(__array_errors)
                     );
                 }
                 ::serde_valid::validation::Errors::NewType(__new_type_errors) => {
-                    #errors.entry(#rename).or_default().extend(__new_type_errors);
+                    #errors.entry((#rename).into()).or_default().extend(__new_type_errors);
+                }
+            }
+        }
+    )
+}
+
+/// Merge the errors of a `#[serde(flatten)]` field into the parent,
+/// where its properties are serialized.
+fn flatten_validator_tokens(errors: &TokenStream, inner_result: TokenStream) -> TokenStream {
+    quote!(
+        let __inner_result = #inner_result;
+        if let Err(__inner_errors) = __inner_result {
+            match __inner_errors {
+                ::serde_valid::validation::Errors::Object(__object_errors) => {
+                    __rule_vec_errors.extend(__object_errors.errors);
+                    for (__property, __property_errors) in __object_errors.properties {
+                        let __vec_errors = #errors.entry(__property).or_default();
+                        match __property_errors {
+                            ::serde_valid::validation::Errors::Object(__object_errors) => {
+                                __vec_errors.push(
+                                    ::serde_valid::validation::Error::Properties(__object_errors)
+                                );
+                            }
+                            ::serde_valid::validation::Errors::Array(__array_errors) => {
+                                __vec_errors.push(
+                                    ::serde_valid::validation::Error::Items(__array_errors)
+                                );
+                            }
+                            ::serde_valid::validation::Errors::NewType(__new_type_errors) => {
+                                __vec_errors.extend(__new_type_errors);
+                            }
+                        }
+                    }
+                }
+                ::serde_valid::validation::Errors::Array(__array_errors) => {
+                    __rule_vec_errors.push(::serde_valid::validation::Error::Items(__array_errors));
+                }
+                ::serde_valid::validation::Errors::NewType(__new_type_errors) => {
+                    __rule_vec_errors.extend(__new_type_errors);
                 }
             }
         }
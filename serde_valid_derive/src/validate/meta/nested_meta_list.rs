@@ -1,17 +1,29 @@
+use super::nested_meta_name_value::extract_validator_from_nested_meta_name_value;
+use super::nested_meta_path::extract_validator_from_nested_meta_path;
 use crate::serde::rename::RenameMap;
 use crate::types::{Field, SingleIdentPath};
-use crate::validate::common::{CustomMessageToken, MetaListValidation};
+use crate::validate::array::{
+    extract_array_contains_validator, extract_array_max_contains_validator,
+    extract_array_min_contains_validator,
+};
+use crate::validate::common::{CustomMessageToken, ErrorParams, MetaListValidation};
 use crate::validate::generic::{
-    extract_generic_custom_validator, extract_generic_enumerate_validator,
+    extract_generic_composition_validator, extract_generic_custom_validator,
+    extract_generic_enumerate_validator, Composition,
+};
+use crate::validate::object::{
+    extract_object_pattern_properties_validator, extract_object_property_names_validator,
 };
 use crate::validate::Validator;
 use std::str::FromStr;
 
 pub fn extract_validator_from_nested_meta_list(
     field: &impl Field,
+    attribute: &syn::Attribute,
     validation_list: &syn::MetaList,
     custom_message: CustomMessageToken,
     rename_map: &RenameMap,
+    context: Option<&syn::Type>,
 ) -> Result<Validator, crate::Errors> {
     let syn::MetaList {
         path: validation_name,
@@ -20,11 +32,116 @@ pub fn extract_validator_from_nested_meta_list(
     let validation_ident = SingleIdentPath::new(validation_name).ident();
 
     match MetaListValidation::from_str(&validation_ident.to_string()) {
-        Ok(MetaListValidation::Enumerate) => {
-            extract_generic_enumerate_validator(field, validation_list, custom_message, rename_map)
-        }
+        Ok(MetaListValidation::Enumerate) => extract_generic_enumerate_validator(
+            field,
+            validation_list,
+            custom_message.with_error_params(&ErrorParams::enumerate())?,
+            rename_map,
+        ),
         Ok(MetaListValidation::Custom) => {
-            extract_generic_custom_validator(field, validation_list, rename_map)
+            if custom_message.is_specified() {
+                return Err(vec![crate::Error::validate_custom_not_support_message(
+                    validation_name,
+                )]);
+            }
+            extract_generic_custom_validator(field, validation_list, rename_map, context)
+        }
+        Ok(MetaListValidation::CustomAsync) => {
+            Err(vec![crate::Error::validate_custom_async_need_async_derive(
+                validation_name,
+            )])
+        }
+        Ok(MetaListValidation::AnyOf) => extract_composition_validator(
+            field,
+            attribute,
+            validation_list,
+            Composition::AnyOf,
+            custom_message.with_error_params(&ErrorParams::from_validation_name("any_of"))?,
+            rename_map,
+            context,
+        ),
+        Ok(MetaListValidation::AllOf) => extract_composition_validator(
+            field,
+            attribute,
+            validation_list,
+            Composition::AllOf,
+            custom_message.with_error_params(&ErrorParams::from_validation_name("all_of"))?,
+            rename_map,
+            context,
+        ),
+        Ok(MetaListValidation::OneOf) => extract_composition_validator(
+            field,
+            attribute,
+            validation_list,
+            Composition::OneOf,
+            custom_message.with_error_params(&ErrorParams::from_validation_name("one_of"))?,
+            rename_map,
+            context,
+        ),
+        Ok(MetaListValidation::Not) => extract_composition_validator(
+            field,
+            attribute,
+            validation_list,
+            Composition::Not,
+            custom_message.with_error_params(&ErrorParams::not())?,
+            rename_map,
+            context,
+        ),
+        Ok(MetaListValidation::Contains) => extract_array_contains_validator(
+            field,
+            validation_list,
+            custom_message.with_error_params(&ErrorParams::contains())?,
+            rename_map,
+        ),
+        Ok(MetaListValidation::MinContains) => extract_array_min_contains_validator(
+            field,
+            validation_list,
+            custom_message.with_error_params(&ErrorParams::from_validation_name("min_contains"))?,
+            rename_map,
+        ),
+        Ok(MetaListValidation::MaxContains) => extract_array_max_contains_validator(
+            field,
+            validation_list,
+            custom_message.with_error_params(&ErrorParams::from_validation_name("max_contains"))?,
+            rename_map,
+        ),
+        Ok(MetaListValidation::PropertyNames) => {
+            let [syn::NestedMeta::Meta(meta)] = Vec::from_iter(&validation_list.nested)[..] else {
+                return Err(vec![crate::Error::validate_property_names_need_validation(
+                    validation_list,
+                )]);
+            };
+            let validator = extract_nested_validator(
+                field,
+                attribute,
+                meta,
+                custom_message,
+                rename_map,
+                context,
+            )?;
+
+            Ok(extract_object_property_names_validator(
+                field, validator, rename_map,
+            ))
+        }
+        Ok(MetaListValidation::PatternProperties) => {
+            let [syn::NestedMeta::Lit(syn::Lit::Str(pattern)), syn::NestedMeta::Meta(meta)] =
+                Vec::from_iter(&validation_list.nested)[..]
+            else {
+                return Err(vec![
+                    crate::Error::validate_pattern_properties_need_validation(validation_list),
+                ]);
+            };
+            let validator = extract_nested_validator(
+                field,
+                attribute,
+                meta,
+                custom_message,
+                rename_map,
+                context,
+            )?;
+
+            extract_object_pattern_properties_validator(field, pattern, validator, rename_map)
         }
         Err(unknown) => Err(vec![crate::Error::validate_unknown_type(
             validation_name,
@@ -35,3 +152,108 @@ pub fn extract_validator_from_nested_meta_list(
         )]),
     }
 }
+
+fn extract_composition_validator(
+    field: &impl Field,
+    attribute: &syn::Attribute,
+    validation_list: &syn::MetaList,
+    composition: Composition,
+    custom_message: CustomMessageToken,
+    rename_map: &RenameMap,
+    context: Option<&syn::Type>,
+) -> Result<Validator, crate::Errors> {
+    let branches =
+        extract_composition_branches(field, attribute, validation_list, rename_map, context)?;
+    if matches!(composition, Composition::Not) && branches.len() != 1 {
+        return Err(vec![crate::Error::validate_not_allow_single_validation(
+            validation_list,
+        )]);
+    }
+
+    Ok(extract_generic_composition_validator(
+        field,
+        composition,
+        branches,
+        custom_message,
+        rename_map,
+    ))
+}
+
+/// Validators of the branches of `any_of(...)`, `all_of(...)`, `one_of(...)` and `not(...)`,
+/// which use the default messages.
+fn extract_composition_branches(
+    field: &impl Field,
+    attribute: &syn::Attribute,
+    syn::MetaList { path, nested, .. }: &syn::MetaList,
+    rename_map: &RenameMap,
+    context: Option<&syn::Type>,
+) -> Result<Vec<Validator>, crate::Errors> {
+    if nested.is_empty() {
+        return Err(vec![crate::Error::validate_composition_need_item(path)]);
+    }
+
+    let mut errors = vec![];
+    let branches = nested
+        .iter()
+        .filter_map(|nested_meta| {
+            let branch = match nested_meta {
+                syn::NestedMeta::Meta(meta) => extract_nested_validator(
+                    field,
+                    attribute,
+                    meta,
+                    CustomMessageToken::default(),
+                    rename_map,
+                    context,
+                ),
+                syn::NestedMeta::Lit(lit) => {
+                    Err(vec![crate::Error::validate_meta_literal_not_support(lit)])
+                }
+            };
+            branch
+                .map_err(|branch_errors| errors.extend(branch_errors))
+                .ok()
+        })
+        .collect();
+
+    if errors.is_empty() {
+        Ok(branches)
+    } else {
+        Err(errors)
+    }
+}
+
+/// Validator of a validation nested in another, such as `max_length = 100` of
+/// `pattern_properties("^x-", max_length = 100)`.
+fn extract_nested_validator(
+    field: &impl Field,
+    attribute: &syn::Attribute,
+    meta: &syn::Meta,
+    custom_message: CustomMessageToken,
+    rename_map: &RenameMap,
+    context: Option<&syn::Type>,
+) -> Result<Validator, crate::Errors> {
+    match meta {
+        syn::Meta::Path(path) => {
+            extract_validator_from_nested_meta_path(field, path, custom_message, rename_map)
+        }
+        syn::Meta::List(list) => extract_validator_from_nested_meta_list(
+            field,
+            attribute,
+            list,
+            custom_message,
+            rename_map,
+            context,
+        ),
+        syn::Meta::NameValue(name_value) => extract_validator_from_nested_meta_name_value(
+            field,
+            attribute,
+            &name_value.path,
+            &syn::Expr::Lit(syn::ExprLit {
+                attrs: vec![],
+                lit: name_value.lit.clone(),
+            }),
+            custom_message,
+            rename_map,
+        ),
+    }
+}
@@ -1,9 +1,15 @@
 use crate::serde::rename::RenameMap;
 use crate::types::{Field, SingleIdentPath};
 use crate::validate::array::{
-    extract_array_max_items_validator, extract_array_min_items_validator,
+    extract_array_contains_value_validator, extract_array_max_items_validator,
+    extract_array_min_items_validator, extract_array_unique_items_by_validator,
+};
+use crate::validate::common::{CustomMessageToken, ErrorParams, MetaNameValueValidation};
+use crate::validate::generic::{
+    extract_generic_equals_validator, extract_generic_greater_than_or_equal_validator,
+    extract_generic_greater_than_validator, extract_generic_less_than_or_equal_validator,
+    extract_generic_less_than_validator, extract_generic_not_equals_validator,
 };
-use crate::validate::common::{CustomMessageToken, MetaNameValueValidation};
 use crate::validate::numeric::{
     extract_numeric_exclusive_maximum_validator, extract_numeric_exclusive_minimum_validator,
     extract_numeric_maximum_validator, extract_numeric_minimum_validator,
@@ -13,8 +19,8 @@ use crate::validate::object::{
     extract_object_max_properties_validator, extract_object_min_properties_validator,
 };
 use crate::validate::string::{
-    extract_string_max_length_validator, extract_string_min_length_validator,
-    extract_string_pattern_validator,
+    extract_string_format_validator, extract_string_max_length_validator,
+    extract_string_min_length_validator, extract_string_pattern_validator,
 };
 use crate::validate::Validator;
 use std::str::FromStr;
@@ -22,16 +28,26 @@ use std::str::FromStr;
 pub fn extract_validator_from_nested_meta_name_value(
     field: &impl Field,
     _attribute: &syn::Attribute,
-    syn::MetaNameValue {
-        path: validation_name,
-        lit: validation_value,
-        ..
-    }: &syn::MetaNameValue,
+    validation_name: &syn::Path,
+    validation_value: &syn::Expr,
     custom_message: CustomMessageToken,
     rename_map: &RenameMap,
 ) -> Result<Validator, crate::Errors> {
     let validation_name_ident = SingleIdentPath::new(validation_name).ident();
-    match MetaNameValueValidation::from_str(&validation_name_ident.to_string()) {
+    let validation = MetaNameValueValidation::from_str(&validation_name_ident.to_string());
+    let custom_message = match &validation {
+        Ok(MetaNameValueValidation::Contains) => {
+            custom_message.with_error_params(&ErrorParams::contains())?
+        }
+        Ok(MetaNameValueValidation::UniqueItemsBy) => {
+            custom_message.with_error_params(&ErrorParams::unique_items())?
+        }
+        Ok(validation) => custom_message
+            .with_error_params(&ErrorParams::from_validation_name(validation.name()))?,
+        Err(_) => custom_message,
+    };
+
+    match validation {
         Ok(MetaNameValueValidation::Minimum) => {
             extract_numeric_minimum_validator(field, validation_value, custom_message, rename_map)
         }
@@ -87,6 +103,55 @@ pub fn extract_validator_from_nested_meta_name_value(
         Ok(MetaNameValueValidation::Pattern) => {
             extract_string_pattern_validator(field, validation_value, custom_message, rename_map)
         }
+        Ok(MetaNameValueValidation::Format) => {
+            extract_string_format_validator(field, validation_value, custom_message, rename_map)
+        }
+        Ok(MetaNameValueValidation::GreaterThan) => extract_generic_greater_than_validator(
+            field,
+            validation_value,
+            custom_message,
+            rename_map,
+        ),
+        Ok(MetaNameValueValidation::GreaterThanOrEqual) => {
+            extract_generic_greater_than_or_equal_validator(
+                field,
+                validation_value,
+                custom_message,
+                rename_map,
+            )
+        }
+        Ok(MetaNameValueValidation::LessThan) => {
+            extract_generic_less_than_validator(field, validation_value, custom_message, rename_map)
+        }
+        Ok(MetaNameValueValidation::LessThanOrEqual) => {
+            extract_generic_less_than_or_equal_validator(
+                field,
+                validation_value,
+                custom_message,
+                rename_map,
+            )
+        }
+        Ok(MetaNameValueValidation::Equals) => {
+            extract_generic_equals_validator(field, validation_value, custom_message, rename_map)
+        }
+        Ok(MetaNameValueValidation::NotEquals) => extract_generic_not_equals_validator(
+            field,
+            validation_value,
+            custom_message,
+            rename_map,
+        ),
+        Ok(MetaNameValueValidation::Contains) => extract_array_contains_value_validator(
+            field,
+            validation_value,
+            custom_message,
+            rename_map,
+        ),
+        Ok(MetaNameValueValidation::UniqueItemsBy) => extract_array_unique_items_by_validator(
+            field,
+            validation_value,
+            custom_message,
+            rename_map,
+        ),
         Err(unknown) => Err(vec![crate::Error::validate_unknown_type(
             validation_name,
             &unknown,
@@ -2,7 +2,8 @@ use crate::serde::rename::RenameMap;
 use crate::types::{Field, SingleIdentPath};
 use crate::validate::array::extract_array_unique_items_validator;
 use crate::validate::common::{
-    CustomMessageToken, MetaListValidation, MetaNameValueValidation, MetaPathValidation,
+    CustomMessageToken, ErrorParams, MetaListValidation, MetaNameValueValidation,
+    MetaPathValidation,
 };
 use crate::validate::Validator;
 use std::str::FromStr;
@@ -20,7 +21,7 @@ pub fn extract_validator_from_nested_meta_path(
     match MetaPathValidation::from_str(&validation_name) {
         Ok(MetaPathValidation::UniqueItems) => Ok(extract_array_unique_items_validator(
             field,
-            custom_message,
+            custom_message.with_error_params(&ErrorParams::unique_items())?,
             rename_map,
         )),
         Err(unknown) => {
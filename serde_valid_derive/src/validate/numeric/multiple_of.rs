@@ -1,13 +1,13 @@
 use crate::serde::rename::RenameMap;
 use crate::types::Field;
-use crate::validate::common::{get_numeric, CustomMessageToken};
+use crate::validate::common::{get_numeric, get_numeric_literal_value, CustomMessageToken};
 use crate::validate::Validator;
 use proc_macro2::TokenStream;
 use quote::quote;
 
 pub fn extract_numeric_multiple_of_validator(
     field: &impl Field,
-    validation_value: &syn::Lit,
+    validation_value: &syn::Expr,
     custom_message: CustomMessageToken,
     rename_map: &RenameMap,
 ) -> Result<Validator, crate::Errors> {
@@ -16,7 +16,7 @@ pub fn extract_numeric_multiple_of_validator(
 
 fn inner_extract_numeric_multiple_of_validator(
     field: &impl Field,
-    validation_value: &syn::Lit,
+    validation_value: &syn::Expr,
     custom_message: CustomMessageToken,
     rename_map: &RenameMap,
 ) -> Result<TokenStream, crate::Errors> {
@@ -26,6 +26,11 @@ fn inner_extract_numeric_multiple_of_validator(
     let rename = rename_map.get(field_name).unwrap_or(&field_key);
     let errors = field.errors_variable();
     let multiple_of = get_numeric(validation_value)?;
+    if get_numeric_literal_value(validation_value) == Some(0.0) {
+        return Err(vec![crate::Error::validate_multiple_of_zero(
+            validation_value,
+        )]);
+    }
     let custom_message = custom_message.into_token();
 
     Ok(quote!(
@@ -36,7 +41,7 @@ fn inner_extract_numeric_multiple_of_validator(
             use ::serde_valid::validation::{IntoError, ToDefaultMessage};
 
             #errors
-                .entry(#rename)
+                .entry((#rename).into())
                 .or_default()
                 .push(__composited_error_params.into_error_by(#custom_message));
         }
@@ -1,4 +1,8 @@
+mod properties;
 mod size_properties;
+pub use properties::{
+    extract_object_pattern_properties_validator, extract_object_property_names_validator,
+};
 pub use size_properties::{
     extract_object_max_properties_validator, extract_object_min_properties_validator,
 };
@@ -0,0 +1,111 @@
+use crate::serde::rename::RenameMap;
+use crate::types::Field;
+use crate::validate::Validator;
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// Validation of the property names, running the validator on each key.
+///
+/// See <https://json-schema.org/understanding-json-schema/reference/object.html#property-names>
+pub fn extract_object_property_names_validator(
+    field: &impl Field,
+    validator: Validator,
+    rename_map: &RenameMap,
+) -> Validator {
+    properties_validator_tokens(
+        field,
+        quote!((__property, _)),
+        quote!(__property),
+        None,
+        validator,
+        rename_map,
+    )
+}
+
+/// Validation of the properties whose keys match the pattern, running the validator on each value.
+///
+/// See <https://json-schema.org/understanding-json-schema/reference/object.html#pattern-properties>
+pub fn extract_object_pattern_properties_validator(
+    field: &impl Field,
+    pattern: &syn::LitStr,
+    validator: Validator,
+    rename_map: &RenameMap,
+) -> Result<Validator, crate::Errors> {
+    if let Err(error) = regex::Regex::new(&pattern.value()) {
+        return Err(vec![crate::Error::validate_pattern_invalid(
+            pattern, &error,
+        )]);
+    }
+
+    let validator = properties_validator_tokens(
+        field,
+        quote!((__property, __value)),
+        quote!(__value),
+        Some(quote!(
+            if ::serde_valid::ValidatePattern::validate_pattern(__property, __pattern).is_err() {
+                return None;
+            }
+        )),
+        validator,
+        rename_map,
+    );
+
+    Ok(quote!({
+        static __PROPERTY_PATTERN: ::serde_valid::export::OnceCell<::regex::Regex> =
+            ::serde_valid::export::OnceCell::new();
+        let __pattern = __PROPERTY_PATTERN.get_or_init(|| ::regex::Regex::new(#pattern).unwrap());
+        #validator
+    }))
+}
+
+/// Run the validator with the field bound to `target` of each property not skipped by `filter`,
+/// and push the errors keyed by the property, which flatten to `/field/<key>`.
+fn properties_validator_tokens(
+    field: &impl Field,
+    entry: TokenStream,
+    target: TokenStream,
+    filter: Option<TokenStream>,
+    validator: Validator,
+    rename_map: &RenameMap,
+) -> Validator {
+    let field_name = field.name();
+    let field_ident = field.ident();
+    let field_key = field.key();
+    let rename = rename_map.get(field_name).unwrap_or(&field_key);
+    let errors = field.errors_variable();
+    let errors_map_type = field.errors_map_type();
+
+    quote!({
+        let __property_errors = ::serde_valid::validation::PropertyEntries::property_entries(#field_ident)
+            .into_iter()
+            .filter_map(|#entry| {
+                #filter
+                let __errors = {
+                    let #field_ident = #target;
+                    let mut #errors = #errors_map_type::new();
+                    #validator
+                    #errors
+                        .into_iter()
+                        .flat_map(|(_, errors)| errors)
+                        .collect::<::serde_valid::validation::VecErrors>()
+                };
+                if __errors.is_empty() {
+                    None
+                } else {
+                    Some((
+                        ::std::string::ToString::to_string(__property).into(),
+                        ::serde_valid::validation::Errors::NewType(__errors),
+                    ))
+                }
+            })
+            .collect::<::serde_valid::validation::PropertyErrorsMap<_>>();
+        if !__property_errors.is_empty() {
+            #errors
+                .entry((#rename).into())
+                .or_default()
+                .push(::serde_valid::validation::Error::Properties(
+                    ::serde_valid::validation::ObjectErrors::new(vec![], __property_errors),
+                ));
+        }
+    })
+}
@@ -13,7 +13,7 @@ macro_rules! extract_object_size_validator {
         paste::paste! {
             pub fn [<extract_object_ $ErrorType:snake _validator>](
                 field: &impl Field,
-                validation_value: &syn::Lit,
+                validation_value: &syn::Expr,
                 custom_message: CustomMessageToken,
                 rename_map: &RenameMap,
             ) -> Result<Validator, crate::Errors> {
@@ -22,7 +22,7 @@ macro_rules! extract_object_size_validator {
 
             fn [<inner_extract_object_ $ErrorType:snake _validator>](
                 field: &impl Field,
-                validation_value: &syn::Lit,
+                validation_value: &syn::Expr,
                 custom_message: CustomMessageToken,
                 rename_map: &RenameMap,
             ) -> Result<TokenStream, crate::Errors> {
@@ -42,7 +42,7 @@ macro_rules! extract_object_size_validator {
                         use ::serde_valid::validation::{IntoError, ToDefaultMessage};
 
                         #errors
-                            .entry(#rename)
+                            .entry((#rename).into())
                             .or_default()
                             .push(__composited_error_params.into_error_by(#custom_message));
                     }
@@ -0,0 +1,205 @@
+use crate::types::{CommaSeparatedNestedMetas, Field};
+use crate::validate::common::{
+    is_skip_if, MetaListOption, MetaListValidation, MetaNameValueValidation, MetaPathValidation,
+};
+use proc_macro2::TokenStream;
+use quote::quote;
+use std::str::FromStr;
+
+/// Statements inserting the JSON Schema keywords of the field validators into `__schema`.
+///
+/// Validators with `groups(...)`, `when` or `skip_if`, custom functions and non-literal limits
+/// have no keyword. Invalid attributes are left to `#[derive(Validate)]` to report.
+pub fn extract_field_schema(field: &impl Field) -> TokenStream {
+    if field.attrs().iter().any(is_skip_if) {
+        return quote!();
+    }
+
+    TokenStream::from_iter(
+        field
+            .attrs()
+            .iter()
+            .filter(|attribute| {
+                attribute.path.is_ident("validate") || attribute.path.is_ident("serde_valid")
+            })
+            .filter_map(|attribute| match attribute.parse_meta() {
+                Ok(syn::Meta::Path(_)) => {
+                    let ty = field.ty();
+                    Some(quote!(
+                        if let ::serde_valid::export::serde_json::Value::Object(__nested_schema) =
+                            <#ty as ::serde_valid::ValidateSchema>::json_schema()
+                        {
+                            __schema.extend(__nested_schema);
+                        }
+                    ))
+                }
+                Ok(syn::Meta::List(syn::MetaList { nested, .. })) => {
+                    if nested.iter().skip(1).any(is_groups) {
+                        return None;
+                    }
+                    match nested.first()? {
+                        syn::NestedMeta::Meta(meta) => extract_meta_schema(meta),
+                        syn::NestedMeta::Lit(_) => None,
+                    }
+                }
+                _ => None,
+            }),
+    )
+}
+
+fn extract_meta_schema(meta: &syn::Meta) -> Option<TokenStream> {
+    let (keywords, values): (Vec<_>, Vec<_>) = extract_meta_keywords(meta)?.into_iter().unzip();
+
+    Some(quote!(
+        #(
+            __schema.insert(
+                #keywords.to_owned(),
+                ::serde_valid::export::serde_json::json!(#values),
+            );
+        )*
+    ))
+}
+
+/// JSON Schema keywords and values of the validation, such as `"maxLength"` and `10`.
+///
+/// Most validations have a single keyword, but `min_contains` and `max_contains`
+/// also need `contains`.
+fn extract_meta_keywords(meta: &syn::Meta) -> Option<Vec<(&'static str, TokenStream)>> {
+    extract_meta_keyword(meta)
+        .map(|keyword_value| vec![keyword_value])
+        .or_else(|| extract_count_contains_keywords(meta))
+}
+
+/// JSON Schema keyword and value of the validation, such as `"maxLength"` and `10`.
+fn extract_meta_keyword(meta: &syn::Meta) -> Option<(&'static str, TokenStream)> {
+    let keyword_value = match meta {
+        syn::Meta::NameValue(syn::MetaNameValue { path, lit, .. }) => {
+            let keyword =
+                match MetaNameValueValidation::from_str(&path.get_ident()?.to_string()).ok()? {
+                    MetaNameValueValidation::Minimum => "minimum",
+                    MetaNameValueValidation::Maximum => "maximum",
+                    MetaNameValueValidation::ExclusiveMinimum => "exclusiveMinimum",
+                    MetaNameValueValidation::ExclusiveMaximum => "exclusiveMaximum",
+                    MetaNameValueValidation::MinLength => "minLength",
+                    MetaNameValueValidation::MaxLength => "maxLength",
+                    MetaNameValueValidation::MinItems => "minItems",
+                    MetaNameValueValidation::MaxItems => "maxItems",
+                    MetaNameValueValidation::MinProperties => "minProperties",
+                    MetaNameValueValidation::MaxProperties => "maxProperties",
+                    MetaNameValueValidation::MultipleOf => "multipleOf",
+                    MetaNameValueValidation::Pattern => "pattern",
+                    MetaNameValueValidation::Format => "format",
+                    MetaNameValueValidation::GreaterThan
+                    | MetaNameValueValidation::GreaterThanOrEqual
+                    | MetaNameValueValidation::LessThan
+                    | MetaNameValueValidation::LessThanOrEqual
+                    | MetaNameValueValidation::Equals
+                    | MetaNameValueValidation::NotEquals
+                    | MetaNameValueValidation::UniqueItemsBy => return None,
+                    MetaNameValueValidation::Contains => {
+                        return Some(("contains", quote!({ "const": #lit })))
+                    }
+                };
+            (keyword, quote!(#lit))
+        }
+        syn::Meta::Path(path) => {
+            match MetaPathValidation::from_str(&path.get_ident()?.to_string()).ok()? {
+                MetaPathValidation::UniqueItems => ("uniqueItems", quote!(true)),
+            }
+        }
+        syn::Meta::List(syn::MetaList { path, nested, .. }) => {
+            match MetaListValidation::from_str(&path.get_ident()?.to_string()).ok()? {
+                MetaListValidation::Enumerate => ("enum", quote!([#nested])),
+                MetaListValidation::Custom | MetaListValidation::CustomAsync => return None,
+                MetaListValidation::AnyOf => ("anyOf", extract_branches_schema(nested)?),
+                MetaListValidation::AllOf => ("allOf", extract_branches_schema(nested)?),
+                MetaListValidation::OneOf => ("oneOf", extract_branches_schema(nested)?),
+                MetaListValidation::Not => match nested.first()? {
+                    syn::NestedMeta::Meta(meta) if nested.len() == 1 => {
+                        ("not", extract_subschema(meta)?)
+                    }
+                    _ => return None,
+                },
+                MetaListValidation::Contains => match nested.first()? {
+                    syn::NestedMeta::Lit(lit) if nested.len() == 1 => {
+                        ("contains", quote!({ "const": #lit }))
+                    }
+                    _ => return None,
+                },
+                MetaListValidation::MinContains | MetaListValidation::MaxContains => return None,
+                MetaListValidation::PropertyNames => match nested.first()? {
+                    syn::NestedMeta::Meta(meta) if nested.len() == 1 => {
+                        ("propertyNames", extract_subschema(meta)?)
+                    }
+                    _ => return None,
+                },
+                MetaListValidation::PatternProperties => {
+                    match (nested.first()?, nested.iter().nth(1)?) {
+                        (syn::NestedMeta::Lit(pattern), syn::NestedMeta::Meta(meta))
+                            if nested.len() == 2 =>
+                        {
+                            let schema = extract_subschema(meta)?;
+                            ("patternProperties", quote!({ #pattern: #schema }))
+                        }
+                        _ => return None,
+                    }
+                }
+            }
+        }
+    };
+
+    Some(keyword_value)
+}
+
+/// Schemas of the branches of a composition, or `None` when any branch has no keyword.
+fn extract_branches_schema(nested: &CommaSeparatedNestedMetas) -> Option<TokenStream> {
+    let branches = nested
+        .iter()
+        .map(|nested_meta| match nested_meta {
+            syn::NestedMeta::Meta(meta) => extract_subschema(meta),
+            syn::NestedMeta::Lit(_) => None,
+        })
+        .collect::<Option<Vec<_>>>()?;
+
+    Some(quote!([#(#branches),*]))
+}
+
+/// `{"contains": {"const": value}, "minContains": count}` of `min_contains(count, value)`,
+/// or `None` when the item is `custom(...)`.
+fn extract_count_contains_keywords(meta: &syn::Meta) -> Option<Vec<(&'static str, TokenStream)>> {
+    let syn::Meta::List(syn::MetaList { path, nested, .. }) = meta else {
+        return None;
+    };
+    let keyword = match MetaListValidation::from_str(&path.get_ident()?.to_string()).ok()? {
+        MetaListValidation::MinContains => "minContains",
+        MetaListValidation::MaxContains => "maxContains",
+        _ => return None,
+    };
+    match (nested.iter().next()?, nested.iter().nth(1)?) {
+        (syn::NestedMeta::Lit(count), syn::NestedMeta::Lit(value)) if nested.len() == 2 => {
+            Some(vec![
+                ("contains", quote!({ "const": #value })),
+                (keyword, quote!(#count)),
+            ])
+        }
+        _ => None,
+    }
+}
+
+/// Schema object of a nested validation, such as `{"maxLength": 10}`.
+fn extract_subschema(meta: &syn::Meta) -> Option<TokenStream> {
+    let (keywords, values): (Vec<_>, Vec<_>) = extract_meta_keywords(meta)?.into_iter().unzip();
+    Some(quote!({ #(#keywords: #values),* }))
+}
+
+fn is_groups(nested_meta: &syn::NestedMeta) -> bool {
+    match nested_meta {
+        syn::NestedMeta::Meta(syn::Meta::List(list)) => matches!(
+            list.path
+                .get_ident()
+                .map(|ident| MetaListOption::from_str(&ident.to_string())),
+            Some(Ok(MetaListOption::Groups))
+        ),
+        _ => false,
+    }
+}
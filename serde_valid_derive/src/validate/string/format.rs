@@ -0,0 +1,84 @@
+use crate::{
+    serde::rename::RenameMap,
+    types::Field,
+    validate::{common::CustomMessageToken, Validator},
+};
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// The formats built in `serde_valid::format`.
+const BUILTIN_FORMATS: [&str; 9] = [
+    "email",
+    "uri",
+    "uuid",
+    "ipv4",
+    "ipv6",
+    "hostname",
+    "date",
+    "time",
+    "date-time",
+];
+
+/// Format validation.
+///
+/// A literal format name must be built in, so that a typo is a compile error.
+/// Registered formats are given by a non-literal expression, such as a constant.
+///
+/// See <https://json-schema.org/understanding-json-schema/reference/string.html#format>
+pub fn extract_string_format_validator(
+    field: &impl Field,
+    validation_value: &syn::Expr,
+    custom_message: CustomMessageToken,
+    rename_map: &RenameMap,
+) -> Result<Validator, crate::Errors> {
+    inner_extract_string_format_validator(field, validation_value, custom_message, rename_map)
+}
+
+fn inner_extract_string_format_validator(
+    field: &impl Field,
+    validation_value: &syn::Expr,
+    custom_message: CustomMessageToken,
+    rename_map: &RenameMap,
+) -> Result<TokenStream, crate::Errors> {
+    let field_name = field.name();
+    let field_ident = field.ident();
+    let field_key = field.key();
+    let rename = rename_map.get(field_name).unwrap_or(&field_key);
+    let errors = field.errors_variable();
+    let format = get_format(validation_value)?;
+    let custom_message = custom_message.into_token();
+
+    Ok(quote!(
+        if let Err(__composited_error_params) = ::serde_valid::validation::ValidateCompositedFormat::validate_composited_format(
+            #field_ident,
+            #format,
+        ) {
+            use ::serde_valid::validation::{IntoError, ToDefaultMessage};
+
+            #errors
+                .entry((#rename).into())
+                .or_default()
+                .push(__composited_error_params.into_error_by(#custom_message));
+        }
+    ))
+}
+
+fn get_format(validation_value: &syn::Expr) -> Result<TokenStream, crate::Errors> {
+    match validation_value {
+        syn::Expr::Lit(syn::ExprLit {
+            lit: syn::Lit::Str(format),
+            ..
+        }) => {
+            if BUILTIN_FORMATS.contains(&format.value().as_str()) {
+                Ok(quote!(#format))
+            } else {
+                Err(vec![crate::Error::validate_format_unknown(
+                    format,
+                    &BUILTIN_FORMATS,
+                )])
+            }
+        }
+        syn::Expr::Lit(syn::ExprLit { lit, .. }) => Err(vec![crate::Error::str_literal_only(lit)]),
+        _ => Ok(quote!(#validation_value)),
+    }
+}
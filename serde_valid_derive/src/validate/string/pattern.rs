@@ -11,7 +11,7 @@ use quote::quote;
 
 pub fn extract_string_pattern_validator(
     field: &impl Field,
-    validation_value: &syn::Lit,
+    validation_value: &syn::Expr,
     custom_message: CustomMessageToken,
     rename_map: &RenameMap,
 ) -> Result<Validator, crate::Errors> {
@@ -20,7 +20,7 @@ pub fn extract_string_pattern_validator(
 
 fn inner_extract_string_pattern_validator(
     field: &impl Field,
-    validation_value: &syn::Lit,
+    validation_value: &syn::Expr,
     custom_message: CustomMessageToken,
     rename_map: &RenameMap,
 ) -> Result<TokenStream, crate::Errors> {
@@ -30,6 +30,11 @@ fn inner_extract_string_pattern_validator(
     let rename = rename_map.get(field_name).unwrap_or(&field_key);
     let errors = field.errors_variable();
     let pattern = get_str(validation_value)?;
+    if let Err(error) = regex::Regex::new(&pattern.value()) {
+        return Err(vec![crate::Error::validate_pattern_invalid(
+            pattern, &error,
+        )]);
+    }
     let pattern_ident = syn::Ident::new(
         &format!("{}_PATTERN", &field_ident).to_uppercase(),
         field_ident.span(),
@@ -46,7 +51,7 @@ fn inner_extract_string_pattern_validator(
             use ::serde_valid::validation::{IntoError, ToDefaultMessage};
 
             #errors
-                .entry(#rename)
+                .entry((#rename).into())
                 .or_default()
                 .push(__composited_error_params.into_error_by(#custom_message));
         }
@@ -66,6 +66,42 @@ impl std::fmt::Display for Number {
     }
 }
 
+impl serde::Serialize for Number {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        match self {
+            Number::I8(num) => num.serialize(serializer),
+            Number::I16(num) => num.serialize(serializer),
+            Number::I32(num) => num.serialize(serializer),
+            Number::I64(num) => num.serialize(serializer),
+            Number::I128(num) => num.serialize(serializer),
+            Number::Isize(num) => num.serialize(serializer),
+            Number::U8(num) => num.serialize(serializer),
+            Number::U16(num) => num.serialize(serializer),
+            Number::U32(num) => num.serialize(serializer),
+            Number::U64(num) => num.serialize(serializer),
+            Number::U128(num) => num.serialize(serializer),
+            Number::Usize(num) => num.serialize(serializer),
+            Number::NonZeroI8(num) => num.serialize(serializer),
+            Number::NonZeroI16(num) => num.serialize(serializer),
+            Number::NonZeroI32(num) => num.serialize(serializer),
+            Number::NonZeroI64(num) => num.serialize(serializer),
+            Number::NonZeroI128(num) => num.serialize(serializer),
+            Number::NonZeroIsize(num) => num.serialize(serializer),
+            Number::NonZeroU8(num) => num.serialize(serializer),
+            Number::NonZeroU16(num) => num.serialize(serializer),
+            Number::NonZeroU32(num) => num.serialize(serializer),
+            Number::NonZeroU64(num) => num.serialize(serializer),
+            Number::NonZeroU128(num) => num.serialize(serializer),
+            Number::NonZeroUsize(num) => num.serialize(serializer),
+            Number::F32(num) => num.serialize(serializer),
+            Number::F64(num) => num.serialize(serializer),
+        }
+    }
+}
+
 macro_rules! impl_from_trait {
     ($type:ty) => {
         paste::paste! {
@@ -11,11 +11,30 @@ macro_rules! impl_from_trait {
     };
 }
 
-impl_from_trait!(regex::Regex);
-impl_from_trait!(&regex::Regex);
+impl From<regex::Regex> for Pattern {
+    fn from(item: regex::Regex) -> Self {
+        Self(item.as_str().to_owned())
+    }
+}
+
+impl From<&regex::Regex> for Pattern {
+    fn from(item: &regex::Regex) -> Self {
+        Self(item.as_str().to_owned())
+    }
+}
+
 impl_from_trait!(String);
 impl_from_trait!(&str);
 
+impl serde::Serialize for Pattern {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.0)
+    }
+}
+
 impl std::fmt::Display for Pattern {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{:}", self.0)